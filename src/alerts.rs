@@ -0,0 +1,53 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AlertState {
+    Normal,
+    Warning,
+    Critical
+}
+
+pub struct TemperatureAlert {
+    pub name: String,
+    pub warning_threshold: f32,
+    pub critical_threshold: f32,
+    pub state: AlertState
+}
+
+// на скільки градусів температура має опуститися нижче порогу, щоб стан повернувся назад,
+// інакше при коливанні біля порогу сповіщення сипалися б безперервно
+const ALERT_HYSTERESIS: f32 = 2.0;
+
+impl TemperatureAlert {
+    pub fn new(name: &str, warning_threshold: f32, critical_threshold: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            warning_threshold,
+            critical_threshold,
+            state: AlertState::Normal
+        }
+    }
+
+    // повертає новий стан лише тоді, коли він змінився
+    pub fn update(&mut self, temperature: f32) -> Option<AlertState> {
+        let is_critical = temperature >= self.critical_threshold
+            || (self.state == AlertState::Critical && temperature > self.critical_threshold - ALERT_HYSTERESIS);
+
+        let is_warning = temperature >= self.warning_threshold
+            || (self.state != AlertState::Normal && temperature > self.warning_threshold - ALERT_HYSTERESIS);
+
+        let new_state = if is_critical {
+            AlertState::Critical
+        } else if is_warning {
+            AlertState::Warning
+        } else {
+            AlertState::Normal
+        };
+
+        if new_state != self.state {
+            self.state = new_state;
+
+            Some(new_state)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::localization::{detect_language, tr, tr_with, Language};
+use crate::pressure_stall::{get_cgroup_pressure_samples, PressureStallMeter, CGROUP_DIRECTORY};
+use crate::sensors::{SensorKind, SensorReading};
+use crate::units::MeasurementUnit;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CgroupCounters {
+    pub cpu_usage_microseconds: u64,
+    pub read_bytes: u64,
+    pub written_bytes: u64,
+    pub read_operations: u64,
+    pub write_operations: u64
+}
+
+// у cgroup v2 рядок /proc/self/cgroup один: "0::/system.slice/docker-abc.scope"
+pub fn parse_own_cgroup(data: &str) -> Option<String> {
+    data.lines().find_map(|line| line.strip_prefix("0::")).map(|path| path.trim().to_string())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CgroupPathError {
+    OwnCgroupUnknown,
+    NotCgroupV2(PathBuf)
+}
+
+impl CgroupPathError {
+    pub fn message(&self, language: Language) -> String {
+        match self {
+            CgroupPathError::OwnCgroupUnknown => tr(language, "cgroup.own_cgroup_unknown").to_string(),
+            CgroupPathError::NotCgroupV2(path) => tr_with(language, "cgroup.not_cgroup_v2", &[("path", &path.display().to_string())])
+        }
+    }
+}
+
+// "self" - cgroup самої програми, інакше шлях відносно /sys/fs/cgroup або повний шлях всередині неї
+pub fn resolve_cgroup_path(value: &str) -> Result<PathBuf, CgroupPathError> {
+    let relative_path = if value == "self" {
+        match fs::read_to_string("/proc/self/cgroup").ok().as_deref().and_then(parse_own_cgroup) {
+            Some(path) => path,
+            None => return Err(CgroupPathError::OwnCgroupUnknown)
+        }
+    } else {
+        value.strip_prefix(CGROUP_DIRECTORY).unwrap_or(value).to_string()
+    };
+
+    let path = Path::new(CGROUP_DIRECTORY).join(relative_path.trim_start_matches('/'));
+
+    // cpu.stat є в кожній cgroup v2, тож його відсутність означає неправильний шлях або cgroup v1
+    if !path.join("cpu.stat").exists() {
+        return Err(CgroupPathError::NotCgroupV2(path));
+    }
+
+    Ok(path)
+}
+
+pub fn parse_cpu_stat_usage(data: &str) -> Option<u64> {
+    data.lines().find_map(|line| line.strip_prefix("usage_usec ")).and_then(|value| value.trim().parse::<u64>().ok())
+}
+
+// "квота період" у мікросекундах, результат - кількість процесорів, яку cgroup може зайняти;
+// квота "max" означає відсутність обмеження, тоді повертаю нескінченність
+pub fn parse_cpu_max(data: &str) -> Option<f64> {
+    let mut fields = data.split_whitespace();
+
+    let quota = fields.next()?;
+    let period = fields.next()?.parse::<f64>().ok()?;
+
+    if period <= 0.0 {
+        return None;
+    }
+
+    if quota == "max" {
+        return Some(f64::INFINITY);
+    }
+
+    Some(quota.parse::<f64>().ok()? / period)
+}
+
+// memory.current та memory.max, де "max" означає відсутність обмеження
+pub fn parse_memory_value(data: &str) -> Option<u64> {
+    data.trim().parse::<u64>().ok()
+}
+
+// рядки "8:0 rbytes=1 wbytes=2 rios=3 wios=4 dbytes=0 dios=0", по одному на пристрій, сумую всі
+pub fn parse_io_stat(data: &str, counters: &mut CgroupCounters) {
+    for line in data.lines() {
+        for field in line.split_whitespace().skip(1) {
+            let (name, value) = match field.split_once('=') {
+                Some((name, value)) => (name, value.parse::<u64>().unwrap_or(0)),
+                None => continue
+            };
+
+            match name {
+                "rbytes" => counters.read_bytes += value,
+                "wbytes" => counters.written_bytes += value,
+                "rios" => counters.read_operations += value,
+                "wios" => counters.write_operations += value,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok()
+}
+
+// cgroup.procs - по одному pid у рядку
+pub fn parse_cgroup_procs(data: &str) -> Vec<u32> {
+    data.lines().filter_map(|line| line.trim().parse::<u32>().ok()).collect()
+}
+
+// cgroup.procs містить лише процеси самої cgroup, тому вкладені cgroup обходжу рекурсивно
+pub fn get_cgroup_pids(cgroup_directory: &Path) -> HashSet<u32> {
+    let mut result = HashSet::new();
+
+    if let Some(data) = read_file(&cgroup_directory.join("cgroup.procs")) {
+        result.extend(parse_cgroup_procs(&data));
+    }
+
+    if let Ok(entries) = fs::read_dir(cgroup_directory) {
+        for entry in entries.flatten() {
+            if entry.file_type().map(|file_type| file_type.is_dir()).unwrap_or(false) {
+                result.extend(get_cgroup_pids(&entry.path()));
+            }
+        }
+    }
+
+    result
+}
+
+pub struct CgroupMonitor {
+    path: PathBuf,
+    // шлях відносно /sys/fs/cgroup, ним називається пристрій у показах
+    pub name: String,
+    previous: Option<(CgroupCounters, f64)>,
+    pressure_stall_meter: PressureStallMeter
+}
+
+impl CgroupMonitor {
+    pub fn open(value: &str) -> Result<CgroupMonitor, CgroupPathError> {
+        let path = resolve_cgroup_path(value)?;
+
+        let name = match path.strip_prefix(CGROUP_DIRECTORY) {
+            Ok(relative_path) if !relative_path.as_os_str().is_empty() => relative_path.to_string_lossy().to_string(),
+            _ => "/".to_string()
+        };
+
+        Ok(CgroupMonitor {
+            path,
+            name,
+            previous: None,
+            pressure_stall_meter: PressureStallMeter::default()
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn reading(&self, sensor: &str, kind: SensorKind, value: f32, unit: MeasurementUnit) -> SensorReading {
+        SensorReading {
+            device: self.name.clone(),
+            sensor: sensor.to_string(),
+            kind,
+            value,
+            unit
+        }
+    }
+
+    // без квоти відсотки рахуються від усіх процесорів, доступних програмі
+    fn get_cpu_limit(&self) -> f64 {
+        match read_file(&self.path.join("cpu.max")).as_deref().and_then(parse_cpu_max) {
+            Some(limit) if limit.is_finite() => limit,
+            _ => std::thread::available_parallelism().map(|count| count.get() as f64).unwrap_or(1.0)
+        }
+    }
+
+    // cpu.stat є в кожній cgroup v2, тож помилка його читання означає, що cgroup видалили
+    pub fn read(&mut self, timestamp: f64) -> io::Result<Vec<SensorReading>> {
+        let mut readings = Vec::new();
+
+        let mut counters = CgroupCounters::default();
+
+        let cpu_usage = parse_cpu_stat_usage(&fs::read_to_string(self.path.join("cpu.stat"))?);
+
+        counters.cpu_usage_microseconds = cpu_usage.unwrap_or(0);
+
+        if let Some(data) = read_file(&self.path.join("io.stat")) {
+            parse_io_stat(&data, &mut counters);
+        }
+
+        let memory_current = read_file(&self.path.join("memory.current")).as_deref().and_then(parse_memory_value);
+        let memory_max = read_file(&self.path.join("memory.max")).as_deref().and_then(parse_memory_value);
+
+        if let Some(memory_current) = memory_current {
+            readings.push(self.reading("memory current", SensorKind::Memory, memory_current as f32, MeasurementUnit::Byte));
+        }
+
+        if let Some(memory_max) = memory_max {
+            readings.push(self.reading("memory max", SensorKind::Memory, memory_max as f32, MeasurementUnit::Byte));
+        }
+
+        if let (Some(memory_current), Some(memory_max)) = (memory_current, memory_max) {
+            if memory_max > 0 {
+                readings.push(self.reading("memory", SensorKind::Load, (memory_current as f64 / memory_max as f64 * 100.0) as f32, MeasurementUnit::Percent));
+            }
+        }
+
+        if let Some((previous, previous_timestamp)) = &self.previous {
+            let elapsed = timestamp - previous_timestamp;
+
+            if elapsed > 0.0 {
+                // лічильники cgroup лише зростають, зменшення означає, що cgroup створили заново
+                if let (Some(_), Some(cpu_delta)) = (cpu_usage, counters.cpu_usage_microseconds.checked_sub(previous.cpu_usage_microseconds)) {
+                    let percent = cpu_delta as f64 / 1000000.0 / elapsed / self.get_cpu_limit() * 100.0;
+
+                    readings.push(self.reading("cpu", SensorKind::Load, percent as f32, MeasurementUnit::Percent));
+                }
+
+                for (direction, bytes, operations) in [
+                    ("read", counters.read_bytes.checked_sub(previous.read_bytes), counters.read_operations.checked_sub(previous.read_operations)),
+                    ("write", counters.written_bytes.checked_sub(previous.written_bytes), counters.write_operations.checked_sub(previous.write_operations))
+                ] {
+                    if let (Some(bytes), Some(operations)) = (bytes, operations) {
+                        readings.push(self.reading(direction, SensorKind::DiskThroughput, (bytes as f64 / elapsed) as f32, MeasurementUnit::BytePerSecond));
+                        readings.push(self.reading(direction, SensorKind::DiskOperations, (operations as f64 / elapsed) as f32, MeasurementUnit::OperationsPerSecond));
+                    }
+                }
+            }
+        }
+
+        self.previous = Some((counters, timestamp));
+
+        let pressure_samples = get_cgroup_pressure_samples(&self.path, &self.name);
+
+        for reading in self.pressure_stall_meter.update(pressure_samples, timestamp) {
+            readings.push(SensorReading {
+                device: reading.source,
+                sensor: reading.sensor,
+                kind: SensorKind::Pressure,
+                value: reading.percent,
+                unit: MeasurementUnit::Percent
+            });
+        }
+
+        Ok(readings)
+    }
+}
+
+pub fn open_cgroup_monitor_if_requested(cgroup: &Option<String>) -> Option<CgroupMonitor> {
+    let value = cgroup.as_ref()?;
+
+    match CgroupMonitor::open(value) {
+        Ok(cgroup_monitor) => Some(cgroup_monitor),
+        Err(error) => {
+            log::warn!("режим cgroup вимкнено: {}", error.message(detect_language()));
+
+            None
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::units::{MeasurementUnit, TEMPERATURE_UNITS};
+
+pub struct CliArguments {
+    pub is_help_requested: bool,
+    pub is_headless: bool,
+    pub is_overlay_mode: bool,
+    pub is_terminal_ui: bool,
+    pub is_sensor_list_requested: bool,
+    pub metrics_address: Option<String>,
+    pub delay_between_temperature_requests: u64,
+    pub recording_path: Option<String>,
+    pub is_recording_opened: bool,
+    pub export_path: Option<String>,
+    pub storage_directory: Option<String>,
+    pub temperature_unit: MeasurementUnit,
+    pub cgroup: Option<String>
+}
+
+impl Default for CliArguments {
+    fn default() -> Self {
+        Self {
+            is_help_requested: false,
+            is_headless: false,
+            is_overlay_mode: false,
+            is_terminal_ui: false,
+            is_sensor_list_requested: false,
+            metrics_address: None,
+            delay_between_temperature_requests: 500,
+            recording_path: None,
+            is_recording_opened: false,
+            export_path: None,
+            storage_directory: None,
+            temperature_unit: MeasurementUnit::Celsius,
+            cgroup: None
+        }
+    }
+}
+
+pub const USAGE: &str = "usage: resource_monitor [options]
+
+options:
+    --headless                  run without a window, only sampling sensors
+    --tui                       show the charts in the terminal instead of a window (e.g. over SSH)
+    --overlay                   start in the compact always-on-top overlay
+    --list-sensors              print every discovered sensor with its backend, unit, value, read latency
+                                and state, then exit
+    --metrics-address <addr>    serve /metrics in Prometheus format on <addr> (e.g. 127.0.0.1:9184)
+    --interval <ms>             delay between sensor requests in milliseconds (default 500)
+    --record <file>             record every sample of the session to <file> (also with --tui and --headless)
+    --open-recording <file>     open a recorded session in the viewer instead of live data
+    --export <file>             write the history to <file> (.csv or .json) when the window or --tui is closed;
+                                together with --open-recording converts the recording without opening a window
+    --storage-dir <dir>         directory of the long-term history (default: the user data directory)
+    --unit <unit>               temperature unit of the interface, the output and exports:
+                                celsius (default), fahrenheit or kelvin
+    --cgroup <path>             read CPU, memory, IO and pressure of a cgroup v2 instead of the whole machine:
+                                \"self\" for the cgroup of the program (e.g. inside a container) or a path
+                                relative to /sys/fs/cgroup (e.g. system.slice/nginx.service)
+    --help                      print this message";
+
+pub fn parse_cli_arguments(arguments: impl Iterator<Item = String>) -> Result<CliArguments, String> {
+    let mut result = CliArguments::default();
+
+    let mut arguments = arguments;
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--help" | "-h" => {
+                result.is_help_requested = true;
+            },
+            "--headless" => {
+                result.is_headless = true;
+            },
+            "--tui" => {
+                result.is_terminal_ui = true;
+            },
+            "--overlay" => {
+                result.is_overlay_mode = true;
+            },
+            "--list-sensors" => {
+                result.is_sensor_list_requested = true;
+            },
+            "--metrics-address" => {
+                result.metrics_address = Some(next_value(&mut arguments, &argument)?);
+            },
+            "--interval" => {
+                let value = next_value(&mut arguments, &argument)?;
+
+                result.delay_between_temperature_requests = match value.parse::<u64>() {
+                    Ok(value) => value,
+                    Err(_) => return Err(format!("invalid value for --interval: {}", value))
+                };
+            },
+            "--record" => {
+                result.recording_path = Some(next_value(&mut arguments, &argument)?);
+                result.is_recording_opened = false;
+            },
+            "--open-recording" => {
+                result.recording_path = Some(next_value(&mut arguments, &argument)?);
+                result.is_recording_opened = true;
+            },
+            "--export" => {
+                result.export_path = Some(next_value(&mut arguments, &argument)?);
+            },
+            "--storage-dir" => {
+                result.storage_directory = Some(next_value(&mut arguments, &argument)?);
+            },
+            "--unit" => {
+                let value = next_value(&mut arguments, &argument)?;
+
+                result.temperature_unit = match MeasurementUnit::from_name(&value) {
+                    Some(unit) if TEMPERATURE_UNITS.contains(&unit) => unit,
+                    _ => return Err(format!("invalid value for --unit: {}", value))
+                };
+            },
+            "--cgroup" => {
+                result.cgroup = Some(next_value(&mut arguments, &argument)?);
+            },
+            _ => return Err(format!("unknown argument: {}", argument))
+        }
+    }
+
+    // такі комбінації раніше мовчки ігнорувалися, тепер краще одразу сказати про це
+    let is_recording_converted = result.is_recording_opened && result.export_path.is_some();
+
+    if result.is_recording_opened && !is_recording_converted && (result.is_terminal_ui || result.is_headless) {
+        return Err("--open-recording can't be combined with --tui or --headless".to_string());
+    }
+
+    // режим без вікна працює, поки його не зупинять, тож моменту для експорту історії в нього немає
+    if result.is_headless && result.export_path.is_some() && !is_recording_converted {
+        return Err("--export can't be combined with --headless, use --record instead".to_string());
+    }
+
+    Ok(result)
+}
+
+fn next_value(arguments: &mut impl Iterator<Item = String>, argument: &str) -> Result<String, String> {
+    match arguments.next() {
+        Some(value) => Ok(value),
+        None => Err(format!("missing value for {}", argument))
+    }
+}
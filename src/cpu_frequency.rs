@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::hwmon::read_trimmed;
+
+pub const CPU_DEVICES_DIRECTORY: &str = "/sys/devices/system/cpu";
+
+// теки cpu0, cpu1, ... (поряд лежать cpufreq, cpuidle та інші, які не підходять)
+fn get_cpu_indexes(cpu_directory: &Path) -> Vec<u32> {
+    let mut indexes: Vec<u32> = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(cpu_directory) {
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            if let Some(Ok(index)) = file_name.strip_prefix("cpu").map(|index| index.parse::<u32>()) {
+                indexes.push(index);
+            }
+        }
+    }
+
+    indexes.sort();
+
+    indexes
+}
+
+// scaling_cur_freq повертає кілогерци, а показую мегагерци
+pub fn get_cpu_frequencies(cpu_directory: &Path) -> Vec<(String, f32)> {
+    let mut result_arr = Vec::new();
+
+    for index in get_cpu_indexes(cpu_directory) {
+        let path = cpu_directory.join(format!("cpu{}", index)).join("cpufreq").join("scaling_cur_freq");
+
+        if let Some(kilohertz) = read_trimmed(&path).and_then(|value| value.parse::<f32>().ok()) {
+            result_arr.push((format!("cpu{}", index), kilohertz / 1000.0));
+        }
+    }
+
+    result_arr
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct CpuThrottleCounts {
+    pub core_count: u64,
+    pub package_count: u64
+}
+
+fn read_count(path: &Path) -> Option<u64> {
+    read_trimmed(path).and_then(|value| value.parse::<u64>().ok())
+}
+
+// сума лічильників тротлінгу ядер та пакетів; None, якщо драйвер їх не надає (наприклад на AMD)
+// package_throttle_count однаковий для всіх логічних процесорів пакета, а core_throttle_count - для sibling-потоків ядра,
+// тому кожен лічильник рахую один раз за physical_package_id і за парою (physical_package_id, core_id)
+pub fn get_cpu_throttle_counts(cpu_directory: &Path) -> Option<CpuThrottleCounts> {
+    let mut result = None;
+    let mut counted_packages: HashSet<String> = HashSet::new();
+    let mut counted_cores: HashSet<(String, String)> = HashSet::new();
+
+    for index in get_cpu_indexes(cpu_directory) {
+        let cpu_path = cpu_directory.join(format!("cpu{}", index));
+        let directory = cpu_path.join("thermal_throttle");
+
+        // без топології кожен логічний процесор вважаю окремим ядром і пакетом
+        let package_id = read_trimmed(&cpu_path.join("topology").join("physical_package_id")).unwrap_or(format!("cpu{}", index));
+        let core_id = read_trimmed(&cpu_path.join("topology").join("core_id")).unwrap_or(format!("cpu{}", index));
+
+        if !counted_cores.contains(&(package_id.clone(), core_id.clone())) {
+            if let Some(count) = read_count(&directory.join("core_throttle_count")) {
+                result.get_or_insert(CpuThrottleCounts::default()).core_count += count;
+                counted_cores.insert((package_id.clone(), core_id));
+            }
+        }
+
+        if !counted_packages.contains(&package_id) {
+            if let Some(count) = read_count(&directory.join("package_throttle_count")) {
+                result.get_or_insert(CpuThrottleCounts::default()).package_count += count;
+                counted_packages.insert(package_id);
+            }
+        }
+    }
+
+    result
+}
@@ -4,7 +4,9 @@ use windows::{
     Win32::System::Wmi::*,
 };
 
-pub fn get_cpu_current_celsius_temperature_using_wmi(server: &IWbemServices) -> Vec<f32> {
+use crate::units::MeasurementUnit;
+
+pub fn get_cpu_current_kelvin_temperature_using_wmi(server: &IWbemServices) -> Vec<f32> {
     unsafe {
         let query = server.ExecQuery(
             &BSTR::from("WQL"),
@@ -35,7 +37,7 @@ pub fn get_cpu_current_celsius_temperature_using_wmi(server: &IWbemServices) ->
                         if !value.is_empty() {
                             match value.to_string().parse::<f32>() {
                                 Ok(value) => {
-                                    result_arr.push(value - 273.15);
+                                    result_arr.push(value);
                                 },
                                 Err(_) => {}
                             }
@@ -67,10 +69,10 @@ pub fn get_cpu_current_celsius_temperature() -> f32 {
 
     let drained_data: String = data.drain(temp_index..).collect();
 
-    drained_data.trim().parse::<f32>().unwrap() - 273.15
+    MeasurementUnit::Kelvin.convert(drained_data.trim().parse::<f32>().unwrap(), MeasurementUnit::Celsius)
 }
 
-pub fn get_cpu_name(server: &IWbemServices) -> String {
+pub fn get_cpu_name(server: &IWbemServices) -> Option<String> {
     unsafe {
         let query = server.ExecQuery(
             &BSTR::from("WQL"),
@@ -96,14 +98,14 @@ pub fn get_cpu_name(server: &IWbemServices) -> String {
                     0
                 ) {
                     Ok(value) => {
-                        break value.to_string();
+                        break Some(value.to_string());
                     },
                     Err(_) => {}
                 }
 
                 VariantClear(&mut value).unwrap();
             } else {
-                break "інформація відсутня".to_string();
+                break None;
             }
         }
     }
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+use zbus::blocking::{Connection, ConnectionBuilder};
+use zbus::zvariant::Value;
+
+const NOTIFICATIONS_DESTINATION: &str = "org.freedesktop.Notifications";
+const NOTIFICATIONS_PATH: &str = "/org/freedesktop/Notifications";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NotificationUrgency {
+    Low,
+    Normal,
+    Critical
+}
+
+impl NotificationUrgency {
+    // значення підказки "urgency" зі специфікації freedesktop notifications
+    fn as_byte(&self) -> u8 {
+        match self {
+            NotificationUrgency::Low => 0,
+            NotificationUrgency::Normal => 1,
+            NotificationUrgency::Critical => 2
+        }
+    }
+}
+
+struct NotificationRequest {
+    key: String,
+    summary: String,
+    body: String,
+    urgency: NotificationUrgency
+}
+
+// виклик Notify блокує, поки демон не відповість, тому він робиться в окремому потоці, а не в update() інтерфейсу
+pub struct DesktopNotifier {
+    sender: Sender<NotificationRequest>,
+    pub urgency: NotificationUrgency,
+    pub deduplication_interval: Duration,
+    // ключ сповіщення -> час останнього показу
+    sent_notifications: HashMap<String, Instant>
+}
+
+impl Default for DesktopNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DesktopNotifier {
+    // підключається до сесійної шини, адресу якої бере з DBUS_SESSION_BUS_ADDRESS
+    pub fn new() -> Self {
+        Self::spawn(|| Connection::session().ok())
+    }
+
+    // дозволяє підключитися до окремого dbus-daemon, наприклад запущеного локально для перевірки
+    pub fn with_address(address: &str) -> Self {
+        let address = address.to_string();
+
+        Self::spawn(move || match ConnectionBuilder::address(address.as_str()) {
+            Ok(builder) => builder.build().ok(),
+            Err(_) => None
+        })
+    }
+
+    // підключення теж може зависнути, тому і воно відбувається в потоці сповіщень
+    fn spawn(connect: impl FnOnce() -> Option<Connection> + Send + 'static) -> Self {
+        let (sender, receiver) = channel();
+
+        std::thread::spawn(move || run_notification_worker(connect(), receiver));
+
+        Self {
+            sender,
+            urgency: NotificationUrgency::Critical,
+            deduplication_interval: Duration::from_secs(60),
+            sent_notifications: HashMap::new()
+        }
+    }
+
+    pub fn notify(&mut self, key: &str, summary: &str, body: &str) {
+        if let Some(sent_at) = self.sent_notifications.get(key) {
+            if sent_at.elapsed() < self.deduplication_interval {
+                return;
+            }
+        }
+
+        self.sent_notifications.insert(key.to_string(), Instant::now());
+
+        let request = NotificationRequest {
+            key: key.to_string(),
+            summary: summary.to_string(),
+            body: body.to_string(),
+            urgency: self.urgency
+        };
+
+        if self.sender.send(request).is_err() {
+            log::error!("{}: {}", summary, body);
+        }
+    }
+}
+
+fn run_notification_worker(connection: Option<Connection>, receiver: Receiver<NotificationRequest>) {
+    // без демона сповіщень попередження все одно має бути видно, а env_logger за замовчуванням показує лише помилки
+    if connection.is_none() {
+        log::error!("сесійна шина D-Bus недоступна, сповіщення будуть записуватися лише в лог");
+    }
+
+    // ключ сповіщення -> id, який демон повернув для нього
+    let mut notification_ids: HashMap<String, u32> = HashMap::new();
+
+    for request in receiver {
+        // замінюю попереднє сповіщення з тим самим ключем замість того, щоб додавати ще одне
+        let replaces_id = notification_ids.get(&request.key).copied().unwrap_or(0);
+
+        match send_notification(connection.as_ref(), replaces_id, &request) {
+            Ok(id) => {
+                notification_ids.insert(request.key, id);
+            },
+            Err(error) => {
+                log::error!("{}: {} ({})", request.summary, request.body, error);
+            }
+        }
+    }
+}
+
+fn send_notification(connection: Option<&Connection>, replaces_id: u32, request: &NotificationRequest) -> zbus::Result<u32> {
+    let connection = match connection {
+        Some(connection) => connection,
+        None => return Err(zbus::Error::Failure("немає підключення до D-Bus".to_string()))
+    };
+
+    let mut hints: HashMap<&str, Value> = HashMap::new();
+    hints.insert("urgency", Value::from(request.urgency.as_byte()));
+
+    let actions: Vec<&str> = Vec::new();
+
+    let reply = connection.call_method(
+        Some(NOTIFICATIONS_DESTINATION),
+        NOTIFICATIONS_PATH,
+        Some(NOTIFICATIONS_DESTINATION),
+        "Notify",
+        &("Resource monitor", replaces_id, "", request.summary.as_str(), request.body.as_str(), actions, hints, -1i32),
+    )?;
+
+    reply.body::<u32>()
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::hwmon::{get_drive_device_name, read_trimmed};
+
+pub const DISKSTATS_PATH: &str = "/proc/diskstats";
+pub const BLOCK_DEVICES_DIRECTORY: &str = "/sys/block";
+
+// розмір сектора в /proc/diskstats завжди 512 байт, незалежно від справжнього розміру сектора диска
+const DISKSTATS_SECTOR_SIZE: f64 = 512.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiskCounters {
+    pub name: String,
+    pub reads_completed: u64,
+    pub sectors_read: u64,
+    pub writes_completed: u64,
+    pub sectors_written: u64
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiskRates {
+    pub name: String,
+    pub read_bytes_per_second: f32,
+    pub write_bytes_per_second: f32,
+    pub read_operations_per_second: f32,
+    pub write_operations_per_second: f32
+}
+
+// рядок: major minor name reads_completed reads_merged sectors_read time_reading writes_completed writes_merged sectors_written ...
+pub fn parse_diskstats(data: &str) -> Vec<DiskCounters> {
+    let mut result_arr = Vec::new();
+
+    for line in data.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        if fields.len() < 10 {
+            continue;
+        }
+
+        let parse = |index: usize| fields[index].parse::<u64>().ok();
+
+        if let (Some(reads_completed), Some(sectors_read), Some(writes_completed), Some(sectors_written)) = (parse(3), parse(5), parse(7), parse(9)) {
+            result_arr.push(DiskCounters {
+                name: fields[2].to_string(),
+                reads_completed,
+                sectors_read,
+                writes_completed,
+                sectors_written
+            });
+        }
+    }
+
+    result_arr
+}
+
+// у /sys/block є лише цілі диски, тому так відкидаються розділи; loop та ram - це не справжні накопичувачі,
+// а dm-* (LVM, LUKS) та md* (програмний RAID) лише передають запити дискам під ними, які вже пораховані
+pub fn is_physical_block_device(block_directory: &Path, name: &str) -> bool {
+    ["loop", "ram", "zram", "dm-", "md"].iter().all(|prefix| !name.starts_with(prefix)) && block_directory.join(name).exists()
+}
+
+pub fn get_block_device_model(block_directory: &Path, name: &str) -> Option<String> {
+    read_trimmed(&block_directory.join(name).join("device").join("model")).filter(|model| !model.is_empty())
+}
+
+pub fn get_disk_device_name(block_directory: &Path, name: &str) -> String {
+    get_drive_device_name(get_block_device_model(block_directory, name).as_deref(), name)
+}
+
+pub fn get_disk_counters() -> Vec<DiskCounters> {
+    let data = match fs::read_to_string(DISKSTATS_PATH) {
+        Ok(data) => data,
+        Err(_) => return Vec::new()
+    };
+
+    parse_diskstats(&data)
+        .into_iter()
+        .filter(|counters| is_physical_block_device(Path::new(BLOCK_DEVICES_DIRECTORY), &counters.name))
+        .collect()
+}
+
+// швидкості - це різниці лічильників між відліками, тому перший відлік лише запам'ятовується
+#[derive(Default)]
+pub struct DiskRateMeter {
+    previous: HashMap<String, (DiskCounters, f64)>
+}
+
+impl DiskRateMeter {
+    pub fn update(&mut self, counters: Vec<DiskCounters>, timestamp: f64) -> Vec<DiskRates> {
+        let mut result_arr = Vec::new();
+
+        for counters in counters {
+            if let Some((previous, previous_timestamp)) = self.previous.get(&counters.name) {
+                let elapsed = timestamp - previous_timestamp;
+
+                // лічильники скидаються, коли диск від'єднали та під'єднали знову, тоді відлік пропускаю
+                let deltas = (
+                    counters.sectors_read.checked_sub(previous.sectors_read),
+                    counters.sectors_written.checked_sub(previous.sectors_written),
+                    counters.reads_completed.checked_sub(previous.reads_completed),
+                    counters.writes_completed.checked_sub(previous.writes_completed)
+                );
+
+                if let (true, (Some(sectors_read), Some(sectors_written), Some(reads), Some(writes))) = (elapsed > 0.0, deltas) {
+                    result_arr.push(DiskRates {
+                        name: counters.name.clone(),
+                        read_bytes_per_second: (sectors_read as f64 * DISKSTATS_SECTOR_SIZE / elapsed) as f32,
+                        write_bytes_per_second: (sectors_written as f64 * DISKSTATS_SECTOR_SIZE / elapsed) as f32,
+                        read_operations_per_second: (reads as f64 / elapsed) as f32,
+                        write_operations_per_second: (writes as f64 / elapsed) as f32
+                    });
+                }
+            }
+
+            self.previous.insert(counters.name.clone(), (counters, timestamp));
+        }
+
+        result_arr
+    }
+}
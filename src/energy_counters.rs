@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::hwmon::{get_hwmon_chip_name, get_hwmon_directories, get_hwmon_indexes, read_trimmed, HWMON_DIRECTORY};
+
+pub const POWERCAP_DIRECTORY: &str = "/sys/class/powercap";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnergyCounter {
+    pub sensor: String,
+    pub microjoules: u64,
+    // лічильники RAPL переповнюються на цьому значенні, а лічильники amd_energy 64-бітні й не переповнюються
+    pub max_microjoules: Option<u64>
+}
+
+// зони RAPL лежать пласко: intel-rapl:0 - пакет, intel-rapl:0:0 - його підзона (core, uncore, dram)
+pub fn read_rapl_counters(powercap_directory: &Path) -> Vec<EnergyCounter> {
+    let mut zones: Vec<String> = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(powercap_directory) {
+        for entry in entries.flatten() {
+            let zone = entry.file_name().to_string_lossy().to_string();
+
+            // intel-rapl-mmio дублює той самий пакет через mmio, тому беру лише intel-rapl:*
+            if zone.starts_with("intel-rapl:") {
+                zones.push(zone);
+            }
+        }
+    }
+
+    zones.sort();
+
+    let mut result_arr = Vec::new();
+
+    for zone in &zones {
+        let directory = powercap_directory.join(zone);
+
+        let microjoules = match read_trimmed(&directory.join("energy_uj")).and_then(|value| value.parse::<u64>().ok()) {
+            Some(microjoules) => microjoules,
+            None => continue
+        };
+
+        let name = read_trimmed(&directory.join("name")).unwrap_or(zone.clone());
+
+        // у кожного пакета є свої core та dram, тому до назви підзони додаю назву пакета
+        let sensor = match zone.rfind(':') {
+            Some(index) if zone[..index].contains(':') => {
+                let parent_name = read_trimmed(&powercap_directory.join(&zone[..index]).join("name")).unwrap_or(zone[..index].to_string());
+
+                format!("{} {}", parent_name, name)
+            },
+            _ => name
+        };
+
+        result_arr.push(EnergyCounter {
+            sensor,
+            microjoules,
+            max_microjoules: read_trimmed(&directory.join("max_energy_range_uj")).and_then(|value| value.parse::<u64>().ok())
+        });
+    }
+
+    result_arr
+}
+
+// energyN_input драйвера amd_energy (та інших чипів hwmon з лічильниками енергії) у мікроджоулях
+pub fn read_hwmon_energy_counters(directory: &Path) -> Vec<EnergyCounter> {
+    let mut result_arr = Vec::new();
+
+    let chip_name = get_hwmon_chip_name(directory);
+
+    for index in get_hwmon_indexes(directory, "energy", "_input").unwrap_or_default() {
+        let microjoules = match read_trimmed(&directory.join(format!("energy{}_input", index))).and_then(|value| value.parse::<u64>().ok()) {
+            Some(microjoules) => microjoules,
+            None => continue
+        };
+
+        let label = read_trimmed(&directory.join(format!("energy{}_label", index)))
+            .unwrap_or(format!("energy{}", index));
+
+        result_arr.push(EnergyCounter {
+            sensor: format!("{} {}", chip_name, label),
+            microjoules,
+            max_microjoules: None
+        });
+    }
+
+    result_arr
+}
+
+pub fn get_energy_counters() -> Vec<EnergyCounter> {
+    let mut result_arr = read_rapl_counters(Path::new(POWERCAP_DIRECTORY));
+
+    for directory in get_hwmon_directories(Path::new(HWMON_DIRECTORY)) {
+        result_arr.extend(read_hwmon_energy_counters(&directory));
+    }
+
+    result_arr
+}
+
+// спожита між двома відліками енергія; None, якщо лічильник скинувся і межа переповнення невідома
+pub fn get_energy_delta(previous: u64, current: u64, max_microjoules: Option<u64>) -> Option<u64> {
+    if current >= previous {
+        return Some(current - previous);
+    }
+
+    match max_microjoules {
+        Some(max_microjoules) if previous <= max_microjoules => Some(max_microjoules - previous + current),
+        _ => None
+    }
+}
+
+// потужність - це різниця лічильника між відліками, поділена на час між ними, тому перший відлік лише запам'ятовується
+#[derive(Default)]
+pub struct EnergyPowerMeter {
+    previous: HashMap<String, (u64, f64)>
+}
+
+impl EnergyPowerMeter {
+    pub fn update(&mut self, counters: Vec<EnergyCounter>, timestamp: f64) -> Vec<(String, f32)> {
+        let mut result_arr = Vec::new();
+
+        for counter in counters {
+            if let Some((previous_microjoules, previous_timestamp)) = self.previous.get(&counter.sensor) {
+                let elapsed = timestamp - previous_timestamp;
+
+                if elapsed > 0.0 {
+                    if let Some(delta) = get_energy_delta(*previous_microjoules, counter.microjoules, counter.max_microjoules) {
+                        result_arr.push((counter.sensor.clone(), (delta as f64 / 1000000.0 / elapsed) as f32));
+                    }
+                }
+            }
+
+            self.previous.insert(counter.sensor, (counter.microjoules, timestamp));
+        }
+
+        result_arr
+    }
+}
@@ -5,7 +5,7 @@ use nvml_wrapper::{Nvml};
 
 pub fn get_gpu_current_celsius_temperature() -> f32 {
     let data = Command::new("powershell")
-        .args(&[
+        .args([
             "/C",
             r"nvidia-smi --query-gpu=temperature.gpu --format=csv,noheader"
         ])
@@ -28,9 +28,7 @@ pub fn get_gpu_current_celsius_temperature_nvml(nvml: &mut Nvml) -> f32 {
 pub fn get_gpu_name_nvml(nvml: &mut Nvml) -> String {
     let device = nvml.device_by_index(0).unwrap();
 
-    let temperature = device.name().unwrap();
-
-    temperature
+    device.name().unwrap()
 }
 
 /*pub fn get_gpu_current_celsius_temperature() -> f32 {
@@ -0,0 +1,96 @@
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::cgroup::open_cgroup_monitor_if_requested;
+use crate::cli_arguments::CliArguments;
+use crate::metrics_exporter::{start_metrics_server_if_requested, update_metrics};
+use crate::sensors::{initialize_com, SensorCollector};
+use crate::session_recording::{get_current_unix_timestamp, SessionRecorder};
+use crate::tiered_storage::{get_default_storage_directory, TieredStorage};
+
+// режим без вікна: лише опитує сенсори, віддає їх через /metrics, а якщо експортер не запущений - друкує в stdout
+pub fn run_headless(arguments: &CliArguments) -> io::Result<()> {
+    initialize_com();
+
+    let mut session_recorder = match &arguments.recording_path {
+        Some(path) => Some(SessionRecorder::create(&PathBuf::from(path)).map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path, error)))?),
+        None => None
+    };
+
+    let mut sensor_collector = SensorCollector::new();
+
+    sensor_collector.set_cgroup_monitor(open_cgroup_monitor_if_requested(&arguments.cgroup));
+
+    let metrics = start_metrics_server_if_requested(&arguments.metrics_address);
+
+    let storage_directory = match &arguments.storage_directory {
+        Some(directory) => PathBuf::from(directory),
+        None => get_default_storage_directory()
+    };
+
+    let mut tiered_storage = match TieredStorage::open(&storage_directory) {
+        Ok(tiered_storage) => Some(tiered_storage),
+        Err(error) => {
+            log::warn!("довгострокова історія вимкнена, не вдалося відкрити {}: {}", storage_directory.display(), error);
+
+            None
+        }
+    };
+
+    // SIGINT та SIGTERM (а на windows Ctrl+C) лише просять зупинитися, щоб незакриті кошики встигли потрапити на диск
+    let is_stop_requested = Arc::new(AtomicBool::new(false));
+
+    let handler_stop_requested = is_stop_requested.clone();
+
+    ctrlc::set_handler(move || handler_stop_requested.store(true, Ordering::SeqCst)).map_err(io::Error::other)?;
+
+    while !is_stop_requested.load(Ordering::SeqCst) {
+        let scrape_started = Instant::now();
+
+        let readings = sensor_collector.read_all_sensors();
+
+        let timestamp = get_current_unix_timestamp();
+
+        if let Some(recorder) = &mut session_recorder {
+            match recorder.record_sample(timestamp, &readings) {
+                Ok(_) => {},
+                Err(error) => {
+                    log::error!("запис сесії зупинено: {}", error);
+
+                    session_recorder = None;
+                }
+            }
+        }
+
+        if let Some(tiered_storage) = &mut tiered_storage {
+            match tiered_storage.push_sample(timestamp, &readings) {
+                Ok(_) => {},
+                Err(error) => log::warn!("не вдалося записати довгострокову історію: {}", error)
+            }
+        }
+
+        match &metrics {
+            Some(metrics) => {
+                update_metrics(metrics, &readings, scrape_started.elapsed(), sensor_collector.read_errors_total);
+            },
+            None => {
+                for reading in &readings {
+                    let unit = reading.kind.display_unit(arguments.temperature_unit);
+
+                    println!("{} / {}: {}", reading.device, reading.sensor, unit.format_value(reading.kind.to_display_value(reading.value, arguments.temperature_unit) as f64, 1));
+                }
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(arguments.delay_between_temperature_requests));
+    }
+
+    if let Some(tiered_storage) = &mut tiered_storage {
+        tiered_storage.flush_open_buckets()?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
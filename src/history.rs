@@ -0,0 +1,163 @@
+use crate::sensors::{SensorKind, SensorReading};
+use crate::session_recording::RecordedSeries;
+
+pub struct HistorySeries {
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    // значення з тими самими індексами, що й History::timestamps, NaN якщо сенсор не відповів
+    pub values: Vec<f32>
+}
+
+#[derive(Default)]
+pub struct History {
+    pub timestamps: Vec<f64>,
+    pub series: Vec<HistorySeries>
+}
+
+impl History {
+    pub fn push_sample(&mut self, timestamp: f64, readings: &[SensorReading], amount_of_stored_data: usize) {
+        self.timestamps.push(timestamp);
+
+        for reading in readings {
+            let is_known = self.series
+                .iter()
+                .any(|series| series.device == reading.device && series.sensor == reading.sensor && series.kind == reading.kind);
+
+            if !is_known {
+                // сенсор з'явився посеред сесії, тому раніші значення для нього невідомі
+                self.series.push(HistorySeries {
+                    device: reading.device.clone(),
+                    sensor: reading.sensor.clone(),
+                    kind: reading.kind,
+                    values: vec![f32::NAN; self.timestamps.len() - 1]
+                });
+            }
+        }
+
+        for series in &mut self.series {
+            let value = readings
+                .iter()
+                .find(|reading| reading.device == series.device && reading.sensor == series.sensor && reading.kind == series.kind)
+                .map(|reading| reading.value)
+                .unwrap_or(f32::NAN);
+
+            series.values.push(value);
+        }
+
+        if self.timestamps.len() > amount_of_stored_data {
+            let excess = self.timestamps.len() - amount_of_stored_data;
+
+            self.timestamps.drain(..excess);
+
+            for series in &mut self.series {
+                series.values.drain(..excess);
+            }
+        }
+    }
+
+    pub fn find_series(&self, device: &str, kind: SensorKind) -> Option<&HistorySeries> {
+        self.series.iter().find(|series| series.device == device && series.kind == kind)
+    }
+
+    // останнє відоме значення сенсора, пропущені відліки в кінці не враховуються
+    pub fn latest_value(&self, device: &str, sensor: &str, kind: SensorKind) -> Option<f32> {
+        let series = self.series.iter().find(|series| series.device == device && series.sensor == sensor && series.kind == kind)?;
+
+        series.values.iter().rev().find(|value| !value.is_nan()).copied()
+    }
+
+    // проміжки (відносно reference_timestamp), коли прапорець був увімкнений; відлік описує час від попереднього відліку
+    pub fn active_intervals(&self, series: &HistorySeries, reference_timestamp: f64) -> Vec<[f64; 2]> {
+        let mut result = Vec::new();
+        let mut current: Option<[f64; 2]> = None;
+
+        for (index, timestamp) in self.timestamps.iter().enumerate() {
+            if series.values[index] >= 0.5 {
+                let start = if index > 0 { self.timestamps[index - 1] } else { *timestamp };
+
+                current = match current {
+                    Some([start, _]) => Some([start, *timestamp]),
+                    None => Some([start, *timestamp])
+                };
+            } else if let Some(interval) = current.take() {
+                result.push(interval);
+            }
+        }
+
+        if let Some(interval) = current {
+            result.push(interval);
+        }
+
+        result
+            .into_iter()
+            .map(|[start, end]| [start - reference_timestamp, end - reference_timestamp])
+            .collect()
+    }
+
+    // моменти (відносно reference_timestamp), коли прапорець змінився, разом з новим станом
+    pub fn flag_changes(&self, series: &HistorySeries, reference_timestamp: f64) -> Vec<(f64, bool)> {
+        let mut result = Vec::new();
+        let mut previous_state: Option<bool> = None;
+
+        for (index, timestamp) in self.timestamps.iter().enumerate() {
+            let value = series.values[index];
+
+            if value.is_nan() {
+                continue;
+            }
+
+            let state = value >= 0.5;
+
+            if previous_state.is_some_and(|previous_state| previous_state != state) {
+                result.push((timestamp - reference_timestamp, state));
+            }
+
+            previous_state = Some(state);
+        }
+
+        result
+    }
+
+    // точки для графіка, вісь x - секунди відносно reference_timestamp, невідомі значення пропускаються
+    pub fn series_points(&self, series: &HistorySeries, reference_timestamp: f64) -> Vec<[f64; 2]> {
+        self.timestamps
+            .iter()
+            .zip(series.values.iter())
+            .filter(|(_, value)| !value.is_nan())
+            .map(|(timestamp, value)| [*timestamp - reference_timestamp, *value as f64])
+            .collect()
+    }
+
+    pub fn find_nearest_index(&self, timestamp: f64) -> Option<usize> {
+        if self.timestamps.is_empty() {
+            return None;
+        }
+
+        let index = self.timestamps.partition_point(|value| *value < timestamp);
+
+        if index == 0 {
+            Some(0)
+        } else if index == self.timestamps.len() || timestamp - self.timestamps[index - 1] < self.timestamps[index] - timestamp {
+            Some(index - 1)
+        } else {
+            Some(index)
+        }
+    }
+
+    pub fn to_recorded_series(&self) -> Vec<RecordedSeries> {
+        self.series
+            .iter()
+            .map(|series| RecordedSeries {
+                device: series.device.clone(),
+                sensor: series.sensor.clone(),
+                kind: series.kind,
+                points: self.timestamps
+                    .iter()
+                    .zip(series.values.iter())
+                    .map(|(timestamp, value)| [*timestamp, *value as f64])
+                    .collect()
+            })
+            .collect()
+    }
+}
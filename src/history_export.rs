@@ -0,0 +1,153 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::session_recording::{get_current_unix_timestamp, load_recording, RecordedSeries};
+use crate::units::MeasurementUnit;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ExportFormat {
+    Csv,
+    Json
+}
+
+impl ExportFormat {
+    // формат визначається за розширенням файлу, все, що не .json, пишеться як csv
+    pub fn from_path(path: &Path) -> ExportFormat {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => ExportFormat::Json,
+            _ => ExportFormat::Csv
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json"
+        }
+    }
+}
+
+pub struct ExportMetadata {
+    pub host: String,
+    pub app_version: String,
+    pub sampling_interval_ms: u64,
+    pub exported_at: f64,
+    // температури в експорті перераховуються в цю одиницю
+    pub temperature_unit: MeasurementUnit
+}
+
+impl ExportMetadata {
+    pub fn new(sampling_interval_ms: u64, temperature_unit: MeasurementUnit) -> Self {
+        Self {
+            host: get_host_name(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            sampling_interval_ms,
+            exported_at: get_current_unix_timestamp(),
+            temperature_unit
+        }
+    }
+}
+
+pub fn get_host_name() -> String {
+    if let Ok(host) = std::fs::read_to_string("/proc/sys/kernel/hostname") {
+        return host.trim().to_string();
+    }
+
+    std::env::var("COMPUTERNAME")
+        .or(std::env::var("HOSTNAME"))
+        .unwrap_or("unknown".to_string())
+}
+
+pub fn export_history(path: &Path, format: ExportFormat, series: &[RecordedSeries], metadata: &ExportMetadata) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    match format {
+        ExportFormat::Csv => write_csv(&mut writer, series, metadata)?,
+        ExportFormat::Json => write_json(&mut writer, series, metadata)?
+    }
+
+    writer.flush()
+}
+
+// перетворює файл запису сесії на csv/json без відкриття вікна
+pub fn export_recording(recording_path: &Path, export_path: &Path, temperature_unit: MeasurementUnit) -> io::Result<()> {
+    let recording = load_recording(recording_path)?;
+
+    let samples_count = recording.series.iter().map(|series| series.points.len()).max().unwrap_or(0);
+
+    // інтервал у файлі запису не зберігається, тому рахую середній
+    let sampling_interval_ms = if samples_count > 1 {
+        ((recording.end_timestamp - recording.start_timestamp) / (samples_count - 1) as f64 * 1000.0) as u64
+    } else {
+        0
+    };
+
+    export_history(export_path, ExportFormat::from_path(export_path), &recording.series, &ExportMetadata::new(sampling_interval_ms, temperature_unit))
+}
+
+fn escape_csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// довгий формат (один рядок - одне значення), його без змін читає і pandas, і електронні таблиці
+pub fn write_csv(writer: &mut impl Write, series: &[RecordedSeries], metadata: &ExportMetadata) -> io::Result<()> {
+    writeln!(writer, "# host: {}", metadata.host)?;
+    writeln!(writer, "# app_version: {}", metadata.app_version)?;
+    writeln!(writer, "# sampling_interval_ms: {}", metadata.sampling_interval_ms)?;
+    writeln!(writer, "# exported_at: {:.3}", metadata.exported_at)?;
+    writeln!(writer, "# temperature_unit: {}", metadata.temperature_unit.name())?;
+    writeln!(writer, "timestamp,device,sensor,kind,unit,value")?;
+
+    for series in series {
+        let device = escape_csv_field(&series.device);
+        let sensor = escape_csv_field(&series.sensor);
+        let unit = series.kind.display_unit(metadata.temperature_unit).symbol();
+
+        for point in &series.points {
+            if point[1].is_nan() {
+                writeln!(writer, "{:.3},{},{},{},{},", point[0], device, sensor, series.kind.name(), unit)?;
+            } else {
+                writeln!(writer, "{:.3},{},{},{},{},{}", point[0], device, sensor, series.kind.name(), unit, series.kind.to_display_value(point[1] as f32, metadata.temperature_unit))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn write_json(writer: &mut impl Write, series: &[RecordedSeries], metadata: &ExportMetadata) -> io::Result<()> {
+    let series: Vec<serde_json::Value> = series
+        .iter()
+        .map(|series| serde_json::json!({
+            "device": series.device,
+            "sensor": series.sensor,
+            "kind": series.kind.name(),
+            "unit": series.kind.display_unit(metadata.temperature_unit).symbol(),
+            // NaN у json не існує, тому serde_json записує його як null
+            "samples": series.points
+                .iter()
+                .map(|point| serde_json::json!([point[0], series.kind.to_display_value(point[1] as f32, metadata.temperature_unit)]))
+                .collect::<Vec<serde_json::Value>>()
+        }))
+        .collect();
+
+    let document = serde_json::json!({
+        "metadata": {
+            "host": metadata.host,
+            "app_version": metadata.app_version,
+            "sampling_interval_ms": metadata.sampling_interval_ms,
+            "exported_at": metadata.exported_at,
+            "temperature_unit": metadata.temperature_unit.name()
+        },
+        "series": series
+    });
+
+    serde_json::to_writer_pretty(&mut *writer, &document)?;
+
+    writeln!(writer)
+}
@@ -0,0 +1,266 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// назви драйверів hwmon, які віддають температуру саме процесора
+pub const CPU_HWMON_CHIP_NAMES: [&str; 4] = ["coretemp", "k10temp", "zenpower", "cpu_thermal"];
+
+pub const HWMON_DIRECTORY: &str = "/sys/class/hwmon";
+
+// назви драйверів hwmon, які віддають температуру накопичувачів
+pub const DRIVE_HWMON_CHIP_NAMES: [&str; 2] = ["nvme", "drivetemp"];
+
+pub struct HwmonTemperature {
+    pub chip_name: String,
+    // назва накопичувача з get_hwmon_drive_name, лише для чипів з DRIVE_HWMON_CHIP_NAMES
+    pub drive_name: Option<String>,
+    // номер процесора з get_hwmon_cpu_packages
+    pub cpu_package: Option<usize>,
+    pub label: String,
+    pub celsius: f32
+}
+
+pub struct HwmonFan {
+    pub chip_name: String,
+    pub label: String,
+    pub rpm: Option<f32>,
+    // заповнення ШІМ у відсотках, сам файл pwmN містить значення від 0 до 255
+    pub duty_percent: Option<f32>
+}
+
+pub fn read_trimmed(path: &Path) -> Option<String> {
+    match fs::read_to_string(path) {
+        Ok(value) => Some(value.trim().to_string()),
+        Err(_) => None
+    }
+}
+
+pub fn get_hwmon_directories(hwmon_directory: &Path) -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(hwmon_directory) {
+        for entry in entries.flatten() {
+            directories.push(entry.path());
+        }
+    }
+
+    directories.sort();
+
+    directories
+}
+
+pub fn get_hwmon_chip_name(directory: &Path) -> String {
+    read_trimmed(&directory.join("name")).unwrap_or_default()
+}
+
+// номери каналів, для яких у теці чипа є файл prefix{N}suffix, наприклад temp{N}_input
+pub fn get_hwmon_indexes(directory: &Path, prefix: &str, suffix: &str) -> Option<Vec<u32>> {
+    let mut indexes: Vec<u32> = Vec::new();
+
+    for entry in fs::read_dir(directory).ok()?.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        if let Some(index) = file_name.strip_prefix(prefix).and_then(|rest| rest.strip_suffix(suffix)) {
+            if let Ok(index) = index.parse::<u32>() {
+                indexes.push(index);
+            }
+        }
+    }
+
+    indexes.sort();
+
+    Some(indexes)
+}
+
+// і контролер nvme, і scsi пристрій drivetemp мають файл model поруч
+pub fn get_hwmon_device_model(directory: &Path) -> Option<String> {
+    read_trimmed(&directory.join("device").join("model")).filter(|model| !model.is_empty())
+}
+
+// drivetemp: блочний пристрій лежить у device/block/sdX;
+// nvme: device - це контролер nvmeX, а блочні пристрої - його простори імен nvmeXnY (nvmeXcYnZ - приховані шляхи multipath)
+pub fn get_hwmon_block_device_name(directory: &Path) -> Option<String> {
+    let device_directory = directory.join("device");
+
+    let mut names: Vec<String> = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(device_directory.join("block")) {
+        names.extend(entries.flatten().map(|entry| entry.file_name().to_string_lossy().to_string()));
+    }
+
+    if names.is_empty() {
+        if let Ok(entries) = fs::read_dir(&device_directory) {
+            names.extend(entries.flatten()
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| name.strip_prefix("nvme").map(|rest| rest.contains('n') && !rest.contains('c')).unwrap_or(false)));
+        }
+    }
+
+    names.sort();
+
+    names.into_iter().next()
+}
+
+// однаково для температур, порогів і лічильників вводу-виводу, щоб накопичувач був одним пристроєм,
+// а назва блочного пристрою розрізняє два однакові диски
+pub fn get_drive_device_name(device_model: Option<&str>, block_device_name: &str) -> String {
+    match device_model {
+        Some(model) => format!("{} ({})", model, block_device_name),
+        None => block_device_name.to_string()
+    }
+}
+
+pub fn get_hwmon_drive_name(directory: &Path, chip_name: &str) -> Option<String> {
+    if !DRIVE_HWMON_CHIP_NAMES.contains(&chip_name) {
+        return None;
+    }
+
+    let device_model = get_hwmon_device_model(directory);
+
+    match get_hwmon_block_device_name(directory) {
+        Some(block_device_name) => Some(get_drive_device_name(device_model.as_deref(), &block_device_name)),
+        None => device_model
+    }
+}
+
+pub fn get_hwmon_device_name(chip_name: &str, drive_name: Option<&str>) -> String {
+    if CPU_HWMON_CHIP_NAMES.contains(&chip_name) {
+        "cpu".to_string()
+    } else {
+        drive_name.unwrap_or(chip_name).to_string()
+    }
+}
+
+// на кількох процесорах кожен має свій чип coretemp чи k10temp з тими самими мітками ("Core 0", "Tctl"),
+// тому такі чипи нумерую по порядку; з одним процесором номер не потрібен і мітки лишаються як були
+pub fn get_hwmon_cpu_packages(directories: &[PathBuf]) -> Vec<Option<usize>> {
+    let is_cpu_arr: Vec<bool> = directories
+        .iter()
+        .map(|directory| CPU_HWMON_CHIP_NAMES.contains(&get_hwmon_chip_name(directory).as_str()))
+        .collect();
+
+    if is_cpu_arr.iter().filter(|is_cpu| **is_cpu).count() < 2 {
+        return vec![None; directories.len()];
+    }
+
+    let mut package = 0;
+
+    is_cpu_arr
+        .into_iter()
+        .map(|is_cpu| {
+            if !is_cpu {
+                return None;
+            }
+
+            package += 1;
+
+            Some(package - 1)
+        })
+        .collect()
+}
+
+pub fn get_hwmon_sensor_name(label: &str, cpu_package: Option<usize>) -> String {
+    match cpu_package {
+        Some(package) => format!("CPU {} {}", package, label),
+        None => label.to_string()
+    }
+}
+
+pub fn get_hwmon_temperatures(hwmon_directory: &Path) -> Vec<HwmonTemperature> {
+    let mut result_arr = Vec::new();
+
+    let directories = get_hwmon_directories(hwmon_directory);
+
+    for (directory, cpu_package) in directories.iter().zip(get_hwmon_cpu_packages(&directories)) {
+        let chip_name = get_hwmon_chip_name(directory);
+
+        let drive_name = get_hwmon_drive_name(directory, &chip_name);
+
+        let indexes = match get_hwmon_indexes(directory, "temp", "_input") {
+            Some(indexes) => indexes,
+            None => continue
+        };
+
+        for index in indexes {
+            let value = match read_trimmed(&directory.join(format!("temp{}_input", index))) {
+                Some(value) => value,
+                None => continue
+            };
+
+            if let Ok(millidegrees) = value.parse::<f32>() {
+                let label = read_trimmed(&directory.join(format!("temp{}_label", index)))
+                    .unwrap_or(format!("temp{}", index));
+
+                result_arr.push(HwmonTemperature {
+                    chip_name: chip_name.clone(),
+                    drive_name: drive_name.clone(),
+                    cpu_package,
+                    label,
+                    celsius: millidegrees / 1000.0
+                });
+            }
+        }
+    }
+
+    result_arr
+}
+
+// fanN_input та pwmN з однаковим номером зазвичай належать одному вентилятору, тому об'єдную їх за номером
+pub fn get_hwmon_fans(hwmon_directory: &Path) -> Vec<HwmonFan> {
+    let mut result_arr = Vec::new();
+
+    for directory in get_hwmon_directories(hwmon_directory) {
+        let chip_name = get_hwmon_chip_name(&directory);
+
+        let mut indexes = match get_hwmon_indexes(&directory, "fan", "_input") {
+            Some(indexes) => indexes,
+            None => continue
+        };
+
+        for index in get_hwmon_indexes(&directory, "pwm", "").unwrap_or_default() {
+            if !indexes.contains(&index) {
+                indexes.push(index);
+            }
+        }
+
+        indexes.sort();
+
+        for index in indexes {
+            let rpm = read_trimmed(&directory.join(format!("fan{}_input", index)))
+                .and_then(|value| value.parse::<f32>().ok());
+
+            let duty_percent = read_trimmed(&directory.join(format!("pwm{}", index)))
+                .and_then(|value| value.parse::<f32>().ok())
+                .map(|pwm| pwm / 255.0 * 100.0);
+
+            if rpm.is_none() && duty_percent.is_none() {
+                continue;
+            }
+
+            let label = read_trimmed(&directory.join(format!("fan{}_label", index)))
+                .unwrap_or(format!("fan{}", index));
+
+            result_arr.push(HwmonFan {
+                chip_name: chip_name.clone(),
+                label,
+                rpm,
+                duty_percent
+            });
+        }
+    }
+
+    result_arr
+}
+
+pub fn get_cpu_name_from_proc_cpuinfo() -> Option<String> {
+    let data = fs::read_to_string("/proc/cpuinfo").ok()?;
+
+    for line in data.lines() {
+        if line.starts_with("model name") {
+            if let Some(dots_index) = line.find(':') {
+                return Some(line[dots_index + 1..].trim().to_string());
+            }
+        }
+    }
+
+    None
+}
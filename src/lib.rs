@@ -1,4 +1,34 @@
+pub mod alerts;
+pub mod cgroup;
+pub mod cli_arguments;
+pub mod cpu_frequency;
+#[cfg(windows)]
 pub mod cpu_temperature;
+pub mod desktop_notifications;
+pub mod disk_statistics;
+pub mod energy_counters;
 pub mod gpu_temperature;
+pub mod headless;
+pub mod history;
+pub mod history_export;
+pub mod hwmon;
+pub mod localization;
+pub mod metrics_exporter;
+#[cfg(windows)]
 pub mod multi_threaded_implementation;
-pub mod single_threaded_implementation;
\ No newline at end of file
+pub mod network_statistics;
+pub mod power_supply;
+pub mod pressure_stall;
+pub mod process_statistics;
+pub mod scheduler_statistics;
+pub mod sensor_inventory;
+pub mod sensors;
+pub mod series_statistics;
+pub mod series_styles;
+pub mod session_recording;
+pub mod settings;
+pub mod single_threaded_implementation;
+pub mod terminal_ui;
+pub mod thermal_trip_points;
+pub mod tiered_storage;
+pub mod units;
\ No newline at end of file
@@ -0,0 +1,435 @@
+// всі тексти інтерфейсу зібрані тут, в ui використовуються лише ключі
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Language {
+    English,
+    Ukrainian
+}
+
+pub const LANGUAGES: [Language; 2] = [Language::English, Language::Ukrainian];
+
+impl Language {
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Ukrainian => "Українська"
+        }
+    }
+
+    pub fn messages(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => ENGLISH_MESSAGES,
+            Language::Ukrainian => UKRAINIAN_MESSAGES
+        }
+    }
+}
+
+// українська, якщо системна локаль українська (uk, uk-UA, uk_UA.UTF-8), інакше англійська
+pub fn detect_language() -> Language {
+    match sys_locale::get_locale() {
+        Some(locale) if locale.to_lowercase().starts_with("uk") => Language::Ukrainian,
+        _ => Language::English
+    }
+}
+
+pub fn tr(language: Language, key: &'static str) -> &'static str {
+    match language.messages().iter().find(|(message_key, _)| *message_key == key) {
+        Some((_, message)) => message,
+        None => match ENGLISH_MESSAGES.iter().find(|(message_key, _)| *message_key == key) {
+            Some((_, message)) => message,
+            None => key
+        }
+    }
+}
+
+// підставляє значення замість {name}, {value} тощо
+pub fn tr_with(language: Language, key: &'static str, arguments: &[(&str, &str)]) -> String {
+    let mut result = tr(language, key).to_string();
+
+    for (name, value) in arguments {
+        result = result.replace(&format!("{{{}}}", name), value);
+    }
+
+    result
+}
+
+pub const ENGLISH_MESSAGES: &[(&str, &str)] = &[
+    ("display.heading", "Display"),
+    ("display.gpu_temperature", "Show GPU sensors"),
+    ("display.cpu_temperature", "Show CPU sensors"),
+    ("display.trip_points", "Show trip points"),
+    ("display.trip_points_hint", "dotted lines on the temperature panel at which the hardware starts throttling or shuts down, read from the thermal zones, hwmon and NVML."),
+    ("display.statistics", "Show statistics"),
+    ("display.statistics_hint", "a table with the current, minimum, maximum and average value, the 95th percentile and the time above the alert threshold for every sensor."),
+    ("display.language", "Language"),
+    ("display.temperature_unit", "Temperature unit"),
+    ("period.label", "Period"),
+    ("period.live", "Live data"),
+    ("period.hour", "Last hour"),
+    ("period.day", "Last day"),
+    ("period.week", "Last week"),
+    ("period.hint", "the long-term history is stored on disk as 10 second, 1 minute and 10 minute buckets (minimum, maximum, average)."),
+    ("panels.heading", "Chart panels"),
+    ("panels.add", "Add panel"),
+    ("panels.move_up", "Move up"),
+    ("panels.move_down", "Move down"),
+    ("panels.remove", "Remove panel"),
+    ("panels.empty", "No chart panels, add one in the side panel."),
+    ("kind.temperature", "Temperature"),
+    ("kind.load", "Load"),
+    ("kind.power", "Power"),
+    ("kind.memory", "Memory"),
+    ("kind.fan_speed", "Fan speed"),
+    ("kind.fan_duty", "Fan duty"),
+    ("throttling.heading", "Throttling"),
+    ("throttling.hint", "Intervals when the CPU reported thermal throttle events or the GPU reported a thermal or power slowdown. They are also shaded on the temperature panel."),
+    ("throttling.intervals", "Intervals"),
+    ("throttling.time", "Time"),
+    ("throttling.now", "Now"),
+    ("throttling.yes", "throttling"),
+    ("throttling.no", "no"),
+    ("throttling.legend", "Throttling"),
+    ("kind.frequency", "Frequency"),
+    ("kind.throttling", "Throttling"),
+    ("kind.disk_throughput", "Disk throughput"),
+    ("kind.disk_operations", "Disk IOPS"),
+    ("kind.network_throughput", "Network throughput"),
+    ("kind.network_packets", "Network packets"),
+    ("power_supply.heading", "Power supply"),
+    ("power_supply.ac_online", "On AC power"),
+    ("power_supply.ac_offline", "On battery"),
+    ("power_supply.battery", "Battery"),
+    ("power_supply.status", "Status"),
+    ("power_supply.charge", "Charge"),
+    ("power_supply.rate", "Power"),
+    ("power_supply.cycles", "Cycles"),
+    ("power_supply.charging", "charging"),
+    ("power_supply.discharging", "discharging"),
+    ("power_supply.full", "full"),
+    ("power_supply.not_charging", "not charging"),
+    ("power_supply.unknown", "unknown"),
+    ("power_supply.plugged", "AC plugged in"),
+    ("power_supply.unplugged", "AC unplugged"),
+    ("kind.battery_charge", "Battery charge"),
+    ("kind.voltage", "Voltage"),
+    ("kind.ac_online", "AC adapter"),
+    ("kind.pressure", "Pressure (PSI)"),
+    ("display.processes", "Show top processes"),
+    ("processes.heading", "Top processes"),
+    ("processes.count", "processes"),
+    ("processes.hint", "CPU is the share of one core since the previous sample, memory is the resident set size. Click a process to add its CPU usage to the load panel, click it again to remove it."),
+    ("processes.unavailable", "The process list is not available on this system."),
+    ("processes.pid", "PID"),
+    ("processes.name", "Name"),
+    ("processes.cpu", "CPU"),
+    ("processes.memory", "Memory"),
+    ("processes.track_hint", "Show the CPU usage of this process on the load panel"),
+    ("cgroup.own_cgroup_unknown", "could not determine the cgroup v2 of the program from /proc/self/cgroup"),
+    ("cgroup.not_cgroup_v2", "{path} is not a cgroup v2"),
+    ("kind.load_average", "Load average"),
+    ("kind.scheduler_events", "Scheduler events"),
+    ("kind.process_count", "Processes"),
+    ("kind.throttle_count", "Throttling events"),
+    ("scheduler.load_average", "Load: {one} {five} {fifteen}"),
+    ("scheduler.context_switches", "Context switches: {value}"),
+    ("scheduler.interrupts", "Interrupts: {value}"),
+    ("scheduler.forks", "Forks: {value}"),
+    ("scheduler.processes", "Running: {running}, blocked: {blocked}"),
+    ("trip_points.passive", "passive"),
+    ("trip_points.hot", "hot"),
+    ("trip_points.critical", "critical"),
+    ("trip_points.maximum", "max"),
+    ("trip_points.slowdown", "slowdown"),
+    ("trip_points.shutdown", "shutdown"),
+    ("inventory.open", "Sensors…"),
+    ("inventory.heading", "Sensors"),
+    ("inventory.hint", "every sensor found so far, with the value in the unit of its backend. The latency is the time the whole backend took to read."),
+    ("inventory.empty", "No sensors have been read yet."),
+    ("inventory.plotted", "Plot"),
+    ("inventory.backend", "Backend"),
+    ("inventory.device", "Device"),
+    ("inventory.sensor", "Sensor"),
+    ("inventory.alias", "Alias"),
+    ("inventory.unit", "Unit"),
+    ("inventory.value", "Value"),
+    ("inventory.latency", "Latency"),
+    ("inventory.state", "State"),
+    ("inventory.state_ok", "ok"),
+    ("inventory.state_missing", "no value"),
+    ("inventory.state_errors", "read errors: {count}"),
+    ("styles.heading", "Series styles"),
+    ("styles.width", "line width"),
+    ("styles.line", "Line"),
+    ("styles.area", "Area"),
+    ("styles.step", "Steps"),
+    ("styles.dashed", "dashed"),
+    ("styles.reset", "Reset to the color-blind palette"),
+    ("styles.reset_hint", "assigns the default colors again (a palette that stays distinguishable with the common kinds of color blindness), width 2 and a solid line to every series."),
+    ("overlay.heading", "Overlay"),
+    ("overlay.open", "Open overlay"),
+    ("overlay.close", "Close overlay"),
+    ("overlay.open_hint", "a small always-on-top window with the current values and sparklines of the selected sensors. Drag it by the background, resize it by the bottom right corner, double-click it to close it."),
+    ("overlay.opacity", "Opacity"),
+    ("overlay.sensors", "Sensors"),
+    ("overlay.back", "Back to the full window"),
+    ("overlay.no_sensors", "No sensors selected"),
+    ("optimization.heading", "Optimization"),
+    ("optimization.details", "More about optimization"),
+    ("optimization.description", "This section lets you tune the program yourself."),
+    ("optimization.example", "For example:"),
+    ("optimization.example_text", "- when the program is used in the background, to save system resources you can first increase the delay between render updates and the delay between temperature requests, and then minimize the program."),
+    ("optimization.request_delay", "Delay between temperature requests (ms)"),
+    ("optimization.request_delay_hint", "adjusts how often the temperature is requested, the bigger the value, the longer the delay before new data arrives."),
+    ("optimization.render_delay", "Delay between render calls (ms)"),
+    ("optimization.render_delay_hint", "adjusts the delay between rendering frames, in other words it raises or lowers the fps limit. The bigger the value, the longer the delay, the lower the fps and the lower the load on the system."),
+    ("optimization.stored_data", "Number of displayed temperature samples"),
+    ("optimization.stored_data_hint", "adjusts the number of plot points shown on the screen. Once this many samples are collected, the oldest ones are removed one by one as new data arrives. The bigger the value, the more samples are kept and displayed (over a longer period of time)."),
+    ("notifications.heading", "Notifications"),
+    ("notifications.enabled", "Notify about critical temperature"),
+    ("notifications.enabled_hint", "when the temperature reaches the critical threshold, a desktop notification is shown, even if the program is minimized."),
+    ("notifications.cpu_threshold", "Critical CPU temperature ({unit})"),
+    ("notifications.gpu_threshold", "Critical GPU temperature ({unit})"),
+    ("notifications.urgency", "Notification urgency"),
+    ("notifications.urgency_low", "Low"),
+    ("notifications.urgency_normal", "Normal"),
+    ("notifications.urgency_critical", "Critical"),
+    ("notifications.deduplication", "Repeat the same notification at most every"),
+    ("notifications.deduplication_hint", "while the temperature stays critical, the notification of a sensor is not shown again until this interval has passed; 0 shows it on every new crossing of the threshold."),
+    ("notifications.critical_summary", "{name}: critical temperature"),
+    ("notifications.critical_body", "{value}{unit} (threshold {threshold}{unit})"),
+    ("recording.heading", "Session recording"),
+    ("recording.enabled", "Record the session to disk"),
+    ("recording.enabled_hint", "every received value is appended to the file together with its time, so the history survives closing the program and can be attached to a bug report."),
+    ("recording.file", "Recording file"),
+    ("recording.open", "Open recording"),
+    ("recording.create_error", "could not create the recording file: {error}"),
+    ("recording.open_error", "could not open the recording: {error}"),
+    ("recording.not_a_recording", "this is not a resource monitor recording"),
+    ("recording.stopped", "recording stopped: {error}"),
+    ("recording.title", "Recording: {path}"),
+    ("recording.back_to_live", "Back to live data"),
+    ("recording.position", "Position in the recording (s)"),
+    ("recording.window", "Width of the visible window (s)"),
+    ("export.heading", "Export"),
+    ("export.file", "Export file"),
+    ("export.file_hint", "the history of every sensor with timestamps, sensor and device names and units. If no name is given, the file is created in the current directory."),
+    ("export.button", "Export"),
+    ("export.saved", "saved to {path}"),
+    ("export.error", "could not export: {error}"),
+    ("export.recording_error", "could not export {path}: {error}"),
+    ("plot.heading", "CPU and GPU temperature plot"),
+    ("plot.cpu_name", "CPU: {name}"),
+    ("plot.gpu_name", "GPU: {name}"),
+    ("plot.follow_live", "▶ Follow live"),
+    ("plot.pause", "⏸ Pause"),
+    ("plot.paused_hint", "paused: the mouse wheel and dragging zoom and pan the time axis, a right-button selection zooms into the area, a double click returns to live"),
+    ("statistics.heading", "Statistics"),
+    ("statistics.window", "Window"),
+    ("statistics.window_hint", "the values currently kept in memory and shown on the plot; while paused, the values in the zoomed or panned part of the plot."),
+    ("statistics.session", "Session"),
+    ("statistics.session_hint", "all values since the program was started."),
+    ("statistics.sensor", "sensor"),
+    ("statistics.current", "now"),
+    ("statistics.min", "min"),
+    ("statistics.max", "max"),
+    ("statistics.mean", "avg"),
+    ("statistics.p95", "p95"),
+    ("statistics.above_threshold", "above threshold"),
+    ("tui.paused", "paused"),
+    ("tui.error", "terminal interface error: {error}"),
+    ("headless.error", "headless mode error: {error}"),
+    ("tui.log_written", "log messages of the session were written to {path}"),
+    ("tui.keys", "q quit  ↑↓ select  ←→ change  space toggle  p pause  +/- zoom  0 reset"),
+    ("tui.paused_keys", "q quit  ↑↓ select  ←→ change  space toggle  p resume  +/- zoom  [ ] pan  0 back to live"),
+    ("sensor.information_missing", "information is missing")
+];
+
+pub const UKRAINIAN_MESSAGES: &[(&str, &str)] = &[
+    ("display.heading", "Відображення"),
+    ("display.gpu_temperature", "Відображати сенсори відеокарти"),
+    ("display.cpu_temperature", "Відображати сенсори процесора"),
+    ("display.trip_points", "Відображати точки спрацювання"),
+    ("display.trip_points_hint", "пунктирні лінії на панелі температур, на яких залізо починає знижувати частоти або вимикається, з теплових зон, hwmon та NVML."),
+    ("display.statistics", "Відображати статистику"),
+    ("display.statistics_hint", "таблиця з поточним, мінімальним, максимальним, середнім значенням, 95-м перцентилем та часом вище порогу сповіщення для кожного сенсора."),
+    ("display.language", "Мова"),
+    ("display.temperature_unit", "Одиниця температури"),
+    ("period.label", "Період"),
+    ("period.live", "Поточні дані"),
+    ("period.hour", "Остання година"),
+    ("period.day", "Останній день"),
+    ("period.week", "Останній тиждень"),
+    ("period.hint", "довгострокова історія зберігається на диску у вигляді 10-секундних, хвилинних та 10-хвилинних кошиків (мінімум, максимум, середнє)."),
+    ("panels.heading", "Панелі графіків"),
+    ("panels.add", "Додати панель"),
+    ("panels.move_up", "Перемістити вище"),
+    ("panels.move_down", "Перемістити нижче"),
+    ("panels.remove", "Прибрати панель"),
+    ("panels.empty", "Немає жодної панелі графіків, додайте її на бічній панелі."),
+    ("kind.temperature", "Температура"),
+    ("kind.load", "Навантаження"),
+    ("kind.power", "Потужність"),
+    ("kind.memory", "Пам'ять"),
+    ("kind.fan_speed", "Оберти вентилятора"),
+    ("kind.fan_duty", "Заповнення ШІМ вентилятора"),
+    ("throttling.heading", "Тротлінг"),
+    ("throttling.hint", "Проміжки, коли процесор повідомляв про події теплового тротлінгу або відеокарта про уповільнення через температуру чи живлення. Вони також затінені на панелі температур."),
+    ("throttling.intervals", "Проміжки"),
+    ("throttling.time", "Час"),
+    ("throttling.now", "Зараз"),
+    ("throttling.yes", "тротлінг"),
+    ("throttling.no", "ні"),
+    ("throttling.legend", "Тротлінг"),
+    ("kind.frequency", "Частота"),
+    ("kind.throttling", "Тротлінг"),
+    ("kind.disk_throughput", "Швидкість дисків"),
+    ("kind.disk_operations", "Операції дисків (IOPS)"),
+    ("kind.network_throughput", "Швидкість мережі"),
+    ("kind.network_packets", "Мережеві пакети"),
+    ("power_supply.heading", "Живлення"),
+    ("power_supply.ac_online", "Живлення від мережі"),
+    ("power_supply.ac_offline", "Живлення від батареї"),
+    ("power_supply.battery", "Батарея"),
+    ("power_supply.status", "Стан"),
+    ("power_supply.charge", "Заряд"),
+    ("power_supply.rate", "Потужність"),
+    ("power_supply.cycles", "Цикли"),
+    ("power_supply.charging", "заряджається"),
+    ("power_supply.discharging", "розряджається"),
+    ("power_supply.full", "заряджена"),
+    ("power_supply.not_charging", "не заряджається"),
+    ("power_supply.unknown", "невідомо"),
+    ("power_supply.plugged", "Зарядку підключено"),
+    ("power_supply.unplugged", "Зарядку відключено"),
+    ("kind.battery_charge", "Заряд батареї"),
+    ("kind.voltage", "Напруга"),
+    ("kind.ac_online", "Зарядний пристрій"),
+    ("kind.pressure", "Тиск (PSI)"),
+    ("display.processes", "Відображати найактивніші процеси"),
+    ("processes.heading", "Найактивніші процеси"),
+    ("processes.count", "процесів"),
+    ("processes.hint", "Процесор - частка одного ядра з попереднього відліку, пам'ять - резидентний розмір. Клік по процесу додає його завантаження на панель навантаження, повторний клік прибирає."),
+    ("processes.unavailable", "Список процесів недоступний у цій системі."),
+    ("processes.pid", "PID"),
+    ("processes.name", "Назва"),
+    ("processes.cpu", "Процесор"),
+    ("processes.memory", "Пам'ять"),
+    ("processes.track_hint", "Показати завантаження процесора цим процесом на панелі навантаження"),
+    ("cgroup.own_cgroup_unknown", "не вдалося визначити власну cgroup v2 з /proc/self/cgroup"),
+    ("cgroup.not_cgroup_v2", "{path} не є cgroup v2"),
+    ("kind.load_average", "Середнє навантаження"),
+    ("kind.scheduler_events", "Події планувальника"),
+    ("kind.process_count", "Процеси"),
+    ("kind.throttle_count", "Випадки тротлінгу"),
+    ("scheduler.load_average", "Навантаження: {one} {five} {fifteen}"),
+    ("scheduler.context_switches", "Перемикання контексту: {value}"),
+    ("scheduler.interrupts", "Переривання: {value}"),
+    ("scheduler.forks", "Створення процесів: {value}"),
+    ("scheduler.processes", "Виконуються: {running}, заблоковані: {blocked}"),
+    ("trip_points.passive", "пасивне охолодження"),
+    ("trip_points.hot", "перегрів"),
+    ("trip_points.critical", "критична"),
+    ("trip_points.maximum", "максимум"),
+    ("trip_points.slowdown", "зниження частот"),
+    ("trip_points.shutdown", "вимкнення"),
+    ("inventory.open", "Сенсори…"),
+    ("inventory.heading", "Сенсори"),
+    ("inventory.hint", "усі знайдені сенсори, значення в одиниці їхнього бекенда. Затримка - час читання всього бекенда."),
+    ("inventory.empty", "Сенсори ще не прочитані."),
+    ("inventory.plotted", "Графік"),
+    ("inventory.backend", "Бекенд"),
+    ("inventory.device", "Пристрій"),
+    ("inventory.sensor", "Сенсор"),
+    ("inventory.alias", "Псевдонім"),
+    ("inventory.unit", "Одиниця"),
+    ("inventory.value", "Значення"),
+    ("inventory.latency", "Затримка"),
+    ("inventory.state", "Стан"),
+    ("inventory.state_ok", "гаразд"),
+    ("inventory.state_missing", "немає значення"),
+    ("inventory.state_errors", "помилок читання: {count}"),
+    ("styles.heading", "Стилі ліній"),
+    ("styles.width", "товщина лінії"),
+    ("styles.line", "Лінія"),
+    ("styles.area", "Область"),
+    ("styles.step", "Сходинки"),
+    ("styles.dashed", "пунктир"),
+    ("styles.reset", "Скинути до палітри для дальтоніків"),
+    ("styles.reset_hint", "знову призначає всім лініям стандартні кольори (палітра, що розрізняється при поширених видах дальтонізму), товщину 2 та суцільну лінію."),
+    ("overlay.heading", "Міні-вікно"),
+    ("overlay.open", "Відкрити міні-вікно"),
+    ("overlay.close", "Закрити міні-вікно"),
+    ("overlay.open_hint", "маленьке вікно поверх усіх інших з поточними значеннями та мініграфіками обраних сенсорів. Перетягується за фон, розмір змінюється за правий нижній кут, подвійне натискання його закриває."),
+    ("overlay.opacity", "Непрозорість"),
+    ("overlay.sensors", "Сенсори"),
+    ("overlay.back", "Повернутися до повного вікна"),
+    ("overlay.no_sensors", "Сенсори не обрано"),
+    ("optimization.heading", "Оптимізація"),
+    ("optimization.details", "Детальніше про оптимізацію"),
+    ("optimization.description", "Ця вкладка надає можливість самостійно оптимізувати програму."),
+    ("optimization.example", "Наприклад:"),
+    ("optimization.example_text", "- при використанні програми в фоновому режимі, щоб заощадити ресурси системи, програму можна попередньо налавштувати, збільшивши затримку між оновленням рендеру, та збільшивши затримку між запитами на отримання температури, та після цього згорнути програму."),
+    ("optimization.request_delay", "Затримка між запитами на отримання температури (ms)"),
+    ("optimization.request_delay_hint", "регулюємо частуту отримання данних про температуру, чим більше значення, тим більша затримка до отримання данних."),
+    ("optimization.render_delay", "Затримка між викликами рендеру (ms)"),
+    ("optimization.render_delay_hint", "регулюємо затримку між оновленнями рендеру кожного кадру, простіше кажучи - дозволяє збільшувати, або зменшувати обмеження fps. Чим більше значення тим більша затримка, та нижчий fps, та відповідно меньше навантаження на систему."),
+    ("optimization.stored_data", "Кількість відображених даних про температуру"),
+    ("optimization.stored_data_hint", "регулюємо кількість елментів графіка відображених на екрані. Після накопичення вказаного значення найстаріші значеня починають по одному видалятися, як тільки надходять нові данні. Чим більше значення, тим більша кількість елментів буде збережена, та відобоажена на екрані (за певний проміжок часу)."),
+    ("notifications.heading", "Сповіщення"),
+    ("notifications.enabled", "Сповіщати про критичну температуру"),
+    ("notifications.enabled_hint", "коли температура досягає критичного порогу, показується системне сповіщення, навіть якщо програма згорнута."),
+    ("notifications.cpu_threshold", "Критична температура процесора ({unit})"),
+    ("notifications.gpu_threshold", "Критична температура відеокарти ({unit})"),
+    ("notifications.urgency", "Терміновість сповіщення"),
+    ("notifications.urgency_low", "Низька"),
+    ("notifications.urgency_normal", "Звичайна"),
+    ("notifications.urgency_critical", "Критична"),
+    ("notifications.deduplication", "Повторювати те саме сповіщення не частіше, ніж раз на"),
+    ("notifications.deduplication_hint", "поки температура лишається критичною, сповіщення сенсора не показується повторно, доки не мине цей інтервал; 0 - показувати при кожному новому перетині порогу."),
+    ("notifications.critical_summary", "{name}: критична температура"),
+    ("notifications.critical_body", "{value}{unit} (поріг {threshold}{unit})"),
+    ("recording.heading", "Запис сесії"),
+    ("recording.enabled", "Записувати сесію на диск"),
+    ("recording.enabled_hint", "кожне отримане значення з часом отримання дописується у файл, тому історія зберігається навіть після закриття програми, і її можна додати до звіту про помилку."),
+    ("recording.file", "Файл запису"),
+    ("recording.open", "Відкрити запис"),
+    ("recording.create_error", "не вдалося створити файл запису: {error}"),
+    ("recording.open_error", "не вдалося відкрити запис: {error}"),
+    ("recording.not_a_recording", "це не файл запису resource monitor"),
+    ("recording.stopped", "запис зупинено: {error}"),
+    ("recording.title", "Запис: {path}"),
+    ("recording.back_to_live", "Повернутися до поточних даних"),
+    ("recording.position", "Позиція в записі (s)"),
+    ("recording.window", "Ширина вікна перегляду (s)"),
+    ("export.heading", "Експорт"),
+    ("export.file", "Файл експорту"),
+    ("export.file_hint", "історія всіх сенсорів з часом отримання, назвами сенсорів та пристроїв і одиницями виміру. Якщо назва не вказана, файл створюється в поточній директорії."),
+    ("export.button", "Експортувати"),
+    ("export.saved", "збережено у {path}"),
+    ("export.error", "не вдалося експортувати: {error}"),
+    ("export.recording_error", "не вдалося експортувати {path}: {error}"),
+    ("plot.heading", "Графік температур процесора та відеокарти"),
+    ("plot.cpu_name", "Процесор: {name}"),
+    ("plot.gpu_name", "Відеокарта: {name}"),
+    ("plot.follow_live", "▶ Слідкувати наживо"),
+    ("plot.pause", "⏸ Пауза"),
+    ("plot.paused_hint", "пауза: колесо миші та перетягування змінюють масштаб і зсувають вісь часу, виділення з ПКМ - масштабує область, подвійний клік - повернення наживо"),
+    ("statistics.heading", "Статистика"),
+    ("statistics.window", "Вікно"),
+    ("statistics.window_hint", "значення, що зараз зберігаються в пам'яті та відображені на графіку; на паузі - значення у видимій після масштабування чи зсуву частині графіка."),
+    ("statistics.session", "Сесія"),
+    ("statistics.session_hint", "всі значення з моменту запуску програми."),
+    ("statistics.sensor", "сенсор"),
+    ("statistics.current", "зараз"),
+    ("statistics.min", "мін"),
+    ("statistics.max", "макс"),
+    ("statistics.mean", "сер"),
+    ("statistics.p95", "p95"),
+    ("statistics.above_threshold", "вище порогу"),
+    ("tui.paused", "пауза"),
+    ("tui.error", "помилка термінального інтерфейсу: {error}"),
+    ("headless.error", "помилка режиму без інтерфейсу: {error}"),
+    ("tui.log_written", "записи лога за сесію збережено у {path}"),
+    ("tui.keys", "q вихід  ↑↓ вибір  ←→ змінити  пробіл перемкнути  p пауза  +/- масштаб  0 скинути"),
+    ("tui.paused_keys", "q вихід  ↑↓ вибір  ←→ змінити  пробіл перемкнути  p продовжити  +/- масштаб  [ ] зсув  0 наживо"),
+    ("sensor.information_missing", "інформація відсутня")
+];
@@ -1,14 +1,95 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
-use resource_monitor::{
-    multi_threaded_implementation::run_multi_threaded_implementation,
-    single_threaded_implementation::run_single_threaded_implementation
-};
+use std::path::PathBuf;
+
+use resource_monitor::cgroup::resolve_cgroup_path;
+use resource_monitor::cli_arguments::{parse_cli_arguments, USAGE};
+use resource_monitor::headless::run_headless;
+use resource_monitor::history_export::export_recording;
+use resource_monitor::localization::{detect_language, tr_with};
+use resource_monitor::sensor_inventory::print_sensor_inventory;
+use resource_monitor::session_recording::format_recording_error;
+#[cfg(windows)]
+use resource_monitor::multi_threaded_implementation::run_multi_threaded_implementation;
+use resource_monitor::single_threaded_implementation::run_single_threaded_implementation;
+use resource_monitor::terminal_ui::{init_terminal_ui_logger, run_terminal_ui};
 
 fn main() -> Result<(), eframe::Error> {
-    //env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
+    let arguments = match parse_cli_arguments(std::env::args().skip(1)) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+
+            std::process::exit(2);
+        }
+    };
+
+    if arguments.is_terminal_ui {
+        init_terminal_ui_logger();
+    } else {
+        env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
+    }
+
+    if arguments.is_help_requested {
+        println!("{}", USAGE);
+
+        return Ok(());
+    }
+
+    let language = detect_language();
+
+    // неправильний шлях cgroup краще показати одразу, ніж мовчки показувати всю машину
+    if let Some(cgroup) = &arguments.cgroup {
+        if let Err(error) = resolve_cgroup_path(cgroup) {
+            eprintln!("{}", error.message(language));
+
+            std::process::exit(1);
+        }
+    }
+
+    if let (Some(export_path), Some(recording_path), true) = (&arguments.export_path, &arguments.recording_path, arguments.is_recording_opened) {
+        match export_recording(&PathBuf::from(recording_path), &PathBuf::from(export_path), arguments.temperature_unit) {
+            Ok(_) => println!("{} -> {}", recording_path, export_path),
+            Err(error) => {
+                eprintln!("{}", tr_with(language, "export.recording_error", &[("path", recording_path), ("error", &format_recording_error(&error, language))]));
+
+                std::process::exit(1);
+            }
+        }
+
+        return Ok(());
+    }
+
+    if arguments.is_sensor_list_requested {
+        print_sensor_inventory(&arguments);
+
+        return Ok(());
+    }
+
+    if arguments.is_terminal_ui {
+        match run_terminal_ui(&arguments) {
+            Ok(_) => {},
+            Err(error) => {
+                eprintln!("{}", tr_with(language, "tui.error", &[("error", &error.to_string())]));
+
+                std::process::exit(1);
+            }
+        }
+
+        return Ok(());
+    }
+
+    if arguments.is_headless {
+        if let Err(error) = run_headless(&arguments) {
+            eprintln!("{}", tr_with(language, "headless.error", &[("error", &error.to_string())]));
+
+            std::process::exit(1);
+        }
+
+        return Ok(());
+    }
     
     //run_multi_threaded_implementation()
 
-    run_single_threaded_implementation()
+    run_single_threaded_implementation(&arguments)
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use std::fmt::Write;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::sensors::{SensorKind, SensorReading};
+
+#[derive(Default)]
+pub struct MetricsSnapshot {
+    pub readings: Vec<SensorReading>,
+    pub scrape_duration: Duration,
+    pub scrapes_total: u64,
+    pub scrape_errors_total: u64
+}
+
+pub type SharedMetrics = Arc<RwLock<MetricsSnapshot>>;
+
+pub fn update_metrics(metrics: &SharedMetrics, readings: &[SensorReading], scrape_duration: Duration, scrape_errors_total: u64) {
+    let mut snapshot = metrics.write().unwrap();
+
+    snapshot.readings = readings.to_vec();
+    snapshot.scrape_duration = scrape_duration;
+    snapshot.scrapes_total += 1;
+    snapshot.scrape_errors_total = scrape_errors_total;
+}
+
+fn metric_name_and_help(kind: SensorKind) -> (&'static str, &'static str) {
+    match kind {
+        SensorKind::Temperature => ("hw_temperature_celsius", "Current temperature reported by the sensor."),
+        SensorKind::Load => ("hw_load_percent", "Current utilization of the device."),
+        SensorKind::Power => ("hw_power_watts", "Current power draw of the device."),
+        SensorKind::Memory => ("hw_memory_used_bytes", "Memory of the device that is currently in use."),
+        SensorKind::FanSpeed => ("hw_fan_speed_rpm", "Current fan speed."),
+        SensorKind::FanDuty => ("hw_fan_duty_percent", "Current fan PWM duty cycle."),
+        SensorKind::Frequency => ("hw_frequency_megahertz", "Current clock frequency."),
+        SensorKind::Throttling => ("hw_throttling", "1 if the device was throttled since the previous sample, 0 otherwise."),
+        SensorKind::DiskThroughput => ("hw_disk_throughput_bytes_per_second", "Current disk read or write throughput."),
+        SensorKind::DiskOperations => ("hw_disk_operations_per_second", "Current disk read or write operations per second."),
+        SensorKind::NetworkThroughput => ("hw_network_throughput_bytes_per_second", "Current network receive or transmit throughput."),
+        SensorKind::NetworkPackets => ("hw_network_packets_per_second", "Current network receive or transmit packets per second."),
+        SensorKind::BatteryCharge => ("hw_battery_charge_percent", "Current battery charge."),
+        SensorKind::Voltage => ("hw_voltage_volts", "Current voltage."),
+        SensorKind::AcOnline => ("hw_ac_online", "1 if the AC adapter is plugged in, 0 otherwise."),
+        SensorKind::Pressure => ("hw_pressure_percent", "Pressure stall information: share of time tasks were stalled on a resource."),
+        SensorKind::LoadAverage => ("hw_load_average", "System load average over 1, 5 or 15 minutes."),
+        SensorKind::SchedulerEvents => ("hw_scheduler_events_per_second", "Context switches, interrupts or forks per second."),
+        SensorKind::ProcessCount => ("hw_processes", "Number of running or blocked processes."),
+        SensorKind::ThrottleCount => ("hw_throttle_count", "Number of core or package thermal throttling events since boot.")
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+// формат описаний тут: https://prometheus.io/docs/instrumenting/exposition_formats/
+pub fn render_metrics(snapshot: &MetricsSnapshot) -> String {
+    let mut result = String::new();
+
+    let mut kinds: Vec<SensorKind> = Vec::new();
+
+    for reading in &snapshot.readings {
+        if !kinds.contains(&reading.kind) {
+            kinds.push(reading.kind);
+        }
+    }
+
+    // всі рядки однієї метрики мають іти одним блоком після її HELP/TYPE
+    for kind in kinds {
+        let (name, help) = metric_name_and_help(kind);
+
+        writeln!(result, "# HELP {} {}", name, help).unwrap();
+        writeln!(result, "# TYPE {} gauge", name).unwrap();
+
+        for reading in snapshot.readings.iter().filter(|reading| reading.kind == kind) {
+            writeln!(
+                result,
+                "{}{{device=\"{}\",sensor=\"{}\"}} {}",
+                name,
+                escape_label_value(&reading.device),
+                escape_label_value(&reading.sensor),
+                reading.value
+            ).unwrap();
+        }
+    }
+
+    writeln!(result, "# HELP hw_scrape_duration_seconds Time spent reading all sensors during the last sampling.").unwrap();
+    writeln!(result, "# TYPE hw_scrape_duration_seconds gauge").unwrap();
+    writeln!(result, "hw_scrape_duration_seconds {}", snapshot.scrape_duration.as_secs_f64()).unwrap();
+
+    writeln!(result, "# HELP hw_scrapes_total Number of completed sensor samplings.").unwrap();
+    writeln!(result, "# TYPE hw_scrapes_total counter").unwrap();
+    writeln!(result, "hw_scrapes_total {}", snapshot.scrapes_total).unwrap();
+
+    writeln!(result, "# HELP hw_scrape_errors_total Number of sensor reads that failed.").unwrap();
+    writeln!(result, "# TYPE hw_scrape_errors_total counter").unwrap();
+    writeln!(result, "hw_scrape_errors_total {}", snapshot.scrape_errors_total).unwrap();
+
+    result
+}
+
+// помилку прив'язки (неправильна адреса, зайнятий порт) повертаю, щоб монітор працював далі без експортера
+#[cfg(feature = "metrics-exporter")]
+pub fn spawn_metrics_server(address: &str, metrics: SharedMetrics) -> Result<std::thread::JoinHandle<()>, Box<dyn std::error::Error + Send + Sync>> {
+    use tiny_http::{Header, Response, Server};
+
+    let server = Server::http(address)?;
+
+    log::info!("метрики доступні на http://{}/metrics", address);
+
+    Ok(std::thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let response = if request.url() == "/metrics" {
+                let body = render_metrics(&metrics.read().unwrap());
+
+                Response::from_string(body)
+                    .with_header(Header::from_bytes(&b"Content-Type"[..], &b"text/plain; version=0.0.4; charset=utf-8"[..]).unwrap())
+            } else {
+                Response::from_string("not found").with_status_code(404)
+            };
+
+            match request.respond(response) {
+                Ok(_) => {},
+                Err(error) => log::warn!("не вдалося відповісти на запит метрик: {}", error)
+            }
+        }
+    }))
+}
+
+// повертає спільний знімок, який треба оновлювати після кожного опитування сенсорів,
+// або None, якщо адреса не вказана чи програма зібрана без feature "metrics-exporter"
+pub fn start_metrics_server_if_requested(metrics_address: &Option<String>) -> Option<SharedMetrics> {
+    let address = match metrics_address {
+        Some(address) => address,
+        None => return None
+    };
+
+    #[cfg(feature = "metrics-exporter")]
+    {
+        let metrics: SharedMetrics = Arc::new(RwLock::new(MetricsSnapshot::default()));
+
+        match spawn_metrics_server(address, metrics.clone()) {
+            Ok(_) => Some(metrics),
+            Err(error) => {
+                log::error!("не вдалося запустити експортер метрик на {}: {}", address, error);
+
+                None
+            }
+        }
+    }
+
+    #[cfg(not(feature = "metrics-exporter"))]
+    {
+        log::warn!("--metrics-address {} ігнорується: програма зібрана без feature \"metrics-exporter\"", address);
+
+        None
+    }
+}
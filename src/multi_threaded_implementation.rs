@@ -4,6 +4,7 @@ use eframe::egui::{self, Event, Vec2};
 use egui_plot::{Legend, Line, PlotPoints};
 use crate::cpu_temperature::{get_cpu_current_celsius_temperature, get_cpu_name};
 use crate::gpu_temperature::{get_gpu_current_celsius_temperature, get_gpu_current_celsius_temperature_nvml, get_gpu_name_nvml};
+use crate::localization::{detect_language, tr, tr_with, Language, LANGUAGES};
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
@@ -22,13 +23,14 @@ struct PlotExample {
     is_display_cpu_temperature: Arc<RwLock<bool>>,
     gpu_temperature: Arc<RwLock<Vec<f32>>>,
     cpu_temperature: Arc<RwLock<Vec<f32>>>,
-    cpu_name: String,
-    gpu_name: String,
+    cpu_name: Option<String>,
+    gpu_name: Option<String>,
     amount_of_stored_data: Arc<RwLock<u16>>,
     wmi_server: Arc<IWbemServices>,
     nvml: Arc<RwLock<Nvml>>,
     delay_between_updates: Arc<RwLock<u64>>,
-    is_program_finished_working: Arc<RwLock<bool>>
+    is_program_finished_working: Arc<RwLock<bool>>,
+    language: Language
 }
 
 impl Default for PlotExample {
@@ -46,7 +48,7 @@ impl Default for PlotExample {
 
         let cpu_name = get_cpu_name(&wmi_server);
 
-        let gpu_name = get_gpu_name_nvml(&mut nvml);
+        let gpu_name = Some(get_gpu_name_nvml(&mut nvml));
 
         Self {
             delay_between_temperature_requests: Arc::new(RwLock::new(500)),
@@ -61,7 +63,8 @@ impl Default for PlotExample {
             nvml: Arc::new(RwLock::new(nvml)),
             delay_between_updates: Arc::new(RwLock::new(16)),
             wmi_server: Arc::new(wmi_server),
-            is_program_finished_working: Arc::new(RwLock::new(false))
+            is_program_finished_working: Arc::new(RwLock::new(false)),
+            language: detect_language()
         }
     }
     
@@ -75,51 +78,62 @@ impl eframe::App for PlotExample {
         let is_display_gpu_temperature_read = *self.is_display_gpu_temperature.read().unwrap();
         let is_display_cpu_temperature_read = *self.is_display_cpu_temperature.read().unwrap();
 
+        let language = self.language;
+
         egui::SidePanel::left("options").show(&ctx, |ui| {
-            ui.heading("Відображення");
+            ui.heading(tr(language, "display.heading"));
+            ui.add_space(10.0);
+
+            egui::ComboBox::from_label(tr(language, "display.language"))
+                .selected_text(self.language.native_name())
+                .show_ui(ui, |ui| {
+                    for option in LANGUAGES {
+                        ui.selectable_value(&mut self.language, option, option.native_name());
+                    }
+                });
             ui.add_space(10.0);
 
-            ui.checkbox(&mut self.is_display_gpu_temperature.write().unwrap(), "Відображати температуру відеокарти");
-            ui.checkbox(&mut self.is_display_cpu_temperature.write().unwrap(), "Відображати температуру процесора");
+            ui.checkbox(&mut self.is_display_gpu_temperature.write().unwrap(), tr(language, "display.gpu_temperature"));
+            ui.checkbox(&mut self.is_display_cpu_temperature.write().unwrap(), tr(language, "display.cpu_temperature"));
             ui.add_space(10.0);
 
             ui.add(egui::Separator::default());
             ui.add_space(10.0);
 
-            ui.heading("Оптимізація");
+            ui.heading(tr(language, "optimization.heading"));
             ui.add_space(10.0);
 
-            ui.collapsing("Детальніше про оптимізацію", |ui| {
+            ui.collapsing(tr(language, "optimization.details"), |ui| {
                 ui.label(
-                    "Ця вкладка надає можливість самостійно оптимізувати програму."
+                    tr(language, "optimization.description")
                 );
                 ui.add_space(5.0);
-                ui.label("Наприклад:");
-                ui.label("- при використанні програми в фоновому режимі, щоб заощадити ресурси системи, програму можна попередньо налавштувати, збільшивши затримку між оновленням рендеру, та збільшивши затримку між запитами на отримання температури, та після цього згорнути програму.");
+                ui.label(tr(language, "optimization.example"));
+                ui.label(tr(language, "optimization.example_text"));
             });
             ui.add_space(10.0);
 
-            ui.label("Затримка між запитами на отримання температури (ms)").on_hover_text("регулюємо частуту отримання данних про температуру, чим більше значення, тим більша затримка до отримання данних.");
-            ui.add(egui::Slider::new(&mut *self.delay_between_temperature_requests.write().unwrap(), 0..=3000)).on_hover_text("регулюємо частуту отримання данних про температуру, чим більше значення, тим більша затримка до отримання данних.");
+            ui.label(tr(language, "optimization.request_delay")).on_hover_text(tr(language, "optimization.request_delay_hint"));
+            ui.add(egui::Slider::new(&mut *self.delay_between_temperature_requests.write().unwrap(), 0..=3000)).on_hover_text(tr(language, "optimization.request_delay_hint"));
             ui.add_space(10.0);
 
-            ui.label("Затримка між викликами рендеру (ms)").on_hover_text("регулюємо затримку між оновленнями рендеру кожного кадру, простіше кажучи - дозволяє збільшувати, або зменшувати обмеження fps. Чим більше значення тим більша затримка, та нижчий fps, та відповідно меньше навантаження на систему.");
-            ui.add(egui::Slider::new(&mut *self.delay_between_updates.write().unwrap(), 1..=100)).on_hover_text("регулюємо затримку між оновленнями рендеру кожного кадру, простіше кажучи - дозволяє збільшувати, або зменшувати обмеження fps. Чим більше значення тим більша затримка, та нижчий fps, та відповідно меньше навантаження на систему.");
+            ui.label(tr(language, "optimization.render_delay")).on_hover_text(tr(language, "optimization.render_delay_hint"));
+            ui.add(egui::Slider::new(&mut *self.delay_between_updates.write().unwrap(), 1..=100)).on_hover_text(tr(language, "optimization.render_delay_hint"));
             ui.add_space(10.0);
 
-            ui.label("Кількість відображених даних про температуру").on_hover_text("регулюємо кількість елментів графіка відображених на екрані. Після накопичення вказаного значення найстаріші значеня починають по одному видалятися, як тільки надходять нові данні. Чим більше значення, тим більша кількість елментів буде збережена, та відобоажена на екрані (за певний проміжок часу).");
-            ui.add(egui::Slider::new(&mut *self.amount_of_stored_data.write().unwrap(), 10..=1200)).on_hover_text("регулюємо кількість елментів графіка відображених на екрані. Після накопичення вказаного значення найстаріші значеня починають по одному видалятися, як тільки надходять нові данні. Чим більше значення, тим більша кількість елментів буде збережена, та відобоажена на екрані (за певний проміжок часу).");
+            ui.label(tr(language, "optimization.stored_data")).on_hover_text(tr(language, "optimization.stored_data_hint"));
+            ui.add(egui::Slider::new(&mut *self.amount_of_stored_data.write().unwrap(), 10..=1200)).on_hover_text(tr(language, "optimization.stored_data_hint"));
             
         });
 
         egui::CentralPanel::default().show(&ctx, |ui| {
-            ui.heading("Графік температур процесора та відеокарти");
+            ui.heading(tr(language, "plot.heading"));
             ui.add_space(10.0);
 
-            ui.label(format!("Процесор: {}", &self.cpu_name));
+            ui.label(tr_with(language, "plot.cpu_name", &[("name", self.cpu_name.as_deref().unwrap_or(tr(language, "sensor.information_missing")))]));
             ui.add_space(10.0);
 
-            ui.label(format!("Відеокарта: {}", &self.gpu_name));
+            ui.label(tr_with(language, "plot.gpu_name", &[("name", self.gpu_name.as_deref().unwrap_or(tr(language, "sensor.information_missing")))]));
             ui.add_space(10.0);
 
             egui_plot::Plot::new("resource_monitor")
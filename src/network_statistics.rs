@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+pub const NETWORK_DEV_PATH: &str = "/proc/net/dev";
+pub const NETWORK_INTERFACES_DIRECTORY: &str = "/sys/class/net";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetworkCounters {
+    pub interface: String,
+    pub received_bytes: u64,
+    pub received_packets: u64,
+    pub transmitted_bytes: u64,
+    pub transmitted_packets: u64
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetworkRates {
+    pub interface: String,
+    pub received_bytes_per_second: f32,
+    pub transmitted_bytes_per_second: f32,
+    pub received_packets_per_second: f32,
+    pub transmitted_packets_per_second: f32
+}
+
+// два рядки заголовка, далі "interface: rx_bytes rx_packets errs drop fifo frame compressed multicast tx_bytes tx_packets ..."
+pub fn parse_network_dev(data: &str) -> Vec<NetworkCounters> {
+    let mut result_arr = Vec::new();
+
+    for line in data.lines().skip(2) {
+        // при великих лічильниках пробілу після двокрапки може не бути, тому ділю саме по ній
+        let (interface, values) = match line.split_once(':') {
+            Some(parts) => parts,
+            None => continue
+        };
+
+        let fields: Vec<u64> = values.split_whitespace().filter_map(|value| value.parse::<u64>().ok()).collect();
+
+        if fields.len() < 10 {
+            continue;
+        }
+
+        result_arr.push(NetworkCounters {
+            interface: interface.trim().to_string(),
+            received_bytes: fields[0],
+            received_packets: fields[1],
+            transmitted_bytes: fields[8],
+            transmitted_packets: fields[9]
+        });
+    }
+
+    result_arr
+}
+
+// у віртуальних інтерфейсів (lo, мости, veth контейнерів, тунелі) немає посилання device на справжній пристрій
+pub fn is_physical_network_interface(interfaces_directory: &Path, interface: &str) -> bool {
+    interfaces_directory.join(interface).join("device").exists()
+}
+
+pub fn get_network_counters() -> Vec<NetworkCounters> {
+    let data = match fs::read_to_string(NETWORK_DEV_PATH) {
+        Ok(data) => data,
+        Err(_) => return Vec::new()
+    };
+
+    parse_network_dev(&data)
+        .into_iter()
+        .filter(|counters| is_physical_network_interface(Path::new(NETWORK_INTERFACES_DIRECTORY), &counters.interface))
+        .collect()
+}
+
+// швидкості - це різниці лічильників між відліками, тому перший відлік лише запам'ятовується
+#[derive(Default)]
+pub struct NetworkRateMeter {
+    previous: HashMap<String, (NetworkCounters, f64)>
+}
+
+impl NetworkRateMeter {
+    pub fn update(&mut self, counters: Vec<NetworkCounters>, timestamp: f64) -> Vec<NetworkRates> {
+        let mut result_arr = Vec::new();
+
+        for counters in counters {
+            if let Some((previous, previous_timestamp)) = self.previous.get(&counters.interface) {
+                let elapsed = timestamp - previous_timestamp;
+
+                // лічильники скидаються, коли драйвер перезавантажили або інтерфейс створили заново, тоді відлік пропускаю
+                let deltas = (
+                    counters.received_bytes.checked_sub(previous.received_bytes),
+                    counters.transmitted_bytes.checked_sub(previous.transmitted_bytes),
+                    counters.received_packets.checked_sub(previous.received_packets),
+                    counters.transmitted_packets.checked_sub(previous.transmitted_packets)
+                );
+
+                if let (true, (Some(received_bytes), Some(transmitted_bytes), Some(received_packets), Some(transmitted_packets))) = (elapsed > 0.0, deltas) {
+                    result_arr.push(NetworkRates {
+                        interface: counters.interface.clone(),
+                        received_bytes_per_second: (received_bytes as f64 / elapsed) as f32,
+                        transmitted_bytes_per_second: (transmitted_bytes as f64 / elapsed) as f32,
+                        received_packets_per_second: (received_packets as f64 / elapsed) as f32,
+                        transmitted_packets_per_second: (transmitted_packets as f64 / elapsed) as f32
+                    });
+                }
+            }
+
+            self.previous.insert(counters.interface.clone(), (counters, timestamp));
+        }
+
+        result_arr
+    }
+}
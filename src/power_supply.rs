@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::Path;
+
+use crate::hwmon::read_trimmed;
+
+pub const POWER_SUPPLY_DIRECTORY: &str = "/sys/class/power_supply";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatteryInformation {
+    pub name: String,
+    pub capacity_percent: Option<f32>,
+    // потужність, що йде в батарею: додатна при заряджанні та від'ємна при розряджанні
+    pub power_watts: Option<f32>,
+    pub voltage: Option<f32>,
+    pub cycle_count: Option<u32>,
+    // як його повертає ядро: Charging, Discharging, Full, Not charging, Unknown
+    pub status: String
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PowerSupplies {
+    pub batteries: Vec<BatteryInformation>,
+    // None, якщо джерел живлення від мережі немає взагалі (настільний комп'ютер без ДБЖ)
+    pub is_ac_online: Option<bool>
+}
+
+fn read_number(directory: &Path, file_name: &str) -> Option<f64> {
+    read_trimmed(&directory.join(file_name)).and_then(|value| value.parse::<f64>().ok())
+}
+
+// значення в sysfs у мікро-одиницях: мкВт, мкА, мкВ, мкВт·год
+fn read_battery(directory: &Path, name: &str) -> BatteryInformation {
+    let status = read_trimmed(&directory.join("status")).unwrap_or("Unknown".to_string());
+
+    let capacity_percent = match read_number(directory, "capacity") {
+        Some(capacity) => Some(capacity as f32),
+        None => match (read_number(directory, "energy_now"), read_number(directory, "energy_full")) {
+            (Some(now), Some(full)) if full > 0.0 => Some((now / full * 100.0) as f32),
+            _ => match (read_number(directory, "charge_now"), read_number(directory, "charge_full")) {
+                (Some(now), Some(full)) if full > 0.0 => Some((now / full * 100.0) as f32),
+                _ => None
+            }
+        }
+    };
+
+    let voltage = read_number(directory, "voltage_now").map(|microvolts| microvolts / 1000000.0);
+
+    // деякі драйвери віддають знаковий струм, тому напрям беру зі статусу, а не зі знака
+    let watts = match read_number(directory, "power_now") {
+        Some(microwatts) => Some(microwatts.abs() / 1000000.0),
+        None => match (read_number(directory, "current_now"), voltage) {
+            (Some(microamperes), Some(voltage)) => Some(microamperes.abs() / 1000000.0 * voltage),
+            _ => None
+        }
+    };
+
+    let power_watts = watts.map(|watts| if status == "Discharging" { -watts as f32 } else { watts as f32 });
+
+    // 0 циклів драйвери повертають, коли не знають їх кількості
+    let cycle_count = read_number(directory, "cycle_count")
+        .filter(|cycle_count| *cycle_count > 0.0)
+        .map(|cycle_count| cycle_count as u32);
+
+    BatteryInformation {
+        name: name.to_string(),
+        capacity_percent,
+        power_watts,
+        voltage: voltage.map(|voltage| voltage as f32),
+        cycle_count,
+        status
+    }
+}
+
+pub fn read_power_supplies(power_supply_directory: &Path) -> PowerSupplies {
+    let mut power_supplies = PowerSupplies::default();
+
+    let mut names: Vec<String> = match fs::read_dir(power_supply_directory) {
+        Ok(entries) => entries.flatten().map(|entry| entry.file_name().to_string_lossy().to_string()).collect(),
+        Err(_) => return power_supplies
+    };
+
+    names.sort();
+
+    for name in names {
+        let directory = power_supply_directory.join(&name);
+
+        // батареї мишок і навушників теж тут, але в них scope = Device
+        if read_trimmed(&directory.join("scope")).as_deref() == Some("Device") {
+            continue;
+        }
+
+        match read_trimmed(&directory.join("type")).as_deref() {
+            Some("Battery") => {
+                power_supplies.batteries.push(read_battery(&directory, &name));
+            },
+            // зарядка через USB-C на ноутбуках має тип USB
+            Some("Mains") | Some("USB") => {
+                if let Some(online) = read_number(&directory, "online") {
+                    power_supplies.is_ac_online = Some(power_supplies.is_ac_online.unwrap_or(false) || online > 0.0);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    power_supplies
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+pub const PRESSURE_DIRECTORY: &str = "/proc/pressure";
+pub const CGROUP_DIRECTORY: &str = "/sys/fs/cgroup";
+
+pub const PRESSURE_RESOURCES: [&str; 3] = ["cpu", "memory", "io"];
+
+// назва пристрою для загальносистемного тиску, у cgroup пристрій - назва самої cgroup
+pub const SYSTEM_PRESSURE_SOURCE: &str = "pressure";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PressureLine {
+    // some - хоча б одна задача чекала, full - чекали всі задачі одночасно
+    pub scope: String,
+    pub avg10: f32,
+    pub avg60: f32,
+    pub avg300: f32,
+    pub total_microseconds: u64
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PressureSample {
+    pub source: String,
+    pub resource: String,
+    pub line: PressureLine
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PressureReading {
+    pub source: String,
+    pub sensor: String,
+    pub percent: f32
+}
+
+// рядок: "some avg10=0.12 avg60=0.05 avg300=0.01 total=123456"
+fn parse_pressure_line(line: &str) -> Option<PressureLine> {
+    let mut fields = line.split_whitespace();
+
+    let scope = fields.next()?.to_string();
+
+    let mut avg10 = None;
+    let mut avg60 = None;
+    let mut avg300 = None;
+    let mut total_microseconds = None;
+
+    for field in fields {
+        match field.split_once('=')? {
+            ("avg10", value) => avg10 = value.parse::<f32>().ok(),
+            ("avg60", value) => avg60 = value.parse::<f32>().ok(),
+            ("avg300", value) => avg300 = value.parse::<f32>().ok(),
+            ("total", value) => total_microseconds = value.parse::<u64>().ok(),
+            _ => {}
+        }
+    }
+
+    Some(PressureLine {
+        scope,
+        avg10: avg10?,
+        avg60: avg60?,
+        avg300: avg300?,
+        total_microseconds: total_microseconds?
+    })
+}
+
+// рядки, які не вдалося розібрати, пропускаються, щоб один зіпсований рядок не ховав інший
+pub fn parse_pressure(data: &str) -> Vec<PressureLine> {
+    data.lines().filter_map(parse_pressure_line).collect()
+}
+
+fn read_pressure_file(path: &Path) -> Vec<PressureLine> {
+    match fs::read_to_string(path) {
+        Ok(data) => parse_pressure(&data),
+        Err(_) => Vec::new()
+    }
+}
+
+pub fn get_system_pressure_samples(pressure_directory: &Path) -> Vec<PressureSample> {
+    let mut result_arr = Vec::new();
+
+    for resource in PRESSURE_RESOURCES {
+        for line in read_pressure_file(&pressure_directory.join(resource)) {
+            result_arr.push(PressureSample {
+                source: SYSTEM_PRESSURE_SOURCE.to_string(),
+                resource: resource.to_string(),
+                line
+            });
+        }
+    }
+
+    result_arr
+}
+
+// файли cpu.pressure, memory.pressure та io.pressure однієї cgroup v2
+pub fn get_cgroup_pressure_samples(cgroup_path: &Path, source: &str) -> Vec<PressureSample> {
+    let mut result_arr = Vec::new();
+
+    for resource in PRESSURE_RESOURCES {
+        for line in read_pressure_file(&cgroup_path.join(format!("{}.pressure", resource))) {
+            result_arr.push(PressureSample {
+                source: source.to_string(),
+                resource: resource.to_string(),
+                line
+            });
+        }
+    }
+
+    result_arr
+}
+
+// верхні слайси systemd (system.slice, user.slice, machine.slice) показують, хто саме створює тиск, і їх небагато
+pub fn get_slice_pressure_samples(cgroup_directory: &Path) -> Vec<PressureSample> {
+    let mut slices: Vec<String> = match fs::read_dir(cgroup_directory) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| name.ends_with(".slice"))
+            .collect(),
+        Err(_) => return Vec::new()
+    };
+
+    slices.sort();
+
+    let mut result_arr = Vec::new();
+
+    for slice in slices {
+        result_arr.extend(get_cgroup_pressure_samples(&cgroup_directory.join(&slice), &slice));
+    }
+
+    result_arr
+}
+
+pub fn get_pressure_samples() -> Vec<PressureSample> {
+    let mut result_arr = get_system_pressure_samples(Path::new(PRESSURE_DIRECTORY));
+
+    result_arr.extend(get_slice_pressure_samples(Path::new(CGROUP_DIRECTORY)));
+
+    result_arr
+}
+
+// avg10 та avg60 ядро вже віддає у відсотках, а частка часу простою між відліками рахується з приросту total
+#[derive(Default)]
+pub struct PressureStallMeter {
+    previous: HashMap<(String, String, String), (u64, f64)>
+}
+
+impl PressureStallMeter {
+    pub fn update(&mut self, samples: Vec<PressureSample>, timestamp: f64) -> Vec<PressureReading> {
+        let mut result_arr = Vec::new();
+
+        for sample in samples {
+            let name = format!("{} {}", sample.resource, sample.line.scope);
+
+            result_arr.push(PressureReading {
+                source: sample.source.clone(),
+                sensor: format!("{} avg10", name),
+                percent: sample.line.avg10
+            });
+
+            result_arr.push(PressureReading {
+                source: sample.source.clone(),
+                sensor: format!("{} avg60", name),
+                percent: sample.line.avg60
+            });
+
+            let key = (sample.source.clone(), sample.resource, sample.line.scope);
+
+            if let Some((previous_total, previous_timestamp)) = self.previous.get(&key) {
+                let elapsed = timestamp - previous_timestamp;
+
+                // total зменшується лише коли cgroup видалили та створили знову, тоді відлік пропускаю
+                if let (true, Some(stall_microseconds)) = (elapsed > 0.0, sample.line.total_microseconds.checked_sub(*previous_total)) {
+                    result_arr.push(PressureReading {
+                        source: sample.source.clone(),
+                        sensor: format!("{} stall", name),
+                        percent: ((stall_microseconds as f64 / 1000000.0 / elapsed) * 100.0).min(100.0) as f32
+                    });
+                }
+            }
+
+            self.previous.insert(key, (sample.line.total_microseconds, timestamp));
+        }
+
+        result_arr
+    }
+}
@@ -0,0 +1,224 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use crate::cgroup::get_cgroup_pids;
+use crate::sensors::{SensorKind, SensorReading};
+use crate::units::MeasurementUnit;
+
+pub const PROC_DIRECTORY: &str = "/proc";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ProcessSortColumn {
+    Cpu,
+    Memory
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessUsage {
+    pub pid: u32,
+    pub name: String,
+    // як у top: 100% - одне повністю завантажене ядро
+    pub cpu_percent: f32,
+    pub rss_bytes: u64
+}
+
+// назва в дужках може містити пробіли та дужки, тому шукаю останню ')', а utime та stime - 12 та 13 поля після неї
+pub fn parse_process_stat(data: &str) -> Option<(String, u64)> {
+    let name_start = data.find('(')?;
+    let name_end = data.rfind(')')?;
+
+    let fields: Vec<&str> = data[name_end + 1..].split_whitespace().collect();
+
+    let user_time = fields.get(11)?.parse::<u64>().ok()?;
+    let system_time = fields.get(12)?.parse::<u64>().ok()?;
+
+    Some((data[name_start + 1..name_end].to_string(), user_time + system_time))
+}
+
+// у ядерних потоків рядка VmRSS немає, тоді пам'ять вважаю нульовою
+pub fn parse_process_rss(status: &str) -> u64 {
+    for line in status.lines() {
+        if let Some(value) = line.strip_prefix("VmRSS:") {
+            let kilobytes = value.trim().trim_end_matches("kB").trim().parse::<u64>().unwrap_or(0);
+
+            return kilobytes * 1024;
+        }
+    }
+
+    0
+}
+
+// сумарний час усіх ядер з рядка "cpu" та кількість ядер за рядками "cpuN"
+pub fn parse_total_cpu_time(stat: &str) -> Option<(u64, u32)> {
+    let mut total = None;
+    let mut cpu_count = 0;
+
+    for line in stat.lines() {
+        if let Some(values) = line.strip_prefix("cpu ") {
+            total = Some(values.split_whitespace().filter_map(|value| value.parse::<u64>().ok()).sum());
+        } else if line.starts_with("cpu") {
+            cpu_count += 1;
+        }
+    }
+
+    Some((total?, cpu_count.max(1)))
+}
+
+// відсотки - це різниці часу процесу між відліками, тому після першого сканування всі процеси мають 0%
+#[derive(Default)]
+pub struct ProcessScanner {
+    previous_cpu_times: HashMap<u32, u64>,
+    previous_total_cpu_time: Option<u64>
+}
+
+impl ProcessScanner {
+    // cgroup_pids обмежує список процесами вибраної cgroup
+    pub fn scan(&mut self, proc_directory: &Path, cgroup_pids: Option<&HashSet<u32>>) -> Vec<ProcessUsage> {
+        let mut processes = Vec::new();
+
+        let (total_cpu_time, cpu_count) = match fs::read_to_string(proc_directory.join("stat")).ok().as_deref().and_then(parse_total_cpu_time) {
+            Some(total) => total,
+            None => return processes
+        };
+
+        let elapsed_per_cpu = match self.previous_total_cpu_time {
+            Some(previous_total_cpu_time) if total_cpu_time > previous_total_cpu_time => (total_cpu_time - previous_total_cpu_time) as f32 / cpu_count as f32,
+            _ => 0.0
+        };
+
+        let mut cpu_times = HashMap::new();
+
+        if let Ok(entries) = fs::read_dir(proc_directory) {
+            for entry in entries.flatten() {
+                let pid = match entry.file_name().to_string_lossy().parse::<u32>() {
+                    Ok(pid) => pid,
+                    Err(_) => continue
+                };
+
+                if cgroup_pids.is_some_and(|cgroup_pids| !cgroup_pids.contains(&pid)) {
+                    continue;
+                }
+
+                // процес міг завершитися між read_dir та читанням файлів
+                let (name, cpu_time) = match fs::read_to_string(entry.path().join("stat")).ok().as_deref().and_then(parse_process_stat) {
+                    Some(stat) => stat,
+                    None => continue
+                };
+
+                let rss_bytes = fs::read_to_string(entry.path().join("status")).map(|status| parse_process_rss(&status)).unwrap_or(0);
+
+                let cpu_percent = match self.previous_cpu_times.get(&pid) {
+                    Some(previous_cpu_time) if elapsed_per_cpu > 0.0 => cpu_time.saturating_sub(*previous_cpu_time) as f32 / elapsed_per_cpu * 100.0,
+                    _ => 0.0
+                };
+
+                cpu_times.insert(pid, cpu_time);
+
+                processes.push(ProcessUsage {
+                    pid,
+                    name,
+                    cpu_percent,
+                    rss_bytes
+                });
+            }
+        }
+
+        self.previous_cpu_times = cpu_times;
+        self.previous_total_cpu_time = Some(total_cpu_time);
+
+        processes
+    }
+}
+
+fn run_process_worker(receiver: Receiver<Option<PathBuf>>, sender: Sender<Vec<ProcessUsage>>) {
+    let mut scanner = ProcessScanner::default();
+
+    for cgroup_directory in receiver {
+        let cgroup_pids = cgroup_directory.as_deref().map(get_cgroup_pids);
+
+        if sender.send(scanner.scan(Path::new(PROC_DIRECTORY), cgroup_pids.as_ref())).is_err() {
+            break;
+        }
+    }
+}
+
+// на системі з тисячами процесів обхід /proc займає десятки мілісекунд, тому він робиться в окремому потоці, а не в update() інтерфейсу
+pub struct ProcessMonitor {
+    sender: Sender<Option<PathBuf>>,
+    receiver: Receiver<Vec<ProcessUsage>>,
+    is_scan_pending: bool,
+    cgroup_directory: Option<PathBuf>,
+    // результат останнього завершеного сканування
+    pub processes: Vec<ProcessUsage>
+}
+
+impl Default for ProcessMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProcessMonitor {
+    pub fn new() -> Self {
+        let (request_sender, request_receiver) = channel();
+        let (result_sender, result_receiver) = channel();
+
+        std::thread::spawn(move || run_process_worker(request_receiver, result_sender));
+
+        Self {
+            sender: request_sender,
+            receiver: result_receiver,
+            is_scan_pending: false,
+            cgroup_directory: None,
+            processes: Vec::new()
+        }
+    }
+
+    pub fn set_cgroup_directory(&mut self, cgroup_directory: Option<PathBuf>) {
+        self.cgroup_directory = cgroup_directory;
+    }
+
+    // забирає результат попереднього сканування і просить наступне; не блокує, тож список відстає на один інтервал
+    pub fn update(&mut self) {
+        while let Ok(processes) = self.receiver.try_recv() {
+            self.processes = processes;
+            self.is_scan_pending = false;
+        }
+
+        // якщо сканування ще триває, нове не ставлю в чергу, щоб запити не накопичувалися
+        if !self.is_scan_pending && self.sender.send(self.cgroup_directory.clone()).is_ok() {
+            self.is_scan_pending = true;
+        }
+    }
+
+    pub fn get_top_processes(&self, sort_column: ProcessSortColumn, count: usize) -> Vec<&ProcessUsage> {
+        let mut processes: Vec<&ProcessUsage> = self.processes.iter().collect();
+
+        match sort_column {
+            ProcessSortColumn::Cpu => processes.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent)),
+            ProcessSortColumn::Memory => processes.sort_by_key(|process| Reverse(process.rss_bytes))
+        }
+
+        processes.truncate(count);
+
+        processes
+    }
+
+    // завантаження вибраних процесів як звичайні сенсори, щоб вони потрапили на панель навантаження, в записи та експорт
+    pub fn get_tracked_readings(&self, tracked_pids: &[u32]) -> Vec<SensorReading> {
+        self.processes
+            .iter()
+            .filter(|process| tracked_pids.contains(&process.pid))
+            .map(|process| SensorReading {
+                device: "process".to_string(),
+                sensor: format!("{} ({})", process.name, process.pid),
+                kind: SensorKind::Load,
+                value: process.cpu_percent,
+                unit: MeasurementUnit::Percent
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,115 @@
+use std::fs;
+
+use crate::sensors::{SensorKind, SensorReading};
+use crate::units::MeasurementUnit;
+
+pub const LOADAVG_PATH: &str = "/proc/loadavg";
+pub const PROC_STAT_PATH: &str = "/proc/stat";
+
+// пристрій загальносистемних показників планувальника
+pub const SCHEDULER_DEVICE: &str = "system";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchedulerCounters {
+    pub context_switches: u64,
+    pub interrupts: u64,
+    // рядок processes - кількість створених процесів з моменту завантаження
+    pub forks: u64,
+    pub running: u64,
+    pub blocked: u64
+}
+
+// "0.52 0.58 0.59 2/1234 56789", потрібні лише перші три числа
+pub fn parse_loadavg(data: &str) -> Option<[f32; 3]> {
+    let mut fields = data.split_whitespace().map(|field| field.parse::<f32>().ok());
+
+    Some([fields.next()??, fields.next()??, fields.next()??])
+}
+
+pub fn parse_scheduler_counters(data: &str) -> SchedulerCounters {
+    let mut counters = SchedulerCounters::default();
+
+    for line in data.lines() {
+        let mut fields = line.split_whitespace();
+
+        // у рядку intr після загальної кількості йдуть лічильники кожного переривання, беру лише загальну
+        let (name, value) = match (fields.next(), fields.next().and_then(|value| value.parse::<u64>().ok())) {
+            (Some(name), Some(value)) => (name, value),
+            _ => continue
+        };
+
+        match name {
+            "ctxt" => counters.context_switches = value,
+            "intr" => counters.interrupts = value,
+            "processes" => counters.forks = value,
+            "procs_running" => counters.running = value,
+            "procs_blocked" => counters.blocked = value,
+            _ => {}
+        }
+    }
+
+    counters
+}
+
+fn reading(sensor: &str, kind: SensorKind, value: f32, unit: MeasurementUnit) -> SensorReading {
+    SensorReading {
+        device: SCHEDULER_DEVICE.to_string(),
+        sensor: sensor.to_string(),
+        kind,
+        value,
+        unit
+    }
+}
+
+// перемикання контексту, переривання та створення процесів - лічильники, тому показуються як частота між відліками
+#[derive(Default)]
+pub struct SchedulerMeter {
+    previous: Option<(SchedulerCounters, f64)>
+}
+
+impl SchedulerMeter {
+    pub fn update(&mut self, loadavg: Option<[f32; 3]>, counters: Option<SchedulerCounters>, timestamp: f64) -> Vec<SensorReading> {
+        let mut readings = Vec::new();
+
+        if let Some([one, five, fifteen]) = loadavg {
+            readings.push(reading("load 1m", SensorKind::LoadAverage, one, MeasurementUnit::Number));
+            readings.push(reading("load 5m", SensorKind::LoadAverage, five, MeasurementUnit::Number));
+            readings.push(reading("load 15m", SensorKind::LoadAverage, fifteen, MeasurementUnit::Number));
+        }
+
+        let counters = match counters {
+            Some(counters) => counters,
+            None => return readings
+        };
+
+        readings.push(reading("running", SensorKind::ProcessCount, counters.running as f32, MeasurementUnit::Number));
+        readings.push(reading("blocked", SensorKind::ProcessCount, counters.blocked as f32, MeasurementUnit::Number));
+
+        if let Some((previous, previous_timestamp)) = &self.previous {
+            let elapsed = timestamp - previous_timestamp;
+
+            if elapsed > 0.0 {
+                for (sensor, current, previous) in [
+                    ("context switches", counters.context_switches, previous.context_switches),
+                    ("interrupts", counters.interrupts, previous.interrupts),
+                    ("forks", counters.forks, previous.forks)
+                ] {
+                    if let Some(delta) = current.checked_sub(previous) {
+                        readings.push(reading(sensor, SensorKind::SchedulerEvents, (delta as f64 / elapsed) as f32, MeasurementUnit::PerSecond));
+                    }
+                }
+            }
+        }
+
+        self.previous = Some((counters, timestamp));
+
+        readings
+    }
+}
+
+pub fn read_scheduler_statistics(scheduler_meter: &mut SchedulerMeter, timestamp: f64) -> Vec<SensorReading> {
+    let loadavg = fs::read_to_string(LOADAVG_PATH).ok().as_deref().and_then(parse_loadavg);
+    let counters = fs::read_to_string(PROC_STAT_PATH).ok().map(|data| parse_scheduler_counters(&data));
+
+    scheduler_meter.update(loadavg, counters, timestamp)
+}
@@ -0,0 +1,289 @@
+use std::time::Duration;
+
+use crate::cgroup::open_cgroup_monitor_if_requested;
+use crate::cli_arguments::CliArguments;
+use crate::localization::{detect_language, tr, tr_with, Language};
+use crate::sensors::{initialize_com, SensorCollector, SensorKind, SensorReading};
+use crate::units::MeasurementUnit;
+
+// кожен метод читання SensorCollector - окремий бекенд, навіть якщо кілька з них читають ті самі файли hwmon
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SensorBackend {
+    Temperatures,
+    Fans,
+    Nvml,
+    EnergyCounters,
+    CpuFrequency,
+    Disks,
+    Network,
+    PowerSupply,
+    Pressure,
+    Scheduler,
+    Cgroup
+}
+
+impl SensorBackend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            #[cfg(windows)]
+            SensorBackend::Temperatures => "wmi",
+            #[cfg(not(windows))]
+            SensorBackend::Temperatures => "hwmon",
+            SensorBackend::Fans => "hwmon",
+            SensorBackend::Nvml => "nvml",
+            SensorBackend::EnergyCounters => "powercap",
+            SensorBackend::CpuFrequency => "cpufreq",
+            SensorBackend::Disks => "diskstats",
+            SensorBackend::Network => "net/dev",
+            SensorBackend::PowerSupply => "power_supply",
+            SensorBackend::Pressure => "psi",
+            SensorBackend::Scheduler => "procfs",
+            SensorBackend::Cgroup => "cgroup"
+        }
+    }
+}
+
+pub struct InventoryEntry {
+    pub backend: SensorBackend,
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    // одиниця, в якій значення віддав бекенд, до перерахунку в базову одиницю виду
+    pub unit: MeasurementUnit,
+    pub value: f32,
+    // сенсори одного бекенда читаються разом, тому затримка - час усього читання бекенда
+    pub latency: Duration,
+    // помилки бекенда під час останнього читання
+    pub errors: u64,
+    // сенсор був раніше, але в останньому читанні його немає або він не відповів
+    pub is_missing: bool
+}
+
+impl InventoryEntry {
+    pub fn state(&self, language: Language) -> String {
+        if self.errors > 0 {
+            tr_with(language, "inventory.state_errors", &[("count", &self.errors.to_string())])
+        } else if self.is_missing {
+            tr(language, "inventory.state_missing").to_string()
+        } else {
+            tr(language, "inventory.state_ok").to_string()
+        }
+    }
+
+    // значення в тій самій одиниці, що й на графіках (температура - у вибраній користувачем); None, якщо значення немає
+    pub fn format_value(&self, temperature_unit: MeasurementUnit) -> Option<String> {
+        if self.value.is_nan() {
+            return None;
+        }
+
+        let unit = self.kind.display_unit(temperature_unit);
+
+        Some(unit.format_value(self.unit.convert(self.value, unit) as f64, 1))
+    }
+}
+
+#[derive(Default)]
+pub struct SensorInventory {
+    pub entries: Vec<InventoryEntry>
+}
+
+impl SensorInventory {
+    pub fn record(&mut self, backend: SensorBackend, latency: Duration, readings: &[SensorReading], errors: u64) {
+        for entry in self.entries.iter_mut().filter(|entry| entry.backend == backend) {
+            entry.latency = latency;
+            entry.errors = errors;
+            entry.is_missing = true;
+        }
+
+        for reading in readings {
+            let index = match self.entries.iter().position(|entry| entry.device == reading.device && entry.sensor == reading.sensor && entry.kind == reading.kind) {
+                Some(index) => index,
+                None => {
+                    self.entries.push(InventoryEntry {
+                        backend,
+                        device: reading.device.clone(),
+                        sensor: reading.sensor.clone(),
+                        kind: reading.kind,
+                        unit: reading.unit,
+                        value: f32::NAN,
+                        latency,
+                        errors,
+                        is_missing: true
+                    });
+
+                    self.entries.len() - 1
+                }
+            };
+
+            let entry = &mut self.entries[index];
+
+            entry.backend = backend;
+            entry.unit = reading.unit;
+            entry.value = reading.value;
+            entry.latency = latency;
+            entry.errors = errors;
+            entry.is_missing = reading.value.is_nan();
+        }
+    }
+}
+
+pub struct SensorPreference {
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    pub is_plotted: bool,
+    // порожній псевдонім - назва за замовчуванням
+    pub alias: String
+}
+
+// зберігаються лише сенсори, для яких користувач щось змінив, решта показується як раніше
+#[derive(Default)]
+pub struct SensorPreferences {
+    pub entries: Vec<SensorPreference>
+}
+
+impl SensorPreferences {
+    fn find(&self, device: &str, sensor: &str, kind: SensorKind) -> Option<&SensorPreference> {
+        self.entries.iter().find(|entry| entry.device == device && entry.sensor == sensor && entry.kind == kind)
+    }
+
+    pub fn is_plotted(&self, device: &str, sensor: &str, kind: SensorKind, is_plotted_by_default: bool) -> bool {
+        match self.find(device, sensor, kind) {
+            Some(entry) => entry.is_plotted,
+            None => is_plotted_by_default
+        }
+    }
+
+    // сенсор, який користувач явно прибрав з графіків, не показується ні наживо, ні в історії, записах чи міні-вікні
+    pub fn is_hidden(&self, device: &str, sensor: &str, kind: SensorKind) -> bool {
+        self.find(device, sensor, kind).is_some_and(|entry| !entry.is_plotted)
+    }
+
+    pub fn alias(&self, device: &str, sensor: &str, kind: SensorKind) -> Option<&str> {
+        self.find(device, sensor, kind)
+            .map(|entry| entry.alias.as_str())
+            .filter(|alias| !alias.is_empty())
+    }
+
+    pub fn get_or_insert(&mut self, device: &str, sensor: &str, kind: SensorKind, is_plotted_by_default: bool) -> &mut SensorPreference {
+        let index = match self.entries.iter().position(|entry| entry.device == device && entry.sensor == sensor && entry.kind == kind) {
+            Some(index) => index,
+            None => {
+                self.entries.push(SensorPreference {
+                    device: device.to_string(),
+                    sensor: sensor.to_string(),
+                    kind,
+                    is_plotted: is_plotted_by_default,
+                    alias: String::new()
+                });
+
+                self.entries.len() - 1
+            }
+        };
+
+        &mut self.entries[index]
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let entries: Vec<serde_json::Value> = self.entries
+            .iter()
+            .map(|entry| serde_json::json!({
+                "device": entry.device,
+                "sensor": entry.sensor,
+                "kind": entry.kind.name(),
+                "is_plotted": entry.is_plotted,
+                "alias": entry.alias
+            }))
+            .collect();
+
+        serde_json::Value::Array(entries)
+    }
+
+    pub fn from_json(value: &serde_json::Value) -> SensorPreferences {
+        let mut sensor_preferences = SensorPreferences::default();
+
+        let entries = match value.as_array() {
+            Some(entries) => entries,
+            None => return sensor_preferences
+        };
+
+        for entry in entries {
+            if let Some(entry) = parse_sensor_preference(entry) {
+                sensor_preferences.entries.push(entry);
+            }
+        }
+
+        sensor_preferences
+    }
+}
+
+fn parse_sensor_preference(value: &serde_json::Value) -> Option<SensorPreference> {
+    Some(SensorPreference {
+        device: value["device"].as_str()?.to_string(),
+        sensor: value["sensor"].as_str()?.to_string(),
+        kind: SensorKind::from_name(value["kind"].as_str()?)?,
+        is_plotted: value["is_plotted"].as_bool()?,
+        alias: value["alias"].as_str().unwrap_or_default().to_string()
+    })
+}
+
+pub fn format_latency(latency: Duration) -> String {
+    format!("{:.*} ms", 2, latency.as_secs_f64() * 1000.0)
+}
+
+// --list-sensors: два читання з інтервалом, бо частоти, швидкості дисків та мережі рахуються з різниці лічильників
+pub fn print_sensor_inventory(arguments: &CliArguments) {
+    initialize_com();
+
+    let mut sensor_collector = SensorCollector::new();
+
+    sensor_collector.set_cgroup_monitor(open_cgroup_monitor_if_requested(&arguments.cgroup));
+
+    sensor_collector.read_all_sensors();
+    std::thread::sleep(Duration::from_millis(arguments.delay_between_temperature_requests));
+    sensor_collector.read_all_sensors();
+
+    let header = ["backend", "device", "sensor", "kind", "unit", "value", "latency", "state"];
+
+    let mut rows: Vec<[String; 8]> = Vec::new();
+
+    let language = detect_language();
+
+    for entry in &sensor_collector.inventory.entries {
+        let value = entry.format_value(arguments.temperature_unit).unwrap_or("-".to_string());
+
+        rows.push([
+            entry.backend.name().to_string(),
+            entry.device.clone(),
+            entry.sensor.clone(),
+            entry.kind.name().to_string(),
+            entry.unit.name().to_string(),
+            value,
+            format_latency(entry.latency),
+            entry.state(language)
+        ]);
+    }
+
+    let mut widths = header.map(|column| column.chars().count());
+
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| cells
+        .iter()
+        .zip(widths.iter())
+        .map(|(cell, width)| format!("{:<width$}", cell, width = *width))
+        .collect::<Vec<String>>()
+        .join("  ")
+        .trim_end()
+        .to_string();
+
+    println!("{}", format_row(header.to_vec()));
+
+    for row in &rows {
+        println!("{}", format_row(row.iter().map(|cell| cell.as_str()).collect()));
+    }
+}
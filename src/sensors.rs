@@ -0,0 +1,836 @@
+use std::time::Instant;
+
+use nvml_wrapper::bitmasks::device::ThrottleReasons;
+use nvml_wrapper::enum_wrappers::device::{TemperatureSensor, TemperatureThreshold};
+use nvml_wrapper::Nvml;
+
+use crate::cgroup::CgroupMonitor;
+use crate::gpu_temperature::get_gpu_name_nvml;
+use crate::power_supply::PowerSupplies;
+use crate::sensor_inventory::{SensorBackend, SensorInventory};
+use crate::session_recording::get_current_unix_timestamp;
+use crate::thermal_trip_points::{TripPoint, TripPointKind};
+use crate::units::MeasurementUnit;
+
+#[cfg(windows)]
+use crate::cpu_temperature::{get_cpu_current_kelvin_temperature_using_wmi, get_cpu_name};
+#[cfg(windows)]
+use windows::{
+    Win32::System::Com::*,
+    Win32::System::Wmi::*,
+};
+
+#[cfg(not(windows))]
+use std::path::Path;
+#[cfg(not(windows))]
+use crate::cpu_frequency::{get_cpu_frequencies, get_cpu_throttle_counts, CPU_DEVICES_DIRECTORY};
+#[cfg(not(windows))]
+use crate::disk_statistics::{get_disk_counters, get_disk_device_name, DiskRateMeter, BLOCK_DEVICES_DIRECTORY};
+#[cfg(not(windows))]
+use crate::energy_counters::{get_energy_counters, EnergyPowerMeter};
+#[cfg(not(windows))]
+use crate::network_statistics::{get_network_counters, NetworkRateMeter};
+#[cfg(not(windows))]
+use crate::power_supply::{read_power_supplies, POWER_SUPPLY_DIRECTORY};
+#[cfg(not(windows))]
+use crate::pressure_stall::{get_pressure_samples, PressureStallMeter};
+#[cfg(not(windows))]
+use crate::scheduler_statistics::{read_scheduler_statistics, SchedulerMeter};
+#[cfg(not(windows))]
+use crate::hwmon::{get_cpu_name_from_proc_cpuinfo, get_hwmon_cpu_packages, get_hwmon_device_name, get_hwmon_directories, get_hwmon_fans, get_hwmon_sensor_name, get_hwmon_temperatures, HWMON_DIRECTORY};
+#[cfg(not(windows))]
+use crate::thermal_trip_points::{read_hwmon_trip_points, read_thermal_zone_trip_points, THERMAL_ZONES_DIRECTORY};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SensorKind {
+    Temperature,
+    Load,
+    Power,
+    Memory,
+    FanSpeed,
+    FanDuty,
+    Frequency,
+    Throttling,
+    DiskThroughput,
+    DiskOperations,
+    NetworkThroughput,
+    NetworkPackets,
+    BatteryCharge,
+    Voltage,
+    AcOnline,
+    Pressure,
+    LoadAverage,
+    SchedulerEvents,
+    ProcessCount,
+    ThrottleCount
+}
+
+// порядок панелей графіків за замовчуванням
+pub const SENSOR_KINDS: [SensorKind; 20] = [
+    SensorKind::Temperature,
+    SensorKind::Load,
+    SensorKind::Power,
+    SensorKind::Frequency,
+    SensorKind::Throttling,
+    SensorKind::ThrottleCount,
+    SensorKind::Pressure,
+    SensorKind::LoadAverage,
+    SensorKind::SchedulerEvents,
+    SensorKind::ProcessCount,
+    SensorKind::Memory,
+    SensorKind::FanSpeed,
+    SensorKind::FanDuty,
+    SensorKind::DiskThroughput,
+    SensorKind::DiskOperations,
+    SensorKind::NetworkThroughput,
+    SensorKind::NetworkPackets,
+    SensorKind::BatteryCharge,
+    SensorKind::Voltage,
+    SensorKind::AcOnline
+];
+
+impl SensorKind {
+    // стабільні номери для файлів запису сесії, тому нові види додаються лише в кінець
+    pub fn to_id(&self) -> u8 {
+        match self {
+            SensorKind::Temperature => 0,
+            SensorKind::Load => 1,
+            SensorKind::Power => 2,
+            SensorKind::Memory => 3,
+            SensorKind::FanSpeed => 4,
+            SensorKind::FanDuty => 5,
+            SensorKind::Frequency => 6,
+            SensorKind::Throttling => 7,
+            SensorKind::DiskThroughput => 8,
+            SensorKind::DiskOperations => 9,
+            SensorKind::NetworkThroughput => 10,
+            SensorKind::NetworkPackets => 11,
+            SensorKind::BatteryCharge => 12,
+            SensorKind::Voltage => 13,
+            SensorKind::AcOnline => 14,
+            SensorKind::Pressure => 15,
+            SensorKind::LoadAverage => 16,
+            SensorKind::SchedulerEvents => 17,
+            SensorKind::ProcessCount => 18,
+            SensorKind::ThrottleCount => 19
+        }
+    }
+
+    pub fn from_id(id: u8) -> Option<SensorKind> {
+        match id {
+            0 => Some(SensorKind::Temperature),
+            1 => Some(SensorKind::Load),
+            2 => Some(SensorKind::Power),
+            3 => Some(SensorKind::Memory),
+            4 => Some(SensorKind::FanSpeed),
+            5 => Some(SensorKind::FanDuty),
+            6 => Some(SensorKind::Frequency),
+            7 => Some(SensorKind::Throttling),
+            8 => Some(SensorKind::DiskThroughput),
+            9 => Some(SensorKind::DiskOperations),
+            10 => Some(SensorKind::NetworkThroughput),
+            11 => Some(SensorKind::NetworkPackets),
+            12 => Some(SensorKind::BatteryCharge),
+            13 => Some(SensorKind::Voltage),
+            14 => Some(SensorKind::AcOnline),
+            15 => Some(SensorKind::Pressure),
+            16 => Some(SensorKind::LoadAverage),
+            17 => Some(SensorKind::SchedulerEvents),
+            18 => Some(SensorKind::ProcessCount),
+            19 => Some(SensorKind::ThrottleCount),
+            _ => None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SensorKind::Temperature => "temperature",
+            SensorKind::Load => "load",
+            SensorKind::Power => "power",
+            SensorKind::Memory => "memory",
+            SensorKind::FanSpeed => "fan_speed",
+            SensorKind::FanDuty => "fan_duty",
+            SensorKind::Frequency => "frequency",
+            SensorKind::Throttling => "throttling",
+            SensorKind::DiskThroughput => "disk_throughput",
+            SensorKind::DiskOperations => "disk_operations",
+            SensorKind::NetworkThroughput => "network_throughput",
+            SensorKind::NetworkPackets => "network_packets",
+            SensorKind::BatteryCharge => "battery_charge",
+            SensorKind::Voltage => "voltage",
+            SensorKind::AcOnline => "ac_online",
+            SensorKind::Pressure => "pressure",
+            SensorKind::LoadAverage => "load_average",
+            SensorKind::SchedulerEvents => "scheduler_events",
+            SensorKind::ProcessCount => "process_count",
+            SensorKind::ThrottleCount => "throttle_count"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<SensorKind> {
+        SENSOR_KINDS.iter().find(|kind| kind.name() == name).copied()
+    }
+
+    // одиниця, в якій значення зберігаються в історії, записах і сховищі
+    pub fn unit(&self) -> MeasurementUnit {
+        match self {
+            SensorKind::Temperature => MeasurementUnit::Celsius,
+            SensorKind::Load => MeasurementUnit::Percent,
+            SensorKind::Power => MeasurementUnit::Watt,
+            SensorKind::Memory => MeasurementUnit::Byte,
+            SensorKind::FanSpeed => MeasurementUnit::Rpm,
+            SensorKind::FanDuty => MeasurementUnit::Percent,
+            SensorKind::Frequency => MeasurementUnit::Megahertz,
+            SensorKind::Throttling => MeasurementUnit::Flag,
+            SensorKind::DiskThroughput => MeasurementUnit::BytePerSecond,
+            SensorKind::DiskOperations => MeasurementUnit::OperationsPerSecond,
+            SensorKind::NetworkThroughput => MeasurementUnit::BytePerSecond,
+            SensorKind::NetworkPackets => MeasurementUnit::PacketsPerSecond,
+            SensorKind::BatteryCharge => MeasurementUnit::Percent,
+            SensorKind::Voltage => MeasurementUnit::Volt,
+            SensorKind::AcOnline => MeasurementUnit::Flag,
+            SensorKind::Pressure => MeasurementUnit::Percent,
+            SensorKind::LoadAverage => MeasurementUnit::Number,
+            SensorKind::SchedulerEvents => MeasurementUnit::PerSecond,
+            SensorKind::ProcessCount => MeasurementUnit::Number,
+            SensorKind::ThrottleCount => MeasurementUnit::Number
+        }
+    }
+
+    // одиниця, в якій значення показуються користувачу та експортуються
+    pub fn display_unit(&self, temperature_unit: MeasurementUnit) -> MeasurementUnit {
+        match self {
+            SensorKind::Temperature => temperature_unit,
+            _ => self.unit()
+        }
+    }
+
+    pub fn to_display_value(&self, value: f32, temperature_unit: MeasurementUnit) -> f32 {
+        self.unit().convert(value, self.display_unit(temperature_unit))
+    }
+
+    pub fn from_display_value(&self, value: f32, temperature_unit: MeasurementUnit) -> f32 {
+        self.display_unit(temperature_unit).convert(value, self.unit())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SensorReading {
+    // "cpu", "gpu", або назва чипа hwmon для всього іншого
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    pub value: f32,
+    // одиниця, в якій значення повернуло джерело
+    pub unit: MeasurementUnit
+}
+
+impl SensorReading {
+    pub fn converted_to(self, unit: MeasurementUnit) -> SensorReading {
+        SensorReading {
+            value: self.unit.convert(self.value, unit),
+            unit,
+            ..self
+        }
+    }
+}
+
+pub fn find_sensor_value(readings: &[SensorReading], device: &str, kind: SensorKind) -> Option<f32> {
+    readings
+        .iter()
+        .find(|reading| reading.device == device && reading.kind == kind)
+        .map(|reading| reading.value)
+}
+
+// дві однакові відеокарти мають ту саму назву, тому на початку стоїть номер nvml
+pub fn get_gpu_sensor_name(index: u32, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("GPU {}: {}", index, name),
+        None => format!("GPU {}", index)
+    }
+}
+
+// приховує відмінності між windows (WMI) та linux (hwmon), щоб реалізації ui не залежали від платформи
+pub struct SensorCollector {
+    #[cfg(windows)]
+    wmi_server: IWbemServices,
+    // на машинах без відеокарти nvidia (наприклад на серверах у headless режимі) nvml відсутній
+    nvml: Option<Nvml>,
+    #[cfg(not(windows))]
+    energy_power_meter: EnergyPowerMeter,
+    #[cfg(not(windows))]
+    previous_cpu_throttle_count: Option<u64>,
+    #[cfg(not(windows))]
+    disk_rate_meter: DiskRateMeter,
+    #[cfg(not(windows))]
+    network_rate_meter: NetworkRateMeter,
+    #[cfg(not(windows))]
+    pressure_stall_meter: PressureStallMeter,
+    #[cfg(not(windows))]
+    scheduler_meter: SchedulerMeter,
+    // стан батарей з останнього відліку, кількість циклів та статус не мають сенсу як графіки, тому показуються окремо
+    pub power_supplies: PowerSupplies,
+    cgroup_monitor: Option<CgroupMonitor>,
+    // усі сенсори, які будь-коли повертав хоч один бекенд, з останнім значенням та станом
+    pub inventory: SensorInventory,
+    pub read_errors_total: u64
+}
+
+impl Default for SensorCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SensorCollector {
+    pub fn new() -> Self {
+        #[cfg(windows)]
+        let wmi_server = unsafe {
+            // ініціалізую інтерфейс IWbemServices, він використовується для доступу до служб WMI
+            let locator: IWbemLocator = CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER).unwrap();
+            locator.ConnectServer(&windows::core::BSTR::from("root\\cimv2"), None, None, None, 0, None, None).unwrap()
+        };
+
+        // ініціалізую nvml
+        let nvml = match Nvml::init() {
+            Ok(nvml) => Some(nvml),
+            Err(error) => {
+                log::warn!("nvml недоступний: {}", error);
+
+                None
+            }
+        };
+
+        Self {
+            #[cfg(windows)]
+            wmi_server,
+            nvml,
+            #[cfg(not(windows))]
+            energy_power_meter: EnergyPowerMeter::default(),
+            #[cfg(not(windows))]
+            previous_cpu_throttle_count: None,
+            #[cfg(not(windows))]
+            disk_rate_meter: DiskRateMeter::default(),
+            #[cfg(not(windows))]
+            network_rate_meter: NetworkRateMeter::default(),
+            #[cfg(not(windows))]
+            pressure_stall_meter: PressureStallMeter::default(),
+            #[cfg(not(windows))]
+            scheduler_meter: SchedulerMeter::default(),
+            power_supplies: PowerSupplies::default(),
+            cgroup_monitor: None,
+            inventory: SensorInventory::default(),
+            read_errors_total: 0
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn get_cpu_name(&self) -> Option<String> {
+        get_cpu_name(&self.wmi_server)
+    }
+
+    #[cfg(not(windows))]
+    pub fn get_cpu_name(&self) -> Option<String> {
+        get_cpu_name_from_proc_cpuinfo()
+    }
+
+    pub fn get_gpu_name(&mut self) -> Option<String> {
+        self.nvml.as_mut().map(get_gpu_name_nvml)
+    }
+
+    pub fn set_cgroup_monitor(&mut self, cgroup_monitor: Option<CgroupMonitor>) {
+        self.cgroup_monitor = cgroup_monitor;
+    }
+
+    // точки не змінюються під час роботи, тому читаються один раз при запуску
+    pub fn read_trip_points(&self) -> Vec<TripPoint> {
+        let mut trip_points = Vec::new();
+
+        self.read_system_trip_points(&mut trip_points);
+        self.read_nvml_trip_points(&mut trip_points);
+
+        trip_points
+    }
+
+    #[cfg(windows)]
+    fn read_system_trip_points(&self, _trip_points: &mut Vec<TripPoint>) {}
+
+    #[cfg(not(windows))]
+    fn read_system_trip_points(&self, trip_points: &mut Vec<TripPoint>) {
+        trip_points.extend(read_thermal_zone_trip_points(Path::new(THERMAL_ZONES_DIRECTORY)));
+
+        let directories = get_hwmon_directories(Path::new(HWMON_DIRECTORY));
+
+        for (directory, cpu_package) in directories.iter().zip(get_hwmon_cpu_packages(&directories)) {
+            trip_points.extend(read_hwmon_trip_points(directory, cpu_package));
+        }
+    }
+
+    fn read_nvml_trip_points(&self, trip_points: &mut Vec<TripPoint>) {
+        let nvml = match &self.nvml {
+            Some(nvml) => nvml,
+            None => return
+        };
+
+        for index in 0..nvml.device_count().unwrap_or(0) {
+            let device = match nvml.device_by_index(index) {
+                Ok(device) => device,
+                Err(_) => continue
+            };
+
+            let name = get_gpu_sensor_name(index, device.name().ok().as_deref());
+
+            for (threshold, kind) in [(TemperatureThreshold::Slowdown, TripPointKind::Slowdown), (TemperatureThreshold::Shutdown, TripPointKind::Shutdown)] {
+                if let Ok(celsius) = device.temperature_threshold(threshold) {
+                    trip_points.push(TripPoint {
+                        device: "gpu".to_string(),
+                        sensor: Some(name.clone()),
+                        kind,
+                        celsius: celsius as f32
+                    });
+                }
+            }
+        }
+    }
+
+    pub fn read_all_sensors(&mut self) -> Vec<SensorReading> {
+        let mut readings = Vec::new();
+
+        self.read_backend(SensorBackend::Temperatures, &mut readings, Self::read_cpu_sensors);
+        self.read_backend(SensorBackend::Fans, &mut readings, Self::read_fan_sensors);
+        self.read_backend(SensorBackend::Nvml, &mut readings, Self::read_nvml_sensors);
+
+        self.read_backend(SensorBackend::EnergyCounters, &mut readings, Self::read_energy_sensors);
+        self.read_backend(SensorBackend::CpuFrequency, &mut readings, Self::read_cpu_frequency_sensors);
+
+        // у режимі cgroup навантаження, пам'ять, диски та тиск беруться з самої cgroup,
+        // а решта показників лишається загальносистемною, бо залізо та живлення спільні для всіх
+        if self.cgroup_monitor.is_some() {
+            self.read_backend(SensorBackend::Cgroup, &mut readings, Self::read_cgroup_sensors);
+        } else {
+            self.read_backend(SensorBackend::Disks, &mut readings, Self::read_disk_sensors);
+            self.read_backend(SensorBackend::Pressure, &mut readings, Self::read_pressure_sensors);
+        }
+
+        self.read_backend(SensorBackend::Network, &mut readings, Self::read_network_sensors);
+        self.read_backend(SensorBackend::PowerSupply, &mut readings, Self::read_power_supply_sensors);
+        self.read_backend(SensorBackend::Scheduler, &mut readings, Self::read_scheduler_sensors);
+
+        // далі програма працює лише з базовими одиницями видів сенсорів, тому все перераховую тут
+        readings
+            .into_iter()
+            .map(|reading| {
+                let unit = reading.kind.unit();
+
+                reading.converted_to(unit)
+            })
+            .collect()
+    }
+
+    // інвентар сенсорів має знати, який бекенд віддав значення, скільки тривало читання та чи були помилки
+    fn read_backend(&mut self, backend: SensorBackend, readings: &mut Vec<SensorReading>, read: fn(&mut Self, &mut Vec<SensorReading>)) {
+        let first_index = readings.len();
+        let read_errors_before = self.read_errors_total;
+        let read_started = Instant::now();
+
+        read(self, readings);
+
+        self.inventory.record(backend, read_started.elapsed(), &readings[first_index..], self.read_errors_total - read_errors_before);
+    }
+
+    fn read_cgroup_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        if let Some(cgroup_monitor) = &mut self.cgroup_monitor {
+            match cgroup_monitor.read(get_current_unix_timestamp()) {
+                Ok(cgroup_readings) => readings.extend(cgroup_readings),
+                Err(_) => {
+                    self.read_errors_total += 1;
+                }
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    fn read_cpu_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for (index, temperature) in get_cpu_current_kelvin_temperature_using_wmi(&self.wmi_server).into_iter().enumerate() {
+            readings.push(SensorReading {
+                device: "cpu".to_string(),
+                sensor: format!("Thermal zone {}", index),
+                kind: SensorKind::Temperature,
+                value: temperature,
+                unit: MeasurementUnit::Kelvin
+            });
+        }
+    }
+
+    #[cfg(not(windows))]
+    fn read_cpu_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for temperature in get_hwmon_temperatures(Path::new(HWMON_DIRECTORY)) {
+            readings.push(SensorReading {
+                device: get_hwmon_device_name(&temperature.chip_name, temperature.drive_name.as_deref()),
+                sensor: get_hwmon_sensor_name(&temperature.label, temperature.cpu_package),
+                kind: SensorKind::Temperature,
+                value: temperature.celsius,
+                unit: MeasurementUnit::Celsius
+            });
+        }
+    }
+
+    #[cfg(windows)]
+    fn read_cpu_frequency_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    #[cfg(not(windows))]
+    fn read_cpu_frequency_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for (sensor, megahertz) in get_cpu_frequencies(Path::new(CPU_DEVICES_DIRECTORY)) {
+            readings.push(SensorReading {
+                device: "cpu".to_string(),
+                sensor,
+                kind: SensorKind::Frequency,
+                value: megahertz,
+                unit: MeasurementUnit::Megahertz
+            });
+        }
+
+        // лічильники лише зростають, тому тротлінг між двома відліками - це будь-яка їх зміна
+        if let Some(counts) = get_cpu_throttle_counts(Path::new(CPU_DEVICES_DIRECTORY)) {
+            let total = counts.core_count + counts.package_count;
+            let is_throttling = matches!(self.previous_cpu_throttle_count, Some(previous_count) if total > previous_count);
+
+            self.previous_cpu_throttle_count = Some(total);
+
+            readings.push(SensorReading {
+                device: "cpu".to_string(),
+                sensor: "thermal throttle".to_string(),
+                kind: SensorKind::Throttling,
+                value: if is_throttling { 1.0 } else { 0.0 },
+                unit: MeasurementUnit::Flag
+            });
+
+            for (sensor, count) in [("core throttle count", counts.core_count), ("package throttle count", counts.package_count)] {
+                readings.push(SensorReading {
+                    device: "cpu".to_string(),
+                    sensor: sensor.to_string(),
+                    kind: SensorKind::ThrottleCount,
+                    value: count as f32,
+                    unit: MeasurementUnit::Number
+                });
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    fn read_disk_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    #[cfg(not(windows))]
+    fn read_disk_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for rates in self.disk_rate_meter.update(get_disk_counters(), get_current_unix_timestamp()) {
+            let device = get_disk_device_name(Path::new(BLOCK_DEVICES_DIRECTORY), &rates.name);
+
+            for (direction, bytes_per_second, operations_per_second) in [
+                ("read", rates.read_bytes_per_second, rates.read_operations_per_second),
+                ("write", rates.write_bytes_per_second, rates.write_operations_per_second)
+            ] {
+                readings.push(SensorReading {
+                    device: device.clone(),
+                    sensor: format!("{} {}", rates.name, direction),
+                    kind: SensorKind::DiskThroughput,
+                    value: bytes_per_second,
+                    unit: MeasurementUnit::BytePerSecond
+                });
+
+                readings.push(SensorReading {
+                    device: device.clone(),
+                    sensor: format!("{} {}", rates.name, direction),
+                    kind: SensorKind::DiskOperations,
+                    value: operations_per_second,
+                    unit: MeasurementUnit::OperationsPerSecond
+                });
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    fn read_network_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    #[cfg(not(windows))]
+    fn read_network_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for rates in self.network_rate_meter.update(get_network_counters(), get_current_unix_timestamp()) {
+            for (direction, bytes_per_second, packets_per_second) in [
+                ("receive", rates.received_bytes_per_second, rates.received_packets_per_second),
+                ("transmit", rates.transmitted_bytes_per_second, rates.transmitted_packets_per_second)
+            ] {
+                readings.push(SensorReading {
+                    device: rates.interface.clone(),
+                    sensor: direction.to_string(),
+                    kind: SensorKind::NetworkThroughput,
+                    value: bytes_per_second,
+                    unit: MeasurementUnit::BytePerSecond
+                });
+
+                readings.push(SensorReading {
+                    device: rates.interface.clone(),
+                    sensor: direction.to_string(),
+                    kind: SensorKind::NetworkPackets,
+                    value: packets_per_second,
+                    unit: MeasurementUnit::PacketsPerSecond
+                });
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    fn read_power_supply_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    #[cfg(not(windows))]
+    fn read_power_supply_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        self.power_supplies = read_power_supplies(Path::new(POWER_SUPPLY_DIRECTORY));
+
+        for battery in &self.power_supplies.batteries {
+            for (kind, sensor, value, unit) in [
+                (SensorKind::BatteryCharge, "charge", battery.capacity_percent, MeasurementUnit::Percent),
+                (SensorKind::Power, "power", battery.power_watts, MeasurementUnit::Watt),
+                (SensorKind::Voltage, "voltage", battery.voltage, MeasurementUnit::Volt)
+            ] {
+                if let Some(value) = value {
+                    readings.push(SensorReading {
+                        device: battery.name.clone(),
+                        sensor: sensor.to_string(),
+                        kind,
+                        value,
+                        unit
+                    });
+                }
+            }
+        }
+
+        if let Some(is_ac_online) = self.power_supplies.is_ac_online {
+            readings.push(SensorReading {
+                device: "ac".to_string(),
+                sensor: "online".to_string(),
+                kind: SensorKind::AcOnline,
+                value: if is_ac_online { 1.0 } else { 0.0 },
+                unit: MeasurementUnit::Flag
+            });
+        }
+    }
+
+    #[cfg(windows)]
+    fn read_scheduler_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    #[cfg(not(windows))]
+    fn read_scheduler_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        readings.extend(read_scheduler_statistics(&mut self.scheduler_meter, get_current_unix_timestamp()));
+    }
+
+    #[cfg(windows)]
+    fn read_pressure_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    // без CONFIG_PSI файлів /proc/pressure немає, тоді й панелі тиску немає
+    #[cfg(not(windows))]
+    fn read_pressure_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for reading in self.pressure_stall_meter.update(get_pressure_samples(), get_current_unix_timestamp()) {
+            readings.push(SensorReading {
+                device: reading.source,
+                sensor: reading.sensor,
+                kind: SensorKind::Pressure,
+                value: reading.percent,
+                unit: MeasurementUnit::Percent
+            });
+        }
+    }
+
+    #[cfg(windows)]
+    fn read_energy_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    // energy_uj з 2020 року читається лише від root, тоді лічильників просто немає і панель потужності процесора порожня
+    #[cfg(not(windows))]
+    fn read_energy_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for (sensor, watts) in self.energy_power_meter.update(get_energy_counters(), get_current_unix_timestamp()) {
+            readings.push(SensorReading {
+                device: "cpu".to_string(),
+                sensor,
+                kind: SensorKind::Power,
+                value: watts,
+                unit: MeasurementUnit::Watt
+            });
+        }
+    }
+
+    // WMI клас Win32_Fan на більшості плат не повертає обертів, тому на windows вентилятори видно лише через nvml
+    #[cfg(windows)]
+    fn read_fan_sensors(&mut self, _readings: &mut Vec<SensorReading>) {}
+
+    #[cfg(not(windows))]
+    fn read_fan_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        for fan in get_hwmon_fans(Path::new(HWMON_DIRECTORY)) {
+            if let Some(rpm) = fan.rpm {
+                readings.push(SensorReading {
+                    device: fan.chip_name.clone(),
+                    sensor: fan.label.clone(),
+                    kind: SensorKind::FanSpeed,
+                    value: rpm,
+                    unit: MeasurementUnit::Rpm
+                });
+            }
+
+            if let Some(duty_percent) = fan.duty_percent {
+                readings.push(SensorReading {
+                    device: fan.chip_name,
+                    sensor: fan.label,
+                    kind: SensorKind::FanDuty,
+                    value: duty_percent,
+                    unit: MeasurementUnit::Percent
+                });
+            }
+        }
+    }
+
+    fn read_nvml_sensors(&mut self, readings: &mut Vec<SensorReading>) {
+        let nvml = match &self.nvml {
+            Some(nvml) => nvml,
+            None => return
+        };
+
+        let device_count = match nvml.device_count() {
+            Ok(device_count) => device_count,
+            Err(_) => {
+                self.read_errors_total += 1;
+
+                return;
+            }
+        };
+
+        for index in 0..device_count {
+            let device = match nvml.device_by_index(index) {
+                Ok(device) => device,
+                Err(_) => {
+                    self.read_errors_total += 1;
+
+                    continue;
+                }
+            };
+
+            let name = get_gpu_sensor_name(index, device.name().ok().as_deref());
+
+            match device.temperature(TemperatureSensor::Gpu) {
+                Ok(temperature) => {
+                    readings.push(SensorReading {
+                        device: "gpu".to_string(),
+                        sensor: name.clone(),
+                        kind: SensorKind::Temperature,
+                        value: temperature as f32,
+                        unit: MeasurementUnit::Celsius
+                    });
+                },
+                Err(_) => {
+                    self.read_errors_total += 1;
+                }
+            }
+
+            match device.utilization_rates() {
+                Ok(utilization) => {
+                    readings.push(SensorReading {
+                        device: "gpu".to_string(),
+                        sensor: name.clone(),
+                        kind: SensorKind::Load,
+                        value: utilization.gpu as f32,
+                        unit: MeasurementUnit::Percent
+                    });
+                },
+                Err(_) => {
+                    self.read_errors_total += 1;
+                }
+            }
+
+            // не всі відеокарти повідомляють споживання, тому помилку тут не рахую
+            if let Ok(power) = device.power_usage() {
+                readings.push(SensorReading {
+                    device: "gpu".to_string(),
+                    sensor: name.clone(),
+                    kind: SensorKind::Power,
+                    value: power as f32,
+                    unit: MeasurementUnit::Milliwatt
+                });
+            }
+
+            match device.memory_info() {
+                Ok(memory) => {
+                    readings.push(SensorReading {
+                        device: "gpu".to_string(),
+                        sensor: name.clone(),
+                        kind: SensorKind::Memory,
+                        value: memory.used as f32,
+                        unit: MeasurementUnit::Byte
+                    });
+                },
+                Err(_) => {
+                    self.read_errors_total += 1;
+                }
+            }
+
+            // простій та налаштування частот застосунками - це не тротлінг, тому їх не враховую; старі карти причин не повідомляють
+            if let Ok(reasons) = device.current_throttle_reasons() {
+                let throttling_reasons = ThrottleReasons::SW_POWER_CAP
+                    | ThrottleReasons::HW_SLOWDOWN
+                    | ThrottleReasons::SW_THERMAL_SLOWDOWN
+                    | ThrottleReasons::HW_THERMAL_SLOWDOWN
+                    | ThrottleReasons::HW_POWER_BRAKE_SLOWDOWN;
+
+                readings.push(SensorReading {
+                    device: "gpu".to_string(),
+                    sensor: name.clone(),
+                    kind: SensorKind::Throttling,
+                    value: if reasons.intersects(throttling_reasons) { 1.0 } else { 0.0 },
+                    unit: MeasurementUnit::Flag
+                });
+            }
+
+            // nvml повертає швидкість вентилятора у відсотках від максимальної, а пасивно охолоджувані карти вентиляторів не мають
+            let fan_count = device.num_fans().unwrap_or(0);
+
+            for fan_index in 0..fan_count {
+                match device.fan_speed(fan_index) {
+                    Ok(fan_speed) => {
+                        let sensor = if fan_count == 1 {
+                            name.clone()
+                        } else {
+                            format!("{} fan {}", name, fan_index)
+                        };
+
+                        readings.push(SensorReading {
+                            device: "gpu".to_string(),
+                            sensor,
+                            kind: SensorKind::FanDuty,
+                            value: fan_speed as f32,
+                            unit: MeasurementUnit::Percent
+                        });
+                    },
+                    Err(_) => {
+                        self.read_errors_total += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+pub fn initialize_com() {
+    unsafe {
+        CoInitializeEx(None, COINIT_APARTMENTTHREADED).unwrap();
+
+        CoInitializeSecurity(
+            None,
+            -1,
+            None,
+            None,
+            RPC_C_AUTHN_LEVEL_DEFAULT,
+            RPC_C_IMP_LEVEL_IMPERSONATE,
+            None,
+            EOAC_NONE,
+            None,
+        ).unwrap();
+    }
+}
+
+#[cfg(not(windows))]
+pub fn initialize_com() {}
@@ -0,0 +1,364 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::sensors::{SensorKind, SensorReading};
+
+// кошики гістограми ростуть у геометричній прогресії, тож перцентиль має ту саму відносну похибку
+// і для простою мережі в сотні байтів, і для гігабайтів за секунду, а від'ємні значення мають власні кошики
+const HISTOGRAM_BIN_GROWTH: f32 = 1.01;
+
+// менші за модулем значення потрапляють у нульовий кошик
+const HISTOGRAM_MIN_MAGNITUDE: f32 = 0.001;
+
+// розріджена гістограма: ключ - номер кошика зі знаком значення, тож порядок ключів збігається з порядком значень
+#[derive(Clone, Default)]
+struct ValueHistogram {
+    bins: BTreeMap<i32, u32>,
+    count: u32
+}
+
+impl ValueHistogram {
+    fn bin_index(value: f32) -> i32 {
+        let magnitude = value.abs();
+
+        if magnitude < HISTOGRAM_MIN_MAGNITUDE {
+            return 0;
+        }
+
+        let index = 1 + ((magnitude / HISTOGRAM_MIN_MAGNITUDE).ln() / HISTOGRAM_BIN_GROWTH.ln()) as i32;
+
+        if value < 0.0 { -index } else { index }
+    }
+
+    // геометрична середина кошика
+    fn bin_value(index: i32) -> f32 {
+        if index == 0 {
+            return 0.0;
+        }
+
+        let magnitude = HISTOGRAM_MIN_MAGNITUDE * HISTOGRAM_BIN_GROWTH.powf(index.abs() as f32 - 0.5);
+
+        if index < 0 { -magnitude } else { magnitude }
+    }
+
+    fn add(&mut self, value: f32) {
+        *self.bins.entry(Self::bin_index(value)).or_insert(0) += 1;
+        self.count += 1;
+    }
+
+    fn remove(&mut self, value: f32) {
+        let index = Self::bin_index(value);
+
+        if let Some(bin) = self.bins.get_mut(&index) {
+            *bin -= 1;
+
+            if *bin == 0 {
+                self.bins.remove(&index);
+            }
+        }
+
+        self.count -= 1;
+    }
+
+    fn percentile(&self, percentile: f32) -> Option<f32> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = (self.count as f32 * percentile).ceil().max(1.0) as u32;
+
+        let mut accumulated = 0;
+
+        for (index, bin) in &self.bins {
+            accumulated += bin;
+
+            if accumulated >= rank {
+                return Some(Self::bin_value(*index));
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct StatisticsSummary {
+    pub current: f32,
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    pub p95: f32,
+    pub seconds_above_threshold: f64
+}
+
+// статистика за всю сесію, кожне значення враховується один раз, при надходженні
+#[derive(Clone)]
+pub struct SessionStatistics {
+    current: f32,
+    min: f32,
+    max: f32,
+    sum: f64,
+    count: u64,
+    histogram: ValueHistogram,
+    seconds_above_threshold: f64
+}
+
+impl SessionStatistics {
+    fn new() -> Self {
+        Self {
+            current: f32::NAN,
+            min: f32::MAX,
+            max: f32::MIN,
+            sum: 0.0,
+            count: 0,
+            histogram: ValueHistogram::default(),
+            seconds_above_threshold: 0.0
+        }
+    }
+
+    fn push(&mut self, value: f32, seconds_above_threshold: f64) {
+        self.current = value;
+        self.min = f32::min(self.min, value);
+        self.max = f32::max(self.max, value);
+        self.sum += value as f64;
+        self.count += 1;
+        self.histogram.add(value);
+        self.seconds_above_threshold += seconds_above_threshold;
+    }
+
+    pub fn summary(&self) -> Option<StatisticsSummary> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(StatisticsSummary {
+            current: self.current,
+            min: self.min,
+            max: self.max,
+            mean: (self.sum / self.count as f64) as f32,
+            // середина кошика може вийти за межі самих значень
+            p95: self.histogram.percentile(0.95).map(|p95| p95.clamp(self.min, self.max)).unwrap_or(f32::NAN),
+            seconds_above_threshold: self.seconds_above_threshold
+        })
+    }
+}
+
+// статистика за вікно з останніх значень: нове значення додається, найстаріше віднімається,
+// мінімум і максимум тримаються в монотонних чергах, тож за кадр нічого не перераховується з нуля
+#[derive(Clone)]
+pub struct WindowStatistics {
+    // номер значення, саме значення та скільки секунд воно було вище порогу
+    values: VecDeque<(u64, f32, f64)>,
+    next_index: u64,
+    sum: f64,
+    minimums: VecDeque<(u64, f32)>,
+    maximums: VecDeque<(u64, f32)>,
+    histogram: ValueHistogram,
+    seconds_above_threshold: f64
+}
+
+impl WindowStatistics {
+    fn new() -> Self {
+        Self {
+            values: VecDeque::new(),
+            next_index: 0,
+            sum: 0.0,
+            minimums: VecDeque::new(),
+            maximums: VecDeque::new(),
+            histogram: ValueHistogram::default(),
+            seconds_above_threshold: 0.0
+        }
+    }
+
+    fn push(&mut self, value: f32, seconds_above_threshold: f64, window_size: usize) {
+        let index = self.next_index;
+        self.next_index += 1;
+
+        self.values.push_back((index, value, seconds_above_threshold));
+        self.sum += value as f64;
+        self.histogram.add(value);
+        self.seconds_above_threshold += seconds_above_threshold;
+
+        while self.minimums.back().is_some_and(|(_, minimum)| *minimum >= value) {
+            self.minimums.pop_back();
+        }
+        self.minimums.push_back((index, value));
+
+        while self.maximums.back().is_some_and(|(_, maximum)| *maximum <= value) {
+            self.maximums.pop_back();
+        }
+        self.maximums.push_back((index, value));
+
+        while self.values.len() > window_size {
+            let (removed_index, removed_value, removed_seconds) = self.values.pop_front().unwrap();
+
+            self.sum -= removed_value as f64;
+            self.histogram.remove(removed_value);
+            self.seconds_above_threshold -= removed_seconds;
+
+            if self.minimums.front().is_some_and(|(index, _)| *index == removed_index) {
+                self.minimums.pop_front();
+            }
+
+            if self.maximums.front().is_some_and(|(index, _)| *index == removed_index) {
+                self.maximums.pop_front();
+            }
+        }
+    }
+
+    pub fn summary(&self) -> Option<StatisticsSummary> {
+        let (_, current, _) = self.values.back()?;
+        let min = self.minimums.front()?.1;
+        let max = self.maximums.front()?.1;
+
+        Some(StatisticsSummary {
+            current: *current,
+            min,
+            max,
+            mean: (self.sum / self.values.len() as f64) as f32,
+            // середина кошика може вийти за межі самих значень вікна
+            p95: self.histogram.percentile(0.95).map(|p95| p95.clamp(min, max)).unwrap_or(f32::NAN),
+            seconds_above_threshold: self.seconds_above_threshold.max(0.0)
+        })
+    }
+}
+
+// статистика за довільний проміжок часу, наприклад за масштабований на паузі графік; рахується з нуля,
+// тому її варто перераховувати лише тоді, коли змінюється проміжок або дані
+pub fn summarize_range(timestamps: &[f64], values: &[f32], time_range: [f64; 2], threshold: Option<f32>) -> Option<StatisticsSummary> {
+    let mut statistics = WindowStatistics::new();
+    let mut last_timestamp: Option<f64> = None;
+
+    for (timestamp, value) in timestamps.iter().zip(values.iter()) {
+        if *timestamp < time_range[0] || *timestamp > time_range[1] || value.is_nan() {
+            continue;
+        }
+
+        let seconds_above_threshold = match (threshold, last_timestamp) {
+            (Some(threshold), Some(last_timestamp)) if *value > threshold => timestamp - last_timestamp,
+            _ => 0.0
+        };
+
+        statistics.push(*value, seconds_above_threshold, usize::MAX);
+        last_timestamp = Some(*timestamp);
+    }
+
+    statistics.summary()
+}
+
+pub struct SeriesStatistics {
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    pub session: SessionStatistics,
+    pub window: WindowStatistics,
+    last_timestamp: Option<f64>
+}
+
+#[derive(Default)]
+pub struct StatisticsTable {
+    pub series: Vec<SeriesStatistics>
+}
+
+impl StatisticsTable {
+    // threshold_for повертає поріг для сенсора (або None, якщо для нього порогу немає)
+    pub fn push_sample(&mut self, timestamp: f64, readings: &[SensorReading], window_size: usize, threshold_for: impl Fn(&SensorReading) -> Option<f32>) {
+        for reading in readings {
+            if reading.value.is_nan() {
+                continue;
+            }
+
+            let index = match self.series.iter().position(|series| series.device == reading.device && series.sensor == reading.sensor && series.kind == reading.kind) {
+                Some(index) => index,
+                None => {
+                    self.series.push(SeriesStatistics {
+                        device: reading.device.clone(),
+                        sensor: reading.sensor.clone(),
+                        kind: reading.kind,
+                        session: SessionStatistics::new(),
+                        window: WindowStatistics::new(),
+                        last_timestamp: None
+                    });
+
+                    self.series.len() - 1
+                }
+            };
+
+            let series = &mut self.series[index];
+
+            // час між попереднім і поточним значенням вважається проведеним вище порогу, якщо поточне значення вище
+            let seconds_above_threshold = match (threshold_for(reading), series.last_timestamp) {
+                (Some(threshold), Some(last_timestamp)) if reading.value > threshold => timestamp - last_timestamp,
+                _ => 0.0
+            };
+
+            series.session.push(reading.value, seconds_above_threshold);
+            series.window.push(reading.value, seconds_above_threshold, window_size);
+            series.last_timestamp = Some(timestamp);
+        }
+    }
+}
+
+pub struct ThrottlingSummary {
+    pub device: String,
+    pub sensor: String,
+    // кількість окремих проміжків тротлінгу за сесію
+    pub intervals: u32,
+    pub seconds: f64,
+    pub is_throttling: bool,
+    last_timestamp: Option<f64>
+}
+
+#[derive(Default)]
+pub struct ThrottlingTable {
+    pub entries: Vec<ThrottlingSummary>
+}
+
+impl ThrottlingTable {
+    pub fn push_sample(&mut self, timestamp: f64, readings: &[SensorReading]) {
+        for reading in readings.iter().filter(|reading| reading.kind == SensorKind::Throttling && !reading.value.is_nan()) {
+            let index = match self.entries.iter().position(|entry| entry.device == reading.device && entry.sensor == reading.sensor) {
+                Some(index) => index,
+                None => {
+                    self.entries.push(ThrottlingSummary {
+                        device: reading.device.clone(),
+                        sensor: reading.sensor.clone(),
+                        intervals: 0,
+                        seconds: 0.0,
+                        is_throttling: false,
+                        last_timestamp: None
+                    });
+
+                    self.entries.len() - 1
+                }
+            };
+
+            let entry = &mut self.entries[index];
+            let is_throttling = reading.value >= 0.5;
+
+            if is_throttling {
+                if !entry.is_throttling {
+                    entry.intervals += 1;
+                }
+
+                if let Some(last_timestamp) = entry.last_timestamp {
+                    entry.seconds += timestamp - last_timestamp;
+                }
+            }
+
+            entry.is_throttling = is_throttling;
+            entry.last_timestamp = Some(timestamp);
+        }
+    }
+}
+
+pub fn format_duration(seconds: f64) -> String {
+    let seconds = seconds.max(0.0) as u64;
+
+    if seconds >= 3600 {
+        format!("{}h {:02}m {:02}s", seconds / 3600, seconds / 60 % 60, seconds % 60)
+    } else if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
@@ -0,0 +1,180 @@
+use crate::sensors::SensorKind;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SeriesChartType {
+    Line,
+    Area,
+    Step
+}
+
+pub const SERIES_CHART_TYPES: [SeriesChartType; 3] = [SeriesChartType::Line, SeriesChartType::Area, SeriesChartType::Step];
+
+impl SeriesChartType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SeriesChartType::Line => "line",
+            SeriesChartType::Area => "area",
+            SeriesChartType::Step => "step"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<SeriesChartType> {
+        SERIES_CHART_TYPES.iter().find(|chart_type| chart_type.name() == name).copied()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SeriesStyle {
+    pub color: [u8; 3],
+    pub width: f32,
+    pub chart_type: SeriesChartType,
+    pub is_dashed: bool
+}
+
+impl SeriesStyle {
+    fn new(color: [u8; 3]) -> Self {
+        Self {
+            color,
+            width: 2.0,
+            chart_type: SeriesChartType::Line,
+            is_dashed: false
+        }
+    }
+}
+
+// палітра Окабе-Іто: кольори розрізняються і при найпоширеніших видах дальтонізму (чорний замінений сірим, бо фон темний)
+pub const COLOR_BLIND_PALETTE: [[u8; 3]; 8] = [
+    [230, 159, 0],
+    [86, 180, 233],
+    [0, 158, 115],
+    [240, 228, 66],
+    [0, 114, 178],
+    [213, 94, 0],
+    [204, 121, 167],
+    [153, 153, 153]
+];
+
+pub struct SeriesStyleEntry {
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    pub style: SeriesStyle,
+    // зберігаються лише змінені користувачем стилі, інакше налаштування росли б з кожним новим процесом чи диском
+    pub is_edited: bool
+}
+
+#[derive(Default)]
+pub struct SeriesStyles {
+    pub entries: Vec<SeriesStyleEntry>
+}
+
+impl SeriesStyles {
+    pub fn get(&self, device: &str, sensor: &str, kind: SensorKind) -> SeriesStyle {
+        match self.entries.iter().find(|entry| entry.device == device && entry.sensor == sensor && entry.kind == kind) {
+            Some(entry) => entry.style,
+            None => SeriesStyle::new(COLOR_BLIND_PALETTE[0])
+        }
+    }
+
+    // новому сенсору дістається наступний колір палітри; повертає true, якщо стиль додано
+    pub fn assign_if_missing(&mut self, device: &str, sensor: &str, kind: SensorKind) -> bool {
+        if self.entries.iter().any(|entry| entry.device == device && entry.sensor == sensor && entry.kind == kind) {
+            return false;
+        }
+
+        self.entries.push(SeriesStyleEntry {
+            device: device.to_string(),
+            sensor: sensor.to_string(),
+            kind,
+            style: SeriesStyle::new(COLOR_BLIND_PALETTE[self.entries.len() % COLOR_BLIND_PALETTE.len()]),
+            is_edited: false
+        });
+
+        true
+    }
+
+    pub fn reset_to_palette(&mut self) {
+        for (index, entry) in self.entries.iter_mut().enumerate() {
+            entry.style = SeriesStyle::new(COLOR_BLIND_PALETTE[index % COLOR_BLIND_PALETTE.len()]);
+            entry.is_edited = false;
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let entries: Vec<serde_json::Value> = self.entries
+            .iter()
+            .filter(|entry| entry.is_edited)
+            .map(|entry| serde_json::json!({
+                "device": entry.device,
+                "sensor": entry.sensor,
+                "kind": entry.kind.name(),
+                "color": entry.style.color,
+                "width": entry.style.width,
+                "chart_type": entry.style.chart_type.name(),
+                "is_dashed": entry.style.is_dashed,
+                "is_edited": true
+            }))
+            .collect();
+
+        serde_json::Value::Array(entries)
+    }
+
+    // записи, які не вдалося розібрати (наприклад, з невідомим видом сенсора), пропускаються
+    pub fn from_json(value: &serde_json::Value) -> SeriesStyles {
+        let mut series_styles = SeriesStyles::default();
+
+        let entries = match value.as_array() {
+            Some(entries) => entries,
+            None => return series_styles
+        };
+
+        for entry in entries {
+            if let Some(mut entry) = parse_series_style_entry(entry) {
+                // старі налаштування зберігали стиль кожного сенсора, тоді зміненим вважаю лише той, що відрізняється від палітри
+                if !entry.is_edited {
+                    entry.is_edited = entry.style != SeriesStyle::new(COLOR_BLIND_PALETTE[series_styles.entries.len() % COLOR_BLIND_PALETTE.len()]);
+                }
+
+                series_styles.entries.push(entry);
+            }
+        }
+
+        series_styles
+    }
+}
+
+fn parse_series_style_entry(value: &serde_json::Value) -> Option<SeriesStyleEntry> {
+    let color = value["color"].as_array()?;
+
+    if color.len() != 3 {
+        return None;
+    }
+
+    Some(SeriesStyleEntry {
+        device: value["device"].as_str()?.to_string(),
+        sensor: value["sensor"].as_str()?.to_string(),
+        kind: SensorKind::from_name(value["kind"].as_str()?)?,
+        style: SeriesStyle {
+            color: [color[0].as_u64()? as u8, color[1].as_u64()? as u8, color[2].as_u64()? as u8],
+            width: value["width"].as_f64()? as f32,
+            chart_type: SeriesChartType::from_name(value["chart_type"].as_str()?)?,
+            is_dashed: value["is_dashed"].as_bool()?
+        },
+        is_edited: value["is_edited"].as_bool().unwrap_or(false)
+    })
+}
+
+// сходинки: значення тримається до наступного відліку, тому перед кожною точкою додається точка з попереднім значенням
+pub fn to_step_points(points: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    let mut result = Vec::with_capacity(points.len() * 2);
+
+    for (index, point) in points.iter().enumerate() {
+        if index > 0 {
+            result.push([point[0], points[index - 1][1]]);
+        }
+
+        result.push(*point);
+    }
+
+    result
+}
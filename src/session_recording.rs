@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::localization::{tr, Language};
+use crate::sensors::{SensorKind, SensorReading};
+
+// формат файлу: заголовок, а далі записи двох типів, кожен з яких дописується в кінець одним write_all:
+//   SENSOR_RECORD: u32 id, u8 вид сенсора, рядок device, рядок sensor (рядок = u32 довжина + utf8)
+//   SAMPLE_RECORD: f64 unix час у секундах, u32 кількість, далі пари (u32 id, f32 значення)
+// якщо програма впала посеред запису, неповний останній запис просто відкидається при читанні
+const RECORDING_MAGIC: &[u8; 8] = b"RMREC02\n";
+const SENSOR_RECORD: u8 = 1;
+const SAMPLE_RECORD: u8 = 2;
+
+pub const RECORDING_EXTENSION: &str = "rmrec";
+
+// write_all лише передає дані ядру, тож на диск вони примусово скидаються не рідше за цей інтервал
+const RECORDING_SYNC_INTERVAL: Duration = Duration::from_secs(5);
+
+pub fn get_current_unix_timestamp() -> f64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs_f64()
+}
+
+pub struct SessionRecorder {
+    file: File,
+    sensor_ids: HashMap<(String, String, SensorKind), u32>,
+    last_sync: Instant
+}
+
+impl SessionRecorder {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let mut file = OpenOptions::new().create_new(true).append(true).open(path)?;
+
+        file.write_all(RECORDING_MAGIC)?;
+        file.sync_all()?;
+
+        Ok(Self {
+            file,
+            sensor_ids: HashMap::new(),
+            last_sync: Instant::now()
+        })
+    }
+
+    pub fn record_sample(&mut self, timestamp: f64, readings: &[SensorReading]) -> io::Result<()> {
+        let mut buffer = Vec::new();
+        let mut sample = Vec::new();
+
+        for reading in readings {
+            let key = (reading.device.clone(), reading.sensor.clone(), reading.kind);
+
+            let id = match self.sensor_ids.get(&key) {
+                Some(id) => *id,
+                None => {
+                    let id = to_u32_length(self.sensor_ids.len())?;
+
+                    buffer.push(SENSOR_RECORD);
+                    buffer.extend_from_slice(&id.to_le_bytes());
+                    buffer.push(reading.kind.to_id());
+                    write_string(&mut buffer, &reading.device)?;
+                    write_string(&mut buffer, &reading.sensor)?;
+
+                    self.sensor_ids.insert(key, id);
+
+                    id
+                }
+            };
+
+            sample.extend_from_slice(&id.to_le_bytes());
+            sample.extend_from_slice(&reading.value.to_le_bytes());
+        }
+
+        buffer.push(SAMPLE_RECORD);
+        buffer.extend_from_slice(&timestamp.to_le_bytes());
+        buffer.extend_from_slice(&to_u32_length(readings.len())?.to_le_bytes());
+        buffer.extend_from_slice(&sample);
+
+        self.file.write_all(&buffer)?;
+
+        if self.last_sync.elapsed() >= RECORDING_SYNC_INTERVAL {
+            self.file.sync_data()?;
+            self.last_sync = Instant::now();
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for SessionRecorder {
+    fn drop(&mut self) {
+        let _ = self.file.sync_data();
+    }
+}
+
+// обрізана довжина зіпсувала б увесь решту файлу, тож замість приведення типу повертаю помилку
+fn to_u32_length(length: usize) -> io::Result<u32> {
+    u32::try_from(length).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("{} не вміщується в u32", length)))
+}
+
+fn write_string(buffer: &mut Vec<u8>, value: &str) -> io::Result<()> {
+    buffer.extend_from_slice(&to_u32_length(value.len())?.to_le_bytes());
+    buffer.extend_from_slice(value.as_bytes());
+
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct RecordedSeries {
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    // [unix час, значення]
+    pub points: Vec<[f64; 2]>
+}
+
+pub struct Recording {
+    pub series: Vec<RecordedSeries>,
+    pub start_timestamp: f64,
+    pub end_timestamp: f64
+}
+
+struct RecordingReader<'a> {
+    data: &'a [u8],
+    position: usize
+}
+
+impl<'a> RecordingReader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.position + length > self.data.len() {
+            return None;
+        }
+
+        let result = &self.data[self.position..self.position + length];
+        self.position += length;
+
+        Some(result)
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        self.take(4).map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_f32(&mut self) -> Option<f32> {
+        self.take(4).map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_f64(&mut self) -> Option<f64> {
+        self.take(8).map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        let length = self.read_u32()? as usize;
+
+        self.take(length).map(|bytes| String::from_utf8_lossy(bytes).to_string())
+    }
+}
+
+// помилка всередині io::Error, за якою format_recording_error показує перекладений текст
+#[derive(Debug)]
+struct NotRecordingError;
+
+impl fmt::Display for NotRecordingError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "not a resource monitor recording")
+    }
+}
+
+impl std::error::Error for NotRecordingError {}
+
+pub fn format_recording_error(error: &io::Error, language: Language) -> String {
+    match error.get_ref() {
+        Some(inner) if inner.is::<NotRecordingError>() => tr(language, "recording.not_a_recording").to_string(),
+        _ => error.to_string()
+    }
+}
+
+pub fn load_recording(path: &Path) -> io::Result<Recording> {
+    let mut data = Vec::new();
+    File::open(path)?.read_to_end(&mut data)?;
+
+    if !data.starts_with(RECORDING_MAGIC) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, NotRecordingError));
+    }
+
+    let mut reader = RecordingReader { data: &data, position: RECORDING_MAGIC.len() };
+
+    let mut series: Vec<RecordedSeries> = Vec::new();
+    let mut series_indexes: HashMap<u32, usize> = HashMap::new();
+    let mut start_timestamp = f64::MAX;
+    let mut end_timestamp = 0.0;
+
+    // кожна гілка повертає None, якщо запис обірваний, і тоді читання просто зупиняється
+    loop {
+        let record_start = reader.position;
+
+        let is_complete = match reader.read_u8() {
+            Some(SENSOR_RECORD) => (|| {
+                let id = reader.read_u32()?;
+                let kind = SensorKind::from_id(reader.read_u8()?)?;
+                let device = reader.read_string()?;
+                let sensor = reader.read_string()?;
+
+                series_indexes.insert(id, series.len());
+                series.push(RecordedSeries { device, sensor, kind, points: Vec::new() });
+
+                Some(())
+            })().is_some(),
+            Some(SAMPLE_RECORD) => (|| {
+                let timestamp = reader.read_f64()?;
+                let count = reader.read_u32()?;
+
+                let mut values = Vec::new();
+
+                for _ in 0..count {
+                    values.push((reader.read_u32()?, reader.read_f32()?));
+                }
+
+                for (id, value) in values {
+                    if let Some(index) = series_indexes.get(&id) {
+                        series[*index].points.push([timestamp, value as f64]);
+                    }
+                }
+
+                start_timestamp = f64::min(start_timestamp, timestamp);
+                end_timestamp = f64::max(end_timestamp, timestamp);
+
+                Some(())
+            })().is_some(),
+            _ => false
+        };
+
+        if !is_complete {
+            if record_start != data.len() {
+                log::warn!("запис {} обірваний, прочитано {} з {} байт", path.display(), record_start, data.len());
+            }
+
+            break;
+        }
+    }
+
+    if series.iter().all(|series| series.points.is_empty()) {
+        start_timestamp = 0.0;
+    }
+
+    Ok(Recording {
+        series,
+        start_timestamp,
+        end_timestamp
+    })
+}
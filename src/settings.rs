@@ -0,0 +1,129 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::sensor_inventory::SensorPreferences;
+use crate::sensors::{SensorKind, SENSOR_KINDS};
+use crate::series_styles::SeriesStyles;
+
+pub struct Settings {
+    pub series_styles: SeriesStyles,
+    // порядок панелей графіків, кожна панель показує один вид сенсорів
+    pub chart_panels: Vec<SensorKind>,
+    // увімкнення графіка та псевдоніми з вікна сенсорів
+    pub sensor_preferences: SensorPreferences,
+    // сповіщення з тим самим ключем не повторюється частіше за цей інтервал
+    pub notification_deduplication_seconds: u64,
+    // критичні пороги сповіщень у градусах Цельсія; None - користувач їх не змінював, тоді беруться з точок спрацювання заліза
+    pub cpu_alert_threshold: Option<f32>,
+    pub gpu_alert_threshold: Option<f32>
+}
+
+const DEFAULT_NOTIFICATION_DEDUPLICATION_SECONDS: u64 = 60;
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            series_styles: SeriesStyles::default(),
+            chart_panels: SENSOR_KINDS.to_vec(),
+            sensor_preferences: SensorPreferences::default(),
+            notification_deduplication_seconds: DEFAULT_NOTIFICATION_DEDUPLICATION_SECONDS,
+            cpu_alert_threshold: None,
+            gpu_alert_threshold: None
+        }
+    }
+}
+
+pub fn get_default_settings_path() -> PathBuf {
+    let directory = if let Ok(directory) = std::env::var("XDG_CONFIG_HOME") {
+        PathBuf::from(directory)
+    } else if let Ok(directory) = std::env::var("APPDATA") {
+        PathBuf::from(directory)
+    } else {
+        match std::env::var("HOME") {
+            Ok(home) => PathBuf::from(home).join(".config"),
+            Err(_) => PathBuf::from(".")
+        }
+    };
+
+    directory.join("resource_monitor").join("settings.json")
+}
+
+impl Settings {
+    // відсутній файл - це звичайний перший запуск, а пошкоджений лише логується, щоб програма все одно відкрилася
+    pub fn load(path: &Path) -> Settings {
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(_) => return Settings::default()
+        };
+
+        let document: serde_json::Value = match serde_json::from_str(&data) {
+            Ok(document) => document,
+            Err(error) => {
+                log::warn!("не вдалося розібрати налаштування {}: {}", path.display(), error);
+
+                return Settings::default();
+            }
+        };
+
+        // старі файли налаштувань ще не мають панелей, тоді лишаються всі види за замовчуванням
+        let mut chart_panels = match document["chart_panels"].as_array() {
+            Some(panels) => {
+                let mut chart_panels = Vec::new();
+
+                for kind in panels.iter().filter_map(|panel| SensorKind::from_name(panel.as_str()?)) {
+                    if !chart_panels.contains(&kind) {
+                        chart_panels.push(kind);
+                    }
+                }
+
+                chart_panels
+            },
+            None => SENSOR_KINDS.to_vec()
+        };
+
+        // види, яких ще не було, коли файл збережено, додаються в кінець, а прибрані користувачем панелі не повертаються
+        let known_kinds: Vec<&str> = match document["known_sensor_kinds"].as_array() {
+            Some(kinds) => kinds.iter().filter_map(|kind| kind.as_str()).collect(),
+            None => Vec::new()
+        };
+
+        for kind in SENSOR_KINDS {
+            if !chart_panels.contains(&kind) && !known_kinds.contains(&kind.name()) {
+                chart_panels.push(kind);
+            }
+        }
+
+        Settings {
+            series_styles: SeriesStyles::from_json(&document["series_styles"]),
+            chart_panels,
+            sensor_preferences: SensorPreferences::from_json(&document["sensor_preferences"]),
+            notification_deduplication_seconds: document["notification_deduplication_seconds"].as_u64().unwrap_or(DEFAULT_NOTIFICATION_DEDUPLICATION_SECONDS),
+            cpu_alert_threshold: document["cpu_alert_threshold"].as_f64().map(|celsius| celsius as f32),
+            gpu_alert_threshold: document["gpu_alert_threshold"].as_f64().map(|celsius| celsius as f32)
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let document = serde_json::json!({
+            "series_styles": self.series_styles.to_json(),
+            "chart_panels": self.chart_panels.iter().map(|kind| kind.name()).collect::<Vec<&str>>(),
+            "known_sensor_kinds": SENSOR_KINDS.iter().map(|kind| kind.name()).collect::<Vec<&str>>(),
+            "sensor_preferences": self.sensor_preferences.to_json(),
+            "notification_deduplication_seconds": self.notification_deduplication_seconds,
+            "cpu_alert_threshold": self.cpu_alert_threshold,
+            "gpu_alert_threshold": self.gpu_alert_threshold
+        });
+
+        // пишу в тимчасовий файл і лише потім підміняю, щоб при падінні не залишився обрізаний файл
+        let temporary_path = path.with_extension("json.tmp");
+
+        fs::write(&temporary_path, serde_json::to_string_pretty(&document)?)?;
+
+        fs::rename(&temporary_path, path)
+    }
+}
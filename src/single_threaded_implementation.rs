@@ -1,210 +1,1990 @@
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
-use eframe::egui::{self, Event, Vec2};
+use eframe::egui::{self, Vec2};
 use egui_plot::{Legend, Line, PlotPoints};
-use crate::cpu_temperature::{get_cpu_current_celsius_temperature_using_wmi, get_cpu_name};
-use crate::gpu_temperature::{get_gpu_current_celsius_temperature, get_gpu_current_celsius_temperature_nvml, get_gpu_name_nvml};
+use crate::alerts::{AlertState, TemperatureAlert};
+use crate::cgroup::open_cgroup_monitor_if_requested;
+use crate::cli_arguments::CliArguments;
+use crate::desktop_notifications::{DesktopNotifier, NotificationUrgency};
+use crate::history::{History, HistorySeries};
+use crate::history_export::{export_history, ExportFormat, ExportMetadata};
+use crate::localization::{detect_language, tr, tr_with, Language, LANGUAGES};
+use crate::metrics_exporter::{start_metrics_server_if_requested, update_metrics, SharedMetrics};
+use crate::process_statistics::{ProcessMonitor, ProcessSortColumn};
+use crate::scheduler_statistics::SCHEDULER_DEVICE;
+use crate::sensor_inventory::format_latency;
+use crate::series_statistics::{format_duration, summarize_range, StatisticsSummary, StatisticsTable, ThrottlingTable};
+use crate::sensors::{find_sensor_value, initialize_com, SensorCollector, SensorKind, SENSOR_KINDS};
+use crate::series_styles::{to_step_points, SeriesChartType, SeriesStyle, SERIES_CHART_TYPES};
+use crate::session_recording::{format_recording_error, get_current_unix_timestamp, load_recording, Recording, SessionRecorder, RECORDING_EXTENSION};
+use crate::settings::{get_default_settings_path, Settings};
+use crate::thermal_trip_points::{get_default_alert_threshold, TripPoint, TripPointKind};
+use crate::tiered_storage::{get_default_storage_directory, AggregatedSeries, TieredStorage};
+use crate::units::{MeasurementUnit, TEMPERATURE_UNITS};
 
-use windows::{
-    Win32::System::Com::*,
-    Win32::System::Wmi::*,
-};
+const MAIN_WINDOW_SIZE: [f32; 2] = [1100.0, 700.0];
+const OVERLAY_WINDOW_SIZE: [f32; 2] = [260.0, 160.0];
+// скільки останніх значень показує мініграфік у міні-вікні
+const OVERLAY_SPARKLINE_LENGTH: usize = 120;
 
-use nvml_wrapper::Nvml;
+struct OverlaySeries {
+    device: String,
+    sensor: String,
+    kind: SensorKind,
+    is_shown: bool
+}
 
 struct PlotExample {
     delay_between_temperature_requests: u64,
     inner_timer: Option<Instant>,
     is_display_gpu_temperature: bool,
     is_display_cpu_temperature: bool,
-    gpu_temperature: Vec<f32>,
-    cpu_temperature: Vec<f32>,
-    cpu_name: String,
-    gpu_name: String,
+    history: History,
+    cpu_name: Option<String>,
+    gpu_name: Option<String>,
     amount_of_stored_data: u16,
-    wmi_server: IWbemServices,
-    nvml: Nvml,
-    delay_between_updates: u64
+    sensor_collector: SensorCollector,
+    delay_between_updates: u64,
+    is_notifications_enabled: bool,
+    cpu_temperature_alert: TemperatureAlert,
+    gpu_temperature_alert: TemperatureAlert,
+    desktop_notifier: DesktopNotifier,
+    metrics: Option<SharedMetrics>,
+    session_recorder: Option<SessionRecorder>,
+    recording_path: String,
+    recording_error: Option<String>,
+    opened_recording: Option<Recording>,
+    recording_position: f64,
+    recording_window: f64,
+    export_path: String,
+    export_format: ExportFormat,
+    export_status: Option<String>,
+    is_exported_on_exit: bool,
+    tiered_storage: Option<TieredStorage>,
+    // 0 - поточні дані з пам'яті, інакше період у секундах, що береться з довгострокового сховища
+    history_period_seconds: u64,
+    long_term_history: Vec<AggregatedSeries>,
+    long_term_history_timer: Option<Instant>,
+    paused_at: Option<f64>,
+    is_plot_reset_requested: bool,
+    hovered_timestamp: Option<f64>,
+    // точки спрацювання захисту від перегріву, малюються горизонтальними лініями на панелі температур
+    trip_points: Vec<TripPoint>,
+    is_display_trip_points: bool,
+    statistics: StatisticsTable,
+    // межі графіка на паузі (unix час), за них рахується статистика вікна
+    paused_time_range: Option<[f64; 2]>,
+    // статистика вікна на паузі для кожного елемента statistics.series, ключ - межі та час останнього відліку історії
+    paused_statistics: Option<([f64; 2], f64, Vec<Option<StatisticsSummary>>)>,
+    throttling: ThrottlingTable,
+    is_display_statistics: bool,
+    process_monitor: ProcessMonitor,
+    // процеси, завантаження яких додається лінією на панель навантаження
+    tracked_processes: Vec<u32>,
+    process_sort_column: ProcessSortColumn,
+    processes_count: usize,
+    is_display_processes: bool,
+    is_sensor_inventory_open: bool,
+    language: Language,
+    // усе зберігається в градусах Цельсія, а ця одиниця застосовується лише при показі та експорті
+    temperature_unit: MeasurementUnit,
+    is_overlay_mode: bool,
+    overlay_series: Vec<OverlaySeries>,
+    overlay_opacity: f32,
+    // міні-вікно, відкрите з бічної панелі окремим вікном поруч із головним
+    is_overlay_window_open: bool,
+    settings: Settings,
+    settings_path: PathBuf
 }
 
 impl Default for PlotExample {
     fn default() -> Self {
-        let locator: IWbemLocator;
-        let wmi_server;
+        let mut sensor_collector = SensorCollector::new();
 
-        unsafe {
-            // ініціалізую інтерфейс IWbemServices, він використовується для доступу до служб WMI
-            locator = CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER).unwrap();
-            wmi_server = locator.ConnectServer(&windows::core::BSTR::from("root\\cimv2"), None, None, None, 0, None, None).unwrap();
-        }
-            // ініціалізую nvml
-        let mut nvml = Nvml::init().unwrap();
+        let cpu_name = sensor_collector.get_cpu_name();
+
+        let gpu_name = sensor_collector.get_gpu_name();
+
+        let trip_points = sensor_collector.read_trip_points();
 
-        let cpu_name = get_cpu_name(&wmi_server);
+        let settings_path = get_default_settings_path();
 
-        let gpu_name = get_gpu_name_nvml(&mut nvml);
+        let settings = Settings::load(&settings_path);
+
+        // збережені пороги мають перевагу, а поки користувач їх не змінював, беру їх з точок спрацювання самого заліза;
+        // у межах повзунка порогу в налаштуваннях сповіщень
+        let cpu_critical_threshold = settings.cpu_alert_threshold.or(get_default_alert_threshold(&trip_points, "cpu")).unwrap_or(95.0).clamp(50.0, 110.0);
+        let gpu_critical_threshold = settings.gpu_alert_threshold.or(get_default_alert_threshold(&trip_points, "gpu")).unwrap_or(90.0).clamp(50.0, 110.0);
+
+        let mut desktop_notifier = DesktopNotifier::new();
+        desktop_notifier.deduplication_interval = Duration::from_secs(settings.notification_deduplication_seconds);
 
         Self {
             delay_between_temperature_requests: 500,
             inner_timer: None,
             is_display_gpu_temperature: true,
             is_display_cpu_temperature: true,
-            gpu_temperature: Vec::new(),
-            cpu_temperature: Vec::new(),
+            history: History::default(),
             cpu_name,
             gpu_name,
             amount_of_stored_data: 1200,
-            wmi_server,
-            nvml,
-            delay_between_updates: 16
+            sensor_collector,
+            delay_between_updates: 16,
+            is_notifications_enabled: true,
+            cpu_temperature_alert: TemperatureAlert::new("CPU", cpu_critical_threshold - 10.0, cpu_critical_threshold),
+            gpu_temperature_alert: TemperatureAlert::new("GPU", gpu_critical_threshold - 10.0, gpu_critical_threshold),
+            desktop_notifier,
+            metrics: None,
+            session_recorder: None,
+            recording_path: String::new(),
+            recording_error: None,
+            opened_recording: None,
+            recording_position: 0.0,
+            recording_window: 600.0,
+            export_path: String::new(),
+            export_format: ExportFormat::Csv,
+            export_status: None,
+            is_exported_on_exit: false,
+            tiered_storage: None,
+            history_period_seconds: 0,
+            long_term_history: Vec::new(),
+            long_term_history_timer: None,
+            paused_at: None,
+            is_plot_reset_requested: false,
+            hovered_timestamp: None,
+            trip_points,
+            is_display_trip_points: true,
+            statistics: StatisticsTable::default(),
+            paused_time_range: None,
+            paused_statistics: None,
+            throttling: ThrottlingTable::default(),
+            is_display_statistics: true,
+            process_monitor: ProcessMonitor::default(),
+            tracked_processes: Vec::new(),
+            process_sort_column: ProcessSortColumn::Cpu,
+            processes_count: 10,
+            is_display_processes: true,
+            is_sensor_inventory_open: false,
+            language: detect_language(),
+            temperature_unit: MeasurementUnit::Celsius,
+            is_overlay_mode: false,
+            overlay_series: Vec::new(),
+            overlay_opacity: 0.75,
+            is_overlay_window_open: false,
+            settings,
+            settings_path
         }
     }
 }
 
-impl eframe::App for PlotExample {
-    fn update(&mut self, ctx: &egui::Context, _: &mut eframe::Frame) {
-        egui::SidePanel::left("options").show(&ctx, |ui| {
-            ui.heading("Відображення");
-            ui.add_space(10.0);
+impl PlotExample {
+    fn check_temperature_alert(&mut self, temperature: f32, is_cpu: bool) {
+        let alert = if is_cpu {
+            &mut self.cpu_temperature_alert
+        } else {
+            &mut self.gpu_temperature_alert
+        };
 
-            ui.checkbox(&mut self.is_display_gpu_temperature, "Відображати температуру відеокарти");
-            ui.checkbox(&mut self.is_display_cpu_temperature, "Відображати температуру процесора");
-            ui.add_space(10.0);
+        // warning-поріг тримаю на 10 градусів нижче критичного, щоб у панелі був лише один слайдер
+        alert.warning_threshold = alert.critical_threshold - 10.0;
 
-            ui.add(egui::Separator::default());
-            ui.add_space(10.0);
+        if alert.update(temperature) == Some(AlertState::Critical) && self.is_notifications_enabled {
+            let summary = tr_with(self.language, "notifications.critical_summary", &[("name", &alert.name)]);
+            let body = tr_with(self.language, "notifications.critical_body", &[
+                ("value", &format!("{:.*}", 1, SensorKind::Temperature.to_display_value(temperature, self.temperature_unit))),
+                ("threshold", &format!("{:.*}", 0, SensorKind::Temperature.to_display_value(alert.critical_threshold, self.temperature_unit))),
+                ("unit", self.temperature_unit.symbol())
+            ]);
+            let key = alert.name.clone();
 
-            ui.heading("Оптимізація");
-            ui.add_space(10.0);
+            self.desktop_notifier.notify(&key, &summary, &body);
+        }
+    }
 
-            ui.collapsing("Детальніше про оптимізацію", |ui| {
-                ui.label(
-                    "Ця вкладка надає можливість самостійно оптимізувати програму."
-                );
-                ui.add_space(5.0);
-                ui.label("Наприклад:");
-                ui.label("- при використанні програми в фоновому режимі, щоб заощадити ресурси системи, програму можна попередньо налавштувати, збільшивши затримку між оновленням рендеру, та збільшивши затримку між запитами на отримання температури, та після цього згорнути програму.");
-            });
-            ui.add_space(10.0);
+    fn save_settings(&self) {
+        match self.settings.save(&self.settings_path) {
+            Ok(_) => {},
+            Err(error) => log::warn!("не вдалося зберегти налаштування {}: {}", self.settings_path.display(), error)
+        }
+    }
 
-            ui.label("Затримка між запитами на отримання температури (ms)").on_hover_text("регулюємо частуту отримання данних про температуру, чим більше значення, тим більша затримка до отримання данних.");
-            ui.add(egui::Slider::new(&mut self.delay_between_temperature_requests, 0..=3000)).on_hover_text("регулюємо частуту отримання данних про температуру, чим більше значення, тим більша затримка до отримання данних.");
-            ui.add_space(10.0);
+    // кожен новий сенсор (наживо, у довгостроковій історії чи в записі) отримує наступний колір палітри,
+    // а зберігаються налаштування лише тоді, коли користувач змінює стиль
+    fn assign_series_styles(&mut self) {
+        let series_styles = &mut self.settings.series_styles;
 
-            ui.label("Затримка між викликами рендеру (ms)").on_hover_text("регулюємо затримку між оновленнями рендеру кожного кадру, простіше кажучи - дозволяє збільшувати, або зменшувати обмеження fps. Чим більше значення тим більша затримка, та нижчий fps, та відповідно меньше навантаження на систему.");
-            ui.add(egui::Slider::new(&mut self.delay_between_updates, 1..=100)).on_hover_text("регулюємо затримку між оновленнями рендеру кожного кадру, простіше кажучи - дозволяє збільшувати, або зменшувати обмеження fps. Чим більше значення тим більша затримка, та нижчий fps, та відповідно меньше навантаження на систему.");
-            ui.add_space(10.0);
+        for series in &self.history.series {
+            series_styles.assign_if_missing(&series.device, &series.sensor, series.kind);
+        }
+
+        for series in &self.long_term_history {
+            series_styles.assign_if_missing(&series.device, &series.sensor, series.kind);
+        }
+
+        if let Some(recording) = &self.opened_recording {
+            for series in &recording.series {
+                series_styles.assign_if_missing(&series.device, &series.sensor, series.kind);
+            }
+        }
+    }
+
+    fn start_session_recording(&mut self) {
+        if self.recording_path.trim().is_empty() {
+            self.recording_path = format!("resource_monitor_{}.{}", get_current_unix_timestamp() as u64, RECORDING_EXTENSION);
+        }
+
+        match SessionRecorder::create(&PathBuf::from(self.recording_path.trim())) {
+            Ok(recorder) => {
+                self.session_recorder = Some(recorder);
+                self.recording_error = None;
+            },
+            Err(error) => {
+                self.recording_error = Some(tr_with(self.language, "recording.create_error", &[("error", &error.to_string())]));
+            }
+        }
+    }
+
+    pub fn open_recording(&mut self, path: &str) {
+        match load_recording(&PathBuf::from(path.trim())) {
+            Ok(recording) => {
+                self.recording_position = 0.0;
+                self.recording_window = f64::min(600.0, recording.end_timestamp - recording.start_timestamp).max(1.0);
+                self.opened_recording = Some(recording);
+                self.recording_error = None;
+
+                self.assign_series_styles();
+            },
+            Err(error) => {
+                self.recording_error = Some(tr_with(self.language, "recording.open_error", &[("error", &format_recording_error(&error, self.language))]));
+            }
+        }
+    }
+
+    fn export_current_history(&mut self) {
+        if self.export_path.trim().is_empty() {
+            self.export_path = format!("resource_monitor_{}.{}", get_current_unix_timestamp() as u64, self.export_format.extension());
+        }
+
+        // якщо відкритий запис, експортую саме його, бо на екрані зараз він
+        let series = match &self.opened_recording {
+            Some(recording) => recording.series.clone(),
+            None => self.history.to_recorded_series()
+        };
+
+        let metadata = ExportMetadata::new(self.delay_between_temperature_requests, self.temperature_unit);
+
+        self.export_status = match export_history(&PathBuf::from(self.export_path.trim()), self.export_format, &series, &metadata) {
+            Ok(_) => Some(tr_with(self.language, "export.saved", &[("path", self.export_path.trim())])),
+            Err(error) => Some(tr_with(self.language, "export.error", &[("error", &error.to_string())]))
+        };
+    }
 
-            ui.label("Кількість відображених даних про температуру").on_hover_text("регулюємо кількість елментів графіка відображених на екрані. Після накопичення вказаного значення найстаріші значеня починають по одному видалятися, як тільки надходять нові данні. Чим більше значення, тим більша кількість елментів буде збережена, та відобоажена на екрані (за певний проміжок часу).");
-            ui.add(egui::Slider::new(&mut self.amount_of_stored_data, 10..=1200)).on_hover_text("регулюємо кількість елментів графіка відображених на екрані. Після накопичення вказаного значення найстаріші значеня починають по одному видалятися, як тільки надходять нові данні. Чим більше значення, тим більша кількість елментів буде збережена, та відобоажена на екрані (за певний проміжок часу).");
-            
+    fn show_live_plot(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let temperature_unit = self.temperature_unit;
+
+        let latest_timestamp = match self.history.timestamps.last() {
+            Some(timestamp) => *timestamp,
+            None => get_current_unix_timestamp()
+        };
+
+        // на паузі точка відліку не рухається, тому графік можна спокійно масштабувати та зсувати
+        let reference_timestamp = self.paused_at.unwrap_or(latest_timestamp);
+        let is_paused = self.paused_at.is_some();
+
+        ui.horizontal(|ui| {
+            if is_paused {
+                if ui.button(tr(language, "plot.follow_live")).clicked() {
+                    self.paused_at = None;
+                    self.is_plot_reset_requested = true;
+                }
+
+                ui.label(tr(language, "plot.paused_hint"));
+            } else if ui.button(tr(language, "plot.pause")).clicked() {
+                self.paused_at = Some(latest_timestamp);
+            }
         });
-        egui::CentralPanel::default().show(&ctx, |ui| {
-            ui.heading("Графік температур процесора та відеокарти");
-            ui.add_space(10.0);
+        ui.add_space(10.0);
 
-            ui.label(format!("Процесор: {}", &self.cpu_name));
-            ui.add_space(10.0);
+        // панелі без жодного сенсора (наприклад, потужність без nvml) не показуються, щоб не займати місце
+        let mut panels: Vec<(SensorKind, Vec<LiveSeries>)> = self.settings.chart_panels
+            .iter()
+            .map(|kind| (*kind, get_live_series(&self.history, &self.settings, *kind, self.is_display_cpu_temperature, self.is_display_gpu_temperature)))
+            .filter(|(_, series)| !series.is_empty())
+            .collect();
 
-            ui.label(format!("Відеокарта: {}", &self.gpu_name));
-            ui.add_space(10.0);
+        if panels.is_empty() {
+            match self.settings.chart_panels.first() {
+                Some(kind) => panels.push((*kind, Vec::new())),
+                None => {
+                    ui.label(tr(language, "panels.empty"));
+
+                    return;
+                }
+            }
+        }
+
+        let value_row_height = ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+        let panel_height = ((ui.available_height() - value_row_height) / panels.len() as f32 - ui.spacing().item_spacing.y).max(80.0);
+
+        let is_plot_reset_requested = self.is_plot_reset_requested;
+        self.is_plot_reset_requested = false;
+
+        // курсор малюю сам за часом з попереднього кадру, бо він має прилипати до найближчого відліку в усіх панелях
+        let shown_hovered_timestamp = self.hovered_timestamp;
+        let mut hovered_index = None;
+
+        // проміжки тротлінгу процесора та відеокарти затіняються на панелі температур, щоб було видно, чим він викликаний
+        let mut throttling_intervals = Vec::new();
+
+        for (device, is_displayed) in [("gpu", self.is_display_gpu_temperature), ("cpu", self.is_display_cpu_temperature)] {
+            if !is_displayed {
+                continue;
+            }
+
+            for series in self.history.series.iter().filter(|series| series.device == device && series.kind == SensorKind::Throttling) {
+                throttling_intervals.extend(self.history.active_intervals(series, reference_timestamp));
+            }
+        }
+
+        let throttling_name = tr(language, "throttling.legend");
+
+        // підключення та відключення зарядки позначаються на панелі температур, бо від них залежать ліміти потужності ноутбука
+        let ac_changes: Vec<(f64, bool)> = match self.history.find_series("ac", SensorKind::AcOnline) {
+            Some(series) => self.history.flag_changes(series, reference_timestamp),
+            None => Vec::new()
+        };
+
+        // точки спрацювання для кожної показаної температури, однакові точки однієї лінії малюю лише раз;
+        // точки всього пристрою (теплової зони) підписую пристроєм, щоб вони не повторювалися для кожного його сенсора
+        let mut trip_lines: Vec<(String, TripPointKind, f32)> = Vec::new();
+
+        if self.is_display_trip_points {
+            for (kind, panel_series) in &panels {
+                if *kind != SensorKind::Temperature {
+                    continue;
+                }
+
+                for (name, series, _) in panel_series {
+                    for trip_point in self.trip_points.iter().filter(|trip_point| trip_point.applies_to(&series.device, &series.sensor)) {
+                        let name = match trip_point.sensor {
+                            Some(_) => name,
+                            None => &trip_point.device
+                        };
+
+                        let is_known = trip_lines
+                            .iter()
+                            .any(|(line_name, kind, celsius)| line_name == name && *kind == trip_point.kind && *celsius == trip_point.celsius);
+
+                        if !is_known {
+                            trip_lines.push((name.clone(), trip_point.kind, trip_point.celsius));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (panel_index, (kind, panel_series)) in panels.iter().enumerate() {
+            let unit = kind.display_unit(temperature_unit);
+            let is_last_panel = panel_index + 1 == panels.len();
 
-            egui_plot::Plot::new("resource_monitor")
-                .allow_zoom(false)
-                .allow_drag(false)
-                .allow_scroll(false)
-                .allow_boxed_zoom(false)
-                .show_axes(egui::Vec2b{x: false, y: true})
+            let mut plot = egui_plot::Plot::new(format!("resource_monitor_{}", kind.name()))
+                .height(panel_height)
+                .link_axis("resource_monitor_live", true, false)
+                .allow_zoom(egui::Vec2b{x: is_paused, y: false})
+                .allow_drag(egui::Vec2b{x: is_paused, y: false})
+                .allow_scroll(is_paused)
+                .allow_boxed_zoom(is_paused)
+                .allow_double_click_reset(false)
+                .show_axes(egui::Vec2b{x: is_paused && is_last_panel, y: true})
                 .show_grid(egui::Vec2b{x: false, y: true})
+                .x_axis_formatter(|value, _, _| format!("{:.*} s", 0, value))
+                .y_axis_formatter(move |value, _, _| unit.format_value(value, 0))
                 .legend(Legend::default()
                     .background_alpha(1.0)
                     .position(egui_plot::Corner::RightBottom)
                 )
-                .label_formatter(|name, value| {
+                .label_formatter(move |name, value| {
                     if !name.is_empty() {
-                        format!("{}: {:.*}°C", name, 1, value.y)
+                        format!("{}: {}", name, unit.format_value(value.y, 1))
                     } else {
                         "".to_owned()
                     }
-                })
-                .show(ui, |plot_ui| {
-                    if self.gpu_temperature.len() < 2 {
-                        plot_ui.line(Line::new(PlotPoints::default()).name("GPU").width(5.0));
+                });
 
-                        plot_ui.line(Line::new(PlotPoints::default()).name("CPU").width(5.0));
-                    } else {
-                        if self.is_display_gpu_temperature {
-                            plot_ui.line(Line::new(PlotPoints::from_ys_f32(&self.gpu_temperature[..])).name("GPU").width(5.0).color(egui::Color32::GREEN));
+            if is_plot_reset_requested {
+                plot = plot.reset();
+            }
+
+            let plot_response = plot.show(ui, |plot_ui| {
+                if self.history.timestamps.len() < 2 {
+                    plot_ui.line(Line::new(PlotPoints::default()).name(tr(language, get_kind_key(*kind))));
+                } else {
+                    let mut value_range: Option<[f64; 2]> = None;
+
+                    for (name, series, style) in panel_series {
+                        let points: Vec<[f64; 2]> = self.history
+                            .series_points(series, reference_timestamp)
+                            .into_iter()
+                            .map(|point| [point[0], series.kind.to_display_value(point[1] as f32, temperature_unit) as f64])
+                            .collect();
+
+                        for point in &points {
+                            value_range = match value_range {
+                                Some([min, max]) => Some([min.min(point[1]), max.max(point[1])]),
+                                None => Some([point[1], point[1]])
+                            };
                         }
 
-                        if self.is_display_cpu_temperature {
-                            plot_ui.line(Line::new(PlotPoints::from_ys_f32(&self.cpu_temperature[..])).name("CPU").width(5.0).color(egui::Color32::RED));
+                        plot_ui.line(get_styled_line(points, style).name(name));
+                    }
+
+                    // висота затінення береться з даних, а не з меж графіка, інакше межі вже ніколи не зменшаться
+                    if let (SensorKind::Temperature, Some([min, max])) = (*kind, value_range) {
+                        let throttling_color = egui::Color32::from_rgba_unmultiplied(220, 60, 60, 40);
+
+                        for [start, end] in &throttling_intervals {
+                            plot_ui.polygon(egui_plot::Polygon::new(PlotPoints::new(vec![[*start, min], [*end, min], [*end, max], [*start, max]]))
+                                .name(throttling_name)
+                                .fill_color(throttling_color)
+                                .stroke(egui::Stroke::new(0.0, throttling_color))
+                            );
                         }
                     }
-                });
+                }
+
+                if *kind == SensorKind::Temperature {
+                    for (x, is_ac_online) in &ac_changes {
+                        let (name, color) = if *is_ac_online {
+                            (tr(language, "power_supply.plugged"), egui::Color32::from_rgb(0, 158, 115))
+                        } else {
+                            (tr(language, "power_supply.unplugged"), egui::Color32::from_rgb(230, 159, 0))
+                        };
+
+                        plot_ui.vline(egui_plot::VLine::new(*x)
+                            .name(name)
+                            .color(color)
+                            .width(1.5)
+                            .style(egui_plot::LineStyle::Dashed{length: 6.0})
+                        );
+                    }
+
+                    for (name, trip_kind, celsius) in &trip_lines {
+                        let value = SensorKind::Temperature.to_display_value(*celsius, temperature_unit);
+
+                        let color = if trip_kind.is_critical() {
+                            egui::Color32::from_rgb(213, 94, 0)
+                        } else {
+                            egui::Color32::from_rgb(230, 159, 0)
+                        };
+
+                        plot_ui.hline(egui_plot::HLine::new(value)
+                            .name(format!("{} {} {}", name, tr(language, get_trip_point_key(*trip_kind)), unit.format_value(value as f64, 0)))
+                            .color(color)
+                            .width(1.0)
+                            .style(egui_plot::LineStyle::Dotted{spacing: 6.0})
+                        );
+                    }
+                }
+
+                if let Some(timestamp) = shown_hovered_timestamp {
+                    plot_ui.vline(egui_plot::VLine::new(timestamp - reference_timestamp).color(egui::Color32::GRAY).width(1.0));
+                }
+
+                if let Some(pointer) = plot_ui.pointer_coordinate() {
+                    if let Some(index) = self.history.find_nearest_index(pointer.x + reference_timestamp) {
+                        hovered_index = Some(index);
+                    }
+                }
+            });
+
+            // осі x усіх панелей зв'язані, тому межі першої - це межі всього графіка
+            if panel_index == 0 {
+                self.paused_time_range = if is_paused {
+                    let bounds = plot_response.transform.bounds();
+
+                    Some([bounds.min()[0] + reference_timestamp, bounds.max()[0] + reference_timestamp])
+                } else {
+                    None
+                };
+            }
+
+            if plot_response.response.double_clicked() && is_paused {
+                self.paused_at = None;
+                self.is_plot_reset_requested = true;
+            }
+
+            // перетягування графіка наживо саме ставить його на паузу, щоб не доводилося спершу шукати кнопку
+            if plot_response.response.dragged() && !is_paused {
+                self.paused_at = Some(latest_timestamp);
+            }
+        }
+
+        self.hovered_timestamp = hovered_index.map(|index| self.history.timestamps[index]);
+
+        ui.horizontal_wrapped(|ui| {
+            match hovered_index {
+                Some(index) => {
+                    ui.label(format!("{:.*} s:", 1, self.history.timestamps[index] - reference_timestamp));
+
+                    for (kind, panel_series) in &panels {
+                        let unit = kind.display_unit(temperature_unit);
+
+                        for (name, series, style) in panel_series {
+                            let value = series.values[index];
+                            let color = get_style_color(style);
+
+                            if value.is_nan() {
+                                ui.colored_label(color, format!("{}: —", name));
+                            } else {
+                                ui.colored_label(color, format!("{}: {}", name, unit.format_value(series.kind.to_display_value(value, temperature_unit) as f64, 1)));
+                            }
+                        }
+                    }
+                },
+                None => {
+                    ui.label("");
+                }
+            }
         });
+    }
 
-        if self.inner_timer == None {
-            self.inner_timer = Some(Instant::now());
-        } else if self.inner_timer.unwrap().elapsed() >= Duration::from_millis(self.delay_between_temperature_requests) {
-            let mut gpu_temperature_inner = 0.0;
-            let mut cpu_temperature_inner = 0.0;
+    // наживо графік показує всі збережені значення, і статистика вікна рахується поступово в StatisticsTable,
+    // а на паузі графік можна масштабувати та зсувати, тож статистику доводиться рахувати за його межами
+    fn update_paused_statistics(&mut self) {
+        let time_range = match self.paused_time_range {
+            Some(time_range) => time_range,
+            None => {
+                self.paused_statistics = None;
+
+                return;
+            }
+        };
+
+        let latest_timestamp = self.history.timestamps.last().copied().unwrap_or(0.0);
+
+        if let Some((cached_range, cached_timestamp, cached_summaries)) = &self.paused_statistics {
+            if *cached_range == time_range && *cached_timestamp == latest_timestamp && cached_summaries.len() == self.statistics.series.len() {
+                return;
+            }
+        }
+
+        let summaries = self.statistics.series
+            .iter()
+            .map(|series| {
+                let history_series = self.history.series
+                    .iter()
+                    .find(|history_series| history_series.device == series.device && history_series.sensor == series.sensor && history_series.kind == series.kind)?;
 
-            if self.is_display_gpu_temperature {
-                gpu_temperature_inner = get_gpu_current_celsius_temperature_nvml(&mut self.nvml);
+                let threshold = match (series.device.as_str(), series.kind) {
+                    ("cpu", SensorKind::Temperature) => Some(self.cpu_temperature_alert.critical_threshold),
+                    ("gpu", SensorKind::Temperature) => Some(self.gpu_temperature_alert.critical_threshold),
+                    _ => None
+                };
+
+                summarize_range(&self.history.timestamps, &history_series.values, time_range, threshold)
+            })
+            .collect();
+
+        self.paused_statistics = Some((time_range, latest_timestamp, summaries));
+    }
+
+    fn show_statistics(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+
+        self.update_paused_statistics();
+
+        ui.heading(tr(language, "statistics.heading"));
+        ui.add_space(10.0);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (title, hover_text, is_session) in [
+                (tr(language, "statistics.window"), tr(language, "statistics.window_hint"), false),
+                (tr(language, "statistics.session"), tr(language, "statistics.session_hint"), true)
+            ] {
+                ui.label(egui::RichText::new(title).strong()).on_hover_text(hover_text);
+                ui.add_space(5.0);
+
+                egui::Grid::new(format!("statistics_{}", title))
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in ["statistics.sensor", "statistics.current", "statistics.min", "statistics.max", "statistics.mean", "statistics.p95", "statistics.above_threshold"] {
+                            ui.label(egui::RichText::new(tr(language, header)).small());
+                        }
+                        ui.end_row();
+
+                        for (series_index, series) in self.statistics.series.iter().enumerate() {
+                            let summary = match (is_session, &self.paused_statistics) {
+                                (true, _) => series.session.summary(),
+                                (false, Some((_, _, summaries))) => summaries[series_index],
+                                (false, None) => series.window.summary()
+                            };
+
+                            let summary: StatisticsSummary = match summary {
+                                Some(summary) => summary,
+                                None => continue
+                            };
+
+                            ui.label(format!("{} / {}", series.device, series.sensor));
+
+                            let unit = series.kind.display_unit(self.temperature_unit);
+
+                            for value in [summary.current, summary.min, summary.max, summary.mean, summary.p95] {
+                                ui.label(unit.format_value(series.kind.to_display_value(value, self.temperature_unit) as f64, 1));
+                            }
+
+                            ui.label(format_duration(summary.seconds_above_threshold));
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(10.0);
             }
 
-            if self.is_display_cpu_temperature {
-                cpu_temperature_inner = get_cpu_current_celsius_temperature_using_wmi(&self.wmi_server)[0]
+            self.show_power_supplies(ui);
+
+            if !self.throttling.entries.is_empty() {
+                ui.label(egui::RichText::new(tr(language, "throttling.heading")).strong()).on_hover_text(tr(language, "throttling.hint"));
+                ui.add_space(5.0);
+
+                egui::Grid::new("throttling_summary")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in ["statistics.sensor", "throttling.intervals", "throttling.time", "throttling.now"] {
+                            ui.label(egui::RichText::new(tr(language, header)).small());
+                        }
+                        ui.end_row();
+
+                        for entry in &self.throttling.entries {
+                            ui.label(format!("{} / {}", entry.device, entry.sensor));
+                            ui.label(entry.intervals.to_string());
+                            ui.label(format_duration(entry.seconds));
+
+                            if entry.is_throttling {
+                                ui.colored_label(egui::Color32::from_rgb(220, 60, 60), tr(language, "throttling.yes"));
+                            } else {
+                                ui.label(tr(language, "throttling.no"));
+                            }
+                            ui.end_row();
+                        }
+                    });
             }
+        });
+    }
+
+    // компактний рядок над графіком, сенсори планувальника є лише на Linux
+    fn show_scheduler_summary(&self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let latest = |sensor: &str, kind: SensorKind| self.history.latest_value(SCHEDULER_DEVICE, sensor, kind);
+        let format_latest = |value: Option<f32>, unit: MeasurementUnit, precision: usize| match value {
+            Some(value) => unit.format_value(value as f64, precision),
+            None => "-".to_string()
+        };
+
+        let mut result_arr = Vec::new();
 
-            self.gpu_temperature.push(gpu_temperature_inner);
+        let load_averages = [
+            latest("load 1m", SensorKind::LoadAverage),
+            latest("load 5m", SensorKind::LoadAverage),
+            latest("load 15m", SensorKind::LoadAverage)
+        ];
 
-            if self.gpu_temperature.len() > self.amount_of_stored_data as usize {
-                self.gpu_temperature.remove(0);
+        if load_averages.iter().any(|value| value.is_some()) {
+            result_arr.push(tr_with(language, "scheduler.load_average", &[
+                ("one", &format_latest(load_averages[0], MeasurementUnit::Number, 2)),
+                ("five", &format_latest(load_averages[1], MeasurementUnit::Number, 2)),
+                ("fifteen", &format_latest(load_averages[2], MeasurementUnit::Number, 2))
+            ]));
+        }
+
+        for (sensor, key) in [
+            ("context switches", "scheduler.context_switches"),
+            ("interrupts", "scheduler.interrupts"),
+            ("forks", "scheduler.forks")
+        ] {
+            if let Some(value) = latest(sensor, SensorKind::SchedulerEvents) {
+                result_arr.push(tr_with(language, key, &[("value", &format_latest(Some(value), MeasurementUnit::PerSecond, 0))]));
             }
+        }
+
+        let running = latest("running", SensorKind::ProcessCount);
+        let blocked = latest("blocked", SensorKind::ProcessCount);
 
-            self.cpu_temperature.push(cpu_temperature_inner);
+        if running.is_some() || blocked.is_some() {
+            result_arr.push(tr_with(language, "scheduler.processes", &[
+                ("running", &format_latest(running, MeasurementUnit::Number, 0)),
+                ("blocked", &format_latest(blocked, MeasurementUnit::Number, 0))
+            ]));
+        }
+
+        if result_arr.is_empty() {
+            return;
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            for (index, text) in result_arr.iter().enumerate() {
+                if index > 0 {
+                    ui.separator();
+                }
 
-            if self.cpu_temperature.len() > self.amount_of_stored_data as usize {
-                self.cpu_temperature.remove(0);
+                ui.label(egui::RichText::new(text).monospace());
             }
+        });
+        ui.add_space(5.0);
+    }
 
-            self.inner_timer = None;
+    fn show_processes(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr(language, "processes.heading"));
+            ui.add(egui::Slider::new(&mut self.processes_count, 5..=50).text(tr(language, "processes.count")));
+        });
+        ui.label(egui::RichText::new(tr(language, "processes.hint")).small());
+        ui.add_space(5.0);
+
+        if self.process_monitor.processes.is_empty() {
+            ui.label(tr(language, "processes.unavailable"));
+
+            return;
         }
 
-        std::thread::sleep(Duration::from_millis(self.delay_between_updates)); // обмежую "fps" програми, щоб заощадити ресурси комп'ютера
+        let mut toggled_pid = None;
 
-        ctx.request_repaint();
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("top_processes")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new(tr(language, "processes.pid")).small());
+                    ui.label(egui::RichText::new(tr(language, "processes.name")).small());
+
+                    // клік по заголовку стовпця сортує за ним, завжди за спаданням
+                    for (column, key) in [(ProcessSortColumn::Cpu, "processes.cpu"), (ProcessSortColumn::Memory, "processes.memory")] {
+                        let is_selected = self.process_sort_column == column;
+
+                        let text = if is_selected {
+                            format!("{} ⏷", tr(language, key))
+                        } else {
+                            tr(language, key).to_string()
+                        };
+
+                        if ui.selectable_label(is_selected, egui::RichText::new(text).small()).clicked() {
+                            self.process_sort_column = column;
+                        }
+                    }
+                    ui.end_row();
+
+                    for process in self.process_monitor.get_top_processes(self.process_sort_column, self.processes_count) {
+                        let is_tracked = self.tracked_processes.contains(&process.pid);
+
+                        ui.label(process.pid.to_string());
+
+                        if ui.selectable_label(is_tracked, &process.name).on_hover_text(tr(language, "processes.track_hint")).clicked() {
+                            toggled_pid = Some(process.pid);
+                        }
+
+                        ui.label(MeasurementUnit::Percent.format_value(process.cpu_percent as f64, 1));
+                        ui.label(MeasurementUnit::Byte.format_value(process.rss_bytes as f64, 1));
+                        ui.end_row();
+                    }
+                });
+        });
+
+        if let Some(pid) = toggled_pid {
+            match self.tracked_processes.iter().position(|tracked_pid| *tracked_pid == pid) {
+                Some(index) => {
+                    self.tracked_processes.remove(index);
+                },
+                None => self.tracked_processes.push(pid)
+            }
+        }
     }
-}
 
-pub fn run_single_threaded_implementation() -> Result<(), eframe::Error> {
-    //env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
-    unsafe {
-        CoInitializeEx(None, COINIT_APARTMENTTHREADED).unwrap();
-    
-        CoInitializeSecurity(
-            None,
-            -1,
-            None,
-            None,
-            RPC_C_AUTHN_LEVEL_DEFAULT,
-            RPC_C_IMP_LEVEL_IMPERSONATE,
-            None,
-            EOAC_NONE,
-            None,
-        ).unwrap();
+    fn show_power_supplies(&self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let power_supplies = &self.sensor_collector.power_supplies;
+
+        if power_supplies.batteries.is_empty() && power_supplies.is_ac_online.is_none() {
+            return;
+        }
+
+        ui.label(egui::RichText::new(tr(language, "power_supply.heading")).strong());
+        ui.add_space(5.0);
+
+        if let Some(is_ac_online) = power_supplies.is_ac_online {
+            ui.label(tr(language, if is_ac_online { "power_supply.ac_online" } else { "power_supply.ac_offline" }));
+        }
+
+        egui::Grid::new("power_supplies")
+            .striped(true)
+            .show(ui, |ui| {
+                for header in ["power_supply.battery", "power_supply.status", "power_supply.charge", "power_supply.rate", "power_supply.cycles"] {
+                    ui.label(egui::RichText::new(tr(language, header)).small());
+                }
+                ui.end_row();
+
+                for battery in &power_supplies.batteries {
+                    let status_key = match battery.status.as_str() {
+                        "Charging" => "power_supply.charging",
+                        "Discharging" => "power_supply.discharging",
+                        "Full" => "power_supply.full",
+                        "Not charging" => "power_supply.not_charging",
+                        _ => "power_supply.unknown"
+                    };
+
+                    ui.label(&battery.name);
+                    ui.label(tr(language, status_key));
+                    ui.label(match battery.capacity_percent {
+                        Some(capacity) => MeasurementUnit::Percent.format_value(capacity as f64, 0),
+                        None => "—".to_string()
+                    });
+                    ui.label(match battery.power_watts {
+                        Some(watts) => MeasurementUnit::Watt.format_value(watts as f64, 1),
+                        None => "—".to_string()
+                    });
+                    ui.label(match battery.cycle_count {
+                        Some(cycle_count) => cycle_count.to_string(),
+                        None => "—".to_string()
+                    });
+                    ui.end_row();
+                }
+            });
+        ui.add_space(10.0);
+    }
+
+    fn show_long_term_history(&mut self, ui: &mut egui::Ui) {
+        let is_outdated = match self.long_term_history_timer {
+            Some(timer) => timer.elapsed() >= Duration::from_secs(10),
+            None => true
+        };
+
+        // файли рівнів читаються не кожен кадр, а раз на 10 секунд (найдрібніший кошик все одно 10 секунд)
+        if is_outdated {
+            if let Some(tiered_storage) = &self.tiered_storage {
+                self.long_term_history = match tiered_storage.query(self.history_period_seconds) {
+                    Ok(history) => history,
+                    Err(error) => {
+                        log::warn!("не вдалося прочитати довгострокову історію: {}", error);
+
+                        Vec::new()
+                    }
+                };
+            }
+
+            self.long_term_history_timer = Some(Instant::now());
+
+            self.assign_series_styles();
+        }
+
+        let now = get_current_unix_timestamp();
+
+        let temperature_unit = self.temperature_unit;
+
+        let mut lines = Vec::new();
+
+        for series in &self.long_term_history {
+            if (series.device == "cpu" && !self.is_display_cpu_temperature) || (series.device == "gpu" && !self.is_display_gpu_temperature) {
+                continue;
+            }
+
+            if self.settings.sensor_preferences.is_hidden(&series.device, &series.sensor, series.kind) {
+                continue;
+            }
+
+            // вісь x - скільки часу тому (від'ємні значення), лінія - середнє, а тонка лінія - максимум кошика
+            let name = get_series_name(&self.settings, &series.device, &series.sensor, series.kind, format!("{} / {}", series.device, series.sensor));
+
+            let average: Vec<[f64; 2]> = series.points.iter().map(|point| [point.timestamp - now, series.kind.to_display_value(point.average, temperature_unit) as f64]).collect();
+            let maximum: Vec<[f64; 2]> = series.points.iter().map(|point| [point.timestamp - now, series.kind.to_display_value(point.max, temperature_unit) as f64]).collect();
+
+            let style = self.settings.series_styles.get(&series.device, &series.sensor, series.kind);
+
+            lines.push(PanelLine {
+                name: format!("{} max", name),
+                kind: series.kind,
+                style: SeriesStyle {
+                    width: 1.0,
+                    chart_type: SeriesChartType::Line,
+                    is_dashed: false,
+                    ..style
+                },
+                points: maximum
+            });
+
+            lines.push(PanelLine {
+                name,
+                kind: series.kind,
+                style,
+                points: average
+            });
+        }
+
+        show_static_panels(ui, "long_term_history", &self.settings.chart_panels, &lines, temperature_unit, 3600.0, "h");
     }
 
-    let mut options = eframe::NativeOptions::default();
+    fn show_opened_recording(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let temperature_unit = self.temperature_unit;
 
-    options.centered = true;
-    //options.viewport.window_level = Some(egui::viewport::WindowLevel::AlwaysOnTop);
+        let mut is_closed = false;
+
+        if let Some(recording) = &self.opened_recording {
+            let duration = (recording.end_timestamp - recording.start_timestamp).max(1.0);
+
+            ui.heading(tr_with(language, "recording.title", &[("path", &self.recording_path)]));
+            ui.add_space(10.0);
+
+            if ui.button(tr(language, "recording.back_to_live")).clicked() {
+                is_closed = true;
+            }
+            ui.add_space(10.0);
+
+            ui.label(tr(language, "recording.position"));
+            ui.add(egui::Slider::new(&mut self.recording_position, 0.0..=duration));
+
+            ui.label(tr(language, "recording.window"));
+            ui.add(egui::Slider::new(&mut self.recording_window, 1.0..=duration).logarithmic(true));
+            ui.add_space(10.0);
+
+            let window_start = recording.start_timestamp + self.recording_position;
+            let window_end = window_start + self.recording_window;
+
+            // вісь x - секунди від початку запису
+            let lines: Vec<PanelLine> = recording.series
+                .iter()
+                .filter(|series| !self.settings.sensor_preferences.is_hidden(&series.device, &series.sensor, series.kind))
+                .map(|series| PanelLine {
+                    name: get_series_name(&self.settings, &series.device, &series.sensor, series.kind, format!("{} / {}", series.device, series.sensor)),
+                    kind: series.kind,
+                    style: self.settings.series_styles.get(&series.device, &series.sensor, series.kind),
+                    points: series.points
+                        .iter()
+                        .filter(|point| point[0] >= window_start && point[0] <= window_end)
+                        .map(|point| [point[0] - recording.start_timestamp, series.kind.to_display_value(point[1] as f32, temperature_unit) as f64])
+                        .collect()
+                })
+                .collect();
+
+            show_static_panels(ui, "recording", &self.settings.chart_panels, &lines, temperature_unit, 1.0, "s");
+        }
+
+        if is_closed {
+            self.opened_recording = None;
+        }
+    }
+
+    // з --overlay міні-вікном стає саме головне вікно, тож повернення з нього відновлює рамку, рівень і розмір
+    fn leave_overlay_mode(&mut self, ctx: &egui::Context) {
+        self.is_overlay_mode = false;
+
+        ctx.send_viewport_cmd(egui::ViewportCommand::Decorations(true));
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(egui::WindowLevel::Normal));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(Vec2::from(MAIN_WINDOW_SIZE)));
+    }
+
+    // міні-вікно з бічної панелі - окреме прозоре вікно, а головне лишається таким, як було
+    fn show_overlay_window(&mut self, ctx: &egui::Context) {
+        let builder = egui::ViewportBuilder::default()
+            .with_title("Resource monitor")
+            .with_transparent(true)
+            .with_inner_size(OVERLAY_WINDOW_SIZE)
+            .with_decorations(false)
+            .with_always_on_top();
+
+        let is_closed = ctx.show_viewport_immediate(egui::ViewportId::from_hash_of("overlay"), builder, |ctx, class| {
+            // бекенд, що не вміє окремих вікон, малює його всередині головного
+            if class == egui::ViewportClass::Embedded {
+                let mut is_closed = false;
+
+                egui::Window::new(tr(self.language, "overlay.heading")).show(ctx, |ui| {
+                    is_closed = self.show_overlay_contents(ctx, ui, false);
+                });
+
+                return is_closed;
+            }
+
+            let is_close_requested = ctx.input(|input| input.viewport().close_requested());
+
+            self.show_overlay(ctx) || is_close_requested
+        });
+
+        if is_closed {
+            self.is_overlay_window_open = false;
+        }
+    }
+
+    fn update_overlay_series(&mut self) {
+        for series in &self.history.series {
+            let is_known = self.overlay_series
+                .iter()
+                .any(|overlay_series| overlay_series.device == series.device && overlay_series.sensor == series.sensor && overlay_series.kind == series.kind);
+
+            if !is_known {
+                // за замовчуванням показую лише першу температуру процесора та відеокарти, інакше міні-вікно не буде маленьким
+                let is_shown = (series.device == "cpu" || series.device == "gpu")
+                    && series.kind == SensorKind::Temperature
+                    && !self.overlay_series.iter().any(|overlay_series| overlay_series.device == series.device && overlay_series.kind == series.kind);
+
+                self.overlay_series.push(OverlaySeries {
+                    device: series.device.clone(),
+                    sensor: series.sensor.clone(),
+                    kind: series.kind,
+                    is_shown
+                });
+            }
+        }
+    }
+
+    // повертає true, якщо міні-вікно просять закрити
+    fn show_overlay(&mut self, ctx: &egui::Context) -> bool {
+        let frame = egui::Frame::none()
+            .fill(egui::Color32::from_black_alpha((self.overlay_opacity * 255.0) as u8))
+            .rounding(6.0)
+            .inner_margin(8.0);
+
+        egui::CentralPanel::default().frame(frame).show(ctx, |ui| self.show_overlay_contents(ctx, ui, true)).inner
+    }
+
+    // is_own_window - міні-вікно є окремим вікном без рамки, яке саме треба перетягувати та розтягувати
+    fn show_overlay_contents(&mut self, ctx: &egui::Context, ui: &mut egui::Ui, is_own_window: bool) -> bool {
+        let language = self.language;
+        let temperature_unit = self.temperature_unit;
+
+        let mut is_closed = false;
+
+        if is_own_window {
+            // у вікна немає рамки, тому воно перетягується за будь-яке вільне місце
+            let background = ui.interact(ui.max_rect(), ui.id().with("overlay_background"), egui::Sense::click_and_drag());
+
+            if background.drag_started() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+            }
+
+            if background.double_clicked() {
+                is_closed = true;
+            }
+        }
+
+        let close_hint = if self.is_overlay_mode { "overlay.back" } else { "overlay.close" };
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+            if ui.small_button("⤢").on_hover_text(tr(language, close_hint)).clicked() {
+                is_closed = true;
+            }
+        });
+
+        let shown_series: Vec<&HistorySeries> = self.overlay_series
+            .iter()
+            .filter(|overlay_series| overlay_series.is_shown && !self.settings.sensor_preferences.is_hidden(&overlay_series.device, &overlay_series.sensor, overlay_series.kind))
+            .filter_map(|overlay_series| self.history.series
+                .iter()
+                .find(|series| series.device == overlay_series.device && series.sensor == overlay_series.sensor && series.kind == overlay_series.kind))
+            .collect();
+
+        if shown_series.is_empty() {
+            ui.label(tr(language, "overlay.no_sensors"));
+        }
+
+        for series in shown_series {
+            let unit = series.kind.display_unit(temperature_unit);
+
+            let values: Vec<f32> = series.values[series.values.len().saturating_sub(OVERLAY_SPARKLINE_LENGTH)..]
+                .iter()
+                .filter(|value| !value.is_nan())
+                .map(|value| series.kind.to_display_value(*value, temperature_unit))
+                .collect();
+
+            let current = match series.values.last() {
+                Some(value) if !value.is_nan() => unit.format_value(series.kind.to_display_value(*value, temperature_unit) as f64, 1),
+                _ => "—".to_string()
+            };
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.label(egui::RichText::new(current).strong());
+                    ui.add(egui::Label::new(egui::RichText::new(get_series_name(&self.settings, &series.device, &series.sensor, series.kind, format!("{} / {}", series.device, series.sensor))).small()).truncate(true));
+                });
+            });
+
+            show_sparkline(ui, &values, get_style_color(&self.settings.series_styles.get(&series.device, &series.sensor, series.kind)));
+            ui.add_space(4.0);
+        }
+
+        if is_own_window {
+            let grip_rect = egui::Rect::from_min_max(ui.max_rect().right_bottom() - Vec2::splat(12.0), ui.max_rect().right_bottom());
+            let grip = ui.interact(grip_rect, ui.id().with("overlay_resize"), egui::Sense::drag());
+
+            if grip.hovered() {
+                ctx.set_cursor_icon(egui::CursorIcon::ResizeSouthEast);
+            }
+
+            if grip.drag_started() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::BeginResize(egui::viewport::ResizeDirection::SouthEast));
+            }
+
+            ui.painter().line_segment([grip_rect.left_bottom(), grip_rect.right_top()], egui::Stroke::new(1.0, ui.visuals().weak_text_color()));
+            ui.painter().line_segment([grip_rect.center_bottom(), grip_rect.right_center()], egui::Stroke::new(1.0, ui.visuals().weak_text_color()));
+        }
+
+        is_closed
+    }
+
+    fn show_main_window(&mut self, ctx: &egui::Context) {
+        let language = self.language;
+
+        egui::SidePanel::left("options").show(ctx, |ui| {
+            ui.heading(tr(language, "display.heading"));
+            ui.add_space(10.0);
+
+            egui::ComboBox::from_label(tr(language, "display.language"))
+                .selected_text(self.language.native_name())
+                .show_ui(ui, |ui| {
+                    for option in LANGUAGES {
+                        ui.selectable_value(&mut self.language, option, option.native_name());
+                    }
+                });
+
+            egui::ComboBox::from_label(tr(language, "display.temperature_unit"))
+                .selected_text(self.temperature_unit.symbol())
+                .show_ui(ui, |ui| {
+                    for option in TEMPERATURE_UNITS {
+                        ui.selectable_value(&mut self.temperature_unit, option, option.symbol());
+                    }
+                });
+            ui.add_space(10.0);
+
+            ui.checkbox(&mut self.is_display_gpu_temperature, tr(language, "display.gpu_temperature"));
+            ui.checkbox(&mut self.is_display_cpu_temperature, tr(language, "display.cpu_temperature"));
+            ui.checkbox(&mut self.is_display_trip_points, tr(language, "display.trip_points")).on_hover_text(tr(language, "display.trip_points_hint"));
+            ui.checkbox(&mut self.is_display_statistics, tr(language, "display.statistics")).on_hover_text(tr(language, "display.statistics_hint"));
+            ui.checkbox(&mut self.is_display_processes, tr(language, "display.processes"));
+
+            if ui.button(tr(language, "inventory.open")).clicked() {
+                self.is_sensor_inventory_open = true;
+            }
+            ui.add_space(10.0);
+
+            ui.collapsing(tr(language, "styles.heading"), |ui| {
+                let mut is_changed = false;
+
+                for entry in &mut self.settings.series_styles.entries {
+                    ui.label(format!("{} / {} ({})", entry.device, entry.sensor, tr(language, get_kind_key(entry.kind))));
+
+                    let mut is_entry_changed = false;
+
+                    ui.horizontal(|ui| {
+                        is_entry_changed |= ui.color_edit_button_srgb(&mut entry.style.color).changed();
+                        is_entry_changed |= ui.add(egui::DragValue::new(&mut entry.style.width).clamp_range(0.5..=8.0).speed(0.1)).on_hover_text(tr(language, "styles.width")).changed();
+
+                        egui::ComboBox::from_id_source(format!("chart_type_{}_{}_{}", entry.device, entry.sensor, entry.kind.name()))
+                            .selected_text(tr(language, get_chart_type_key(entry.style.chart_type)))
+                            .show_ui(ui, |ui| {
+                                for chart_type in SERIES_CHART_TYPES {
+                                    is_entry_changed |= ui.selectable_value(&mut entry.style.chart_type, chart_type, tr(language, get_chart_type_key(chart_type))).changed();
+                                }
+                            });
+
+                        is_entry_changed |= ui.checkbox(&mut entry.style.is_dashed, tr(language, "styles.dashed")).changed();
+                    });
+
+                    if is_entry_changed {
+                        entry.is_edited = true;
+                        is_changed = true;
+                    }
+                }
+                ui.add_space(5.0);
+
+                if ui.button(tr(language, "styles.reset")).on_hover_text(tr(language, "styles.reset_hint")).clicked() {
+                    self.settings.series_styles.reset_to_palette();
+
+                    is_changed = true;
+                }
+
+                if is_changed {
+                    self.save_settings();
+                }
+            });
+
+            ui.collapsing(tr(language, "panels.heading"), |ui| {
+                let panels_count = self.settings.chart_panels.len();
+
+                // індекс панелі, яку треба поміняти місцями з попередньою, та індекс панелі, яку треба прибрати
+                let mut moved_index = None;
+                let mut removed_index = None;
+
+                for (index, kind) in self.settings.chart_panels.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(index > 0, egui::Button::new("⬆").small()).on_hover_text(tr(language, "panels.move_up")).clicked() {
+                            moved_index = Some(index);
+                        }
+
+                        if ui.add_enabled(index + 1 < panels_count, egui::Button::new("⬇").small()).on_hover_text(tr(language, "panels.move_down")).clicked() {
+                            moved_index = Some(index + 1);
+                        }
+
+                        if ui.small_button("✖").on_hover_text(tr(language, "panels.remove")).clicked() {
+                            removed_index = Some(index);
+                        }
+
+                        ui.label(tr(language, get_kind_key(*kind)));
+                    });
+                }
+
+                let mut is_changed = false;
+
+                if let Some(index) = moved_index {
+                    self.settings.chart_panels.swap(index - 1, index);
+
+                    is_changed = true;
+                }
+
+                if let Some(index) = removed_index {
+                    self.settings.chart_panels.remove(index);
+
+                    is_changed = true;
+                }
+
+                let missing_kinds: Vec<SensorKind> = SENSOR_KINDS
+                    .iter()
+                    .filter(|kind| !self.settings.chart_panels.contains(kind))
+                    .copied()
+                    .collect();
+
+                if !missing_kinds.is_empty() {
+                    egui::ComboBox::from_id_source("add_chart_panel")
+                        .selected_text(tr(language, "panels.add"))
+                        .show_ui(ui, |ui| {
+                            for kind in missing_kinds {
+                                if ui.selectable_label(false, tr(language, get_kind_key(kind))).clicked() {
+                                    self.settings.chart_panels.push(kind);
+
+                                    is_changed = true;
+                                }
+                            }
+                        });
+                }
+
+                if is_changed {
+                    self.is_plot_reset_requested = true;
+
+                    self.save_settings();
+                }
+            });
+            ui.add_space(10.0);
+
+            ui.add_enabled_ui(self.tiered_storage.is_some(), |ui| {
+                let previous_period = self.history_period_seconds;
+
+                egui::ComboBox::from_label(tr(language, "period.label"))
+                    .selected_text(match self.history_period_seconds {
+                        0 => tr(language, "period.live"),
+                        3600 => tr(language, "period.hour"),
+                        86400 => tr(language, "period.day"),
+                        _ => tr(language, "period.week")
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.history_period_seconds, 0, tr(language, "period.live"));
+                        ui.selectable_value(&mut self.history_period_seconds, 3600, tr(language, "period.hour"));
+                        ui.selectable_value(&mut self.history_period_seconds, 86400, tr(language, "period.day"));
+                        ui.selectable_value(&mut self.history_period_seconds, 7 * 86400, tr(language, "period.week"));
+                    })
+                    .response
+                    .on_hover_text(tr(language, "period.hint"));
+
+                if previous_period != self.history_period_seconds {
+                    self.long_term_history_timer = None;
+                }
+            });
+            ui.add_space(10.0);
+
+            ui.add(egui::Separator::default());
+            ui.add_space(10.0);
+
+            ui.heading(tr(language, "overlay.heading"));
+            ui.add_space(10.0);
+
+            let overlay_button_key = if self.is_overlay_window_open { "overlay.close" } else { "overlay.open" };
+
+            if ui.button(tr(language, overlay_button_key)).on_hover_text(tr(language, "overlay.open_hint")).clicked() {
+                self.is_overlay_window_open = !self.is_overlay_window_open;
+            }
+            ui.add_space(5.0);
+
+            ui.label(tr(language, "overlay.opacity"));
+            ui.add(egui::Slider::new(&mut self.overlay_opacity, 0.2..=1.0));
+            ui.add_space(5.0);
+
+            ui.collapsing(tr(language, "overlay.sensors"), |ui| {
+                for series in &mut self.overlay_series {
+                    if self.settings.sensor_preferences.is_hidden(&series.device, &series.sensor, series.kind) {
+                        continue;
+                    }
+
+                    ui.checkbox(&mut series.is_shown, get_series_name(&self.settings, &series.device, &series.sensor, series.kind, format!("{} / {}", series.device, series.sensor)));
+                }
+            });
+            ui.add_space(10.0);
+
+            ui.add(egui::Separator::default());
+            ui.add_space(10.0);
+
+            ui.heading(tr(language, "optimization.heading"));
+            ui.add_space(10.0);
+
+            ui.collapsing(tr(language, "optimization.details"), |ui| {
+                ui.label(
+                    tr(language, "optimization.description")
+                );
+                ui.add_space(5.0);
+                ui.label(tr(language, "optimization.example"));
+                ui.label(tr(language, "optimization.example_text"));
+            });
+            ui.add_space(10.0);
+
+            ui.label(tr(language, "optimization.request_delay")).on_hover_text(tr(language, "optimization.request_delay_hint"));
+            ui.add(egui::Slider::new(&mut self.delay_between_temperature_requests, 0..=3000)).on_hover_text(tr(language, "optimization.request_delay_hint"));
+            ui.add_space(10.0);
+
+            ui.label(tr(language, "optimization.render_delay")).on_hover_text(tr(language, "optimization.render_delay_hint"));
+            ui.add(egui::Slider::new(&mut self.delay_between_updates, 1..=100)).on_hover_text(tr(language, "optimization.render_delay_hint"));
+            ui.add_space(10.0);
+
+            ui.label(tr(language, "optimization.stored_data")).on_hover_text(tr(language, "optimization.stored_data_hint"));
+            ui.add(egui::Slider::new(&mut self.amount_of_stored_data, 10..=1200)).on_hover_text(tr(language, "optimization.stored_data_hint"));
+            ui.add_space(10.0);
+
+            ui.add(egui::Separator::default());
+            ui.add_space(10.0);
+
+            ui.heading(tr(language, "notifications.heading"));
+            ui.add_space(10.0);
+
+            ui.checkbox(&mut self.is_notifications_enabled, tr(language, "notifications.enabled")).on_hover_text(tr(language, "notifications.enabled_hint"));
+            ui.add_space(10.0);
+
+            let temperature_unit = self.temperature_unit;
+
+            ui.label(tr_with(language, "notifications.cpu_threshold", &[("unit", temperature_unit.symbol())]));
+
+            if add_threshold_slider(ui, &mut self.cpu_temperature_alert.critical_threshold, temperature_unit) {
+                self.settings.cpu_alert_threshold = Some(self.cpu_temperature_alert.critical_threshold);
+                self.save_settings();
+            }
+            ui.add_space(10.0);
+
+            ui.label(tr_with(language, "notifications.gpu_threshold", &[("unit", temperature_unit.symbol())]));
+
+            if add_threshold_slider(ui, &mut self.gpu_temperature_alert.critical_threshold, temperature_unit) {
+                self.settings.gpu_alert_threshold = Some(self.gpu_temperature_alert.critical_threshold);
+                self.save_settings();
+            }
+            ui.add_space(10.0);
+
+            egui::ComboBox::from_label(tr(language, "notifications.urgency"))
+                .selected_text(match self.desktop_notifier.urgency {
+                    NotificationUrgency::Low => tr(language, "notifications.urgency_low"),
+                    NotificationUrgency::Normal => tr(language, "notifications.urgency_normal"),
+                    NotificationUrgency::Critical => tr(language, "notifications.urgency_critical")
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.desktop_notifier.urgency, NotificationUrgency::Low, tr(language, "notifications.urgency_low"));
+                    ui.selectable_value(&mut self.desktop_notifier.urgency, NotificationUrgency::Normal, tr(language, "notifications.urgency_normal"));
+                    ui.selectable_value(&mut self.desktop_notifier.urgency, NotificationUrgency::Critical, tr(language, "notifications.urgency_critical"));
+                });
+            ui.add_space(10.0);
+
+            ui.label(tr(language, "notifications.deduplication")).on_hover_text(tr(language, "notifications.deduplication_hint"));
+
+            if ui.add(egui::Slider::new(&mut self.settings.notification_deduplication_seconds, 0..=3600).suffix(" s")).changed() {
+                self.desktop_notifier.deduplication_interval = Duration::from_secs(self.settings.notification_deduplication_seconds);
+                self.save_settings();
+            }
+            ui.add_space(10.0);
+
+            ui.add(egui::Separator::default());
+            ui.add_space(10.0);
+
+            ui.heading(tr(language, "recording.heading"));
+            ui.add_space(10.0);
+
+            let mut is_recording = self.session_recorder.is_some();
+
+            if ui.checkbox(&mut is_recording, tr(language, "recording.enabled")).on_hover_text(tr(language, "recording.enabled_hint")).changed() {
+                if is_recording {
+                    self.start_session_recording();
+                } else {
+                    self.session_recorder = None;
+                }
+            }
+            ui.add_space(5.0);
+
+            ui.label(tr(language, "recording.file"));
+            ui.add_enabled(self.session_recorder.is_none(), egui::TextEdit::singleline(&mut self.recording_path));
+            ui.add_space(5.0);
+
+            if ui.add_enabled(self.session_recorder.is_none(), egui::Button::new(tr(language, "recording.open"))).clicked() {
+                let path = self.recording_path.clone();
+
+                self.open_recording(&path);
+            }
+
+            if let Some(error) = &self.recording_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+            ui.add_space(10.0);
+
+            ui.add(egui::Separator::default());
+            ui.add_space(10.0);
+
+            ui.heading(tr(language, "export.heading"));
+            ui.add_space(10.0);
+
+            ui.label(tr(language, "export.file")).on_hover_text(tr(language, "export.file_hint"));
+            ui.text_edit_singleline(&mut self.export_path);
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut self.export_format, ExportFormat::Csv, "CSV");
+                ui.radio_value(&mut self.export_format, ExportFormat::Json, "JSON");
+            });
+            ui.add_space(5.0);
+
+            if ui.button(tr(language, "export.button")).clicked() {
+                self.export_current_history();
+            }
+
+            if let Some(status) = &self.export_status {
+                ui.label(status);
+            }
+        });
+        if self.is_display_statistics {
+            egui::SidePanel::right("statistics").show(ctx, |ui| {
+                self.show_statistics(ui);
+            });
+        }
+
+        if self.is_display_processes {
+            egui::TopBottomPanel::bottom("processes").resizable(true).show(ctx, |ui| {
+                self.show_processes(ui);
+            });
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if self.opened_recording.is_some() {
+                self.show_opened_recording(ui);
+
+                return;
+            }
+
+            if self.history_period_seconds > 0 {
+                self.show_long_term_history(ui);
+
+                return;
+            }
+
+            ui.heading(tr(language, "plot.heading"));
+            ui.add_space(10.0);
+
+            ui.label(tr_with(language, "plot.cpu_name", &[("name", self.cpu_name.as_deref().unwrap_or(tr(language, "sensor.information_missing")))]));
+            ui.add_space(10.0);
+
+            ui.label(tr_with(language, "plot.gpu_name", &[("name", self.gpu_name.as_deref().unwrap_or(tr(language, "sensor.information_missing")))]));
+            ui.add_space(10.0);
+
+            self.show_scheduler_summary(ui);
+            self.show_live_plot(ui);
+        });
+
+        if self.is_sensor_inventory_open {
+            self.show_sensor_inventory(ctx);
+        }
+    }
+
+    // усі знайдені сенсори з останнім значенням, затримкою та станом бекенда, тут же вмикаються графіки та псевдоніми
+    fn show_sensor_inventory(&mut self, ctx: &egui::Context) {
+        let language = self.language;
+        let temperature_unit = self.temperature_unit;
+        let mut is_open = self.is_sensor_inventory_open;
+        let mut is_changed = false;
+
+        egui::Window::new(tr(language, "inventory.heading"))
+            .open(&mut is_open)
+            .default_size([900.0, 400.0])
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new(tr(language, "inventory.hint")).small());
+                ui.add_space(5.0);
+
+                if self.sensor_collector.inventory.entries.is_empty() {
+                    ui.label(tr(language, "inventory.empty"));
+
+                    return;
+                }
+
+                egui::ScrollArea::both().show(ui, |ui| {
+                    egui::Grid::new("sensor_inventory")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for header in ["inventory.plotted", "inventory.backend", "inventory.device", "inventory.sensor", "inventory.alias", "inventory.unit", "inventory.value", "inventory.latency", "inventory.state"] {
+                                ui.strong(tr(language, header));
+                            }
+                            ui.end_row();
+
+                            for entry in &self.sensor_collector.inventory.entries {
+                                let preferences = &mut self.settings.sensor_preferences;
+                                let is_plotted_default = is_plotted_by_default(&self.history, &entry.device, &entry.sensor, entry.kind);
+
+                                let mut is_plotted = preferences.is_plotted(&entry.device, &entry.sensor, entry.kind, is_plotted_default);
+
+                                if ui.checkbox(&mut is_plotted, "").changed() {
+                                    preferences.get_or_insert(&entry.device, &entry.sensor, entry.kind, is_plotted_default).is_plotted = is_plotted;
+                                    is_changed = true;
+                                }
+
+                                ui.label(entry.backend.name());
+                                ui.label(&entry.device);
+                                ui.label(&entry.sensor);
+
+                                let mut alias = preferences.alias(&entry.device, &entry.sensor, entry.kind).unwrap_or_default().to_string();
+
+                                if ui.add(egui::TextEdit::singleline(&mut alias).desired_width(120.0)).changed() {
+                                    preferences.get_or_insert(&entry.device, &entry.sensor, entry.kind, is_plotted_default).alias = alias;
+                                    is_changed = true;
+                                }
+
+                                ui.label(entry.unit.name());
+
+                                ui.label(entry.format_value(temperature_unit).unwrap_or("—".to_string()));
+
+                                ui.label(format_latency(entry.latency));
+
+                                if entry.errors > 0 {
+                                    ui.colored_label(egui::Color32::from_rgb(213, 94, 0), entry.state(language));
+                                } else if entry.is_missing {
+                                    ui.colored_label(egui::Color32::from_rgb(230, 159, 0), entry.state(language));
+                                } else {
+                                    ui.label(entry.state(language));
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+
+        self.is_sensor_inventory_open = is_open;
+
+        if is_changed {
+            self.save_settings();
+        }
+    }
+}
+
+impl eframe::App for PlotExample {
+    fn update(&mut self, ctx: &egui::Context, _: &mut eframe::Frame) {
+        if self.is_overlay_mode {
+            if self.show_overlay(ctx) {
+                self.leave_overlay_mode(ctx);
+            }
+        } else {
+            self.show_main_window(ctx);
+
+            if self.is_overlay_window_open {
+                self.show_overlay_window(ctx);
+            }
+        }
+
+        if self.inner_timer.is_none() {
+            self.inner_timer = Some(Instant::now());
+        } else if self.inner_timer.unwrap().elapsed() >= Duration::from_millis(self.delay_between_temperature_requests) {
+            let scrape_started = Instant::now();
+
+            let mut readings = self.sensor_collector.read_all_sensors();
+
+            // список процесів оновлюється з тим самим інтервалом, що й сенсори, але лише коли він комусь потрібен
+            if self.is_display_processes || !self.tracked_processes.is_empty() {
+                self.process_monitor.update();
+
+                readings.extend(self.process_monitor.get_tracked_readings(&self.tracked_processes));
+            }
+
+            if let Some(metrics) = &self.metrics {
+                update_metrics(metrics, &readings, scrape_started.elapsed(), self.sensor_collector.read_errors_total);
+            }
+
+            let timestamp = get_current_unix_timestamp();
+
+            if let Some(recorder) = &mut self.session_recorder {
+                match recorder.record_sample(timestamp, &readings) {
+                    Ok(_) => {},
+                    Err(error) => {
+                        self.recording_error = Some(tr_with(self.language, "recording.stopped", &[("error", &error.to_string())]));
+                        self.session_recorder = None;
+                    }
+                }
+            }
+
+            if let Some(gpu_temperature_inner) = find_sensor_value(&readings, "gpu", SensorKind::Temperature) {
+                self.check_temperature_alert(gpu_temperature_inner, false);
+            }
+
+            if let Some(cpu_temperature_inner) = find_sensor_value(&readings, "cpu", SensorKind::Temperature) {
+                self.check_temperature_alert(cpu_temperature_inner, true);
+            }
+
+            // на паузі історія не обрізається, щоб застиглий графік не втрачав значень; зайве відкинеться з першим значенням після паузи
+            let amount_of_stored_data = if self.paused_at.is_some() { usize::MAX } else { self.amount_of_stored_data as usize };
+
+            self.history.push_sample(timestamp, &readings, amount_of_stored_data);
+            self.update_overlay_series();
+            self.assign_series_styles();
+
+            let cpu_threshold = self.cpu_temperature_alert.critical_threshold;
+            let gpu_threshold = self.gpu_temperature_alert.critical_threshold;
+
+            self.throttling.push_sample(timestamp, &readings);
+
+            self.statistics.push_sample(timestamp, &readings, self.amount_of_stored_data as usize, |reading| {
+                match (reading.device.as_str(), reading.kind) {
+                    ("cpu", SensorKind::Temperature) => Some(cpu_threshold),
+                    ("gpu", SensorKind::Temperature) => Some(gpu_threshold),
+                    _ => None
+                }
+            });
+
+            if let Some(tiered_storage) = &mut self.tiered_storage {
+                match tiered_storage.push_sample(timestamp, &readings) {
+                    Ok(_) => {},
+                    Err(error) => log::warn!("не вдалося записати довгострокову історію: {}", error)
+                }
+            }
+
+            self.inner_timer = None;
+        }
+
+        std::thread::sleep(Duration::from_millis(self.delay_between_updates)); // обмежую "fps" програми, щоб заощадити ресурси комп'ютера
+
+        ctx.request_repaint();
+    }
+
+    // у міні-вікні фон вікна прозорий, а напівпрозору підкладку малює show_overlay
+    fn clear_color(&self, visuals: &egui::Visuals) -> [f32; 4] {
+        if self.is_overlay_mode {
+            [0.0; 4]
+        } else {
+            visuals.panel_fill.to_normalized_gamma_f32()
+        }
+    }
+
+    fn on_exit(&mut self, _gl: Option<&glow::Context>) {
+        self.save_settings();
+
+        if let Some(tiered_storage) = &mut self.tiered_storage {
+            match tiered_storage.flush_open_buckets() {
+                Ok(_) => {},
+                Err(error) => log::warn!("не вдалося записати довгострокову історію: {}", error)
+            }
+        }
+
+        if self.is_exported_on_exit {
+            self.export_current_history();
+
+            if let Some(status) = &self.export_status {
+                log::info!("{}", status);
+            }
+        }
+    }
+}
+
+fn get_style_color(style: &SeriesStyle) -> egui::Color32 {
+    egui::Color32::from_rgb(style.color[0], style.color[1], style.color[2])
+}
+
+fn get_styled_line(points: Vec<[f64; 2]>, style: &SeriesStyle) -> Line {
+    // область зафарбовується до найменшого значення лінії, а не до нуля, інакше на графіку температур вона займала б майже все
+    let lowest_value = points.iter().map(|point| point[1]).fold(f64::MAX, f64::min);
+
+    let points = match style.chart_type {
+        SeriesChartType::Step => to_step_points(&points),
+        _ => points
+    };
+
+    let mut line = Line::new(PlotPoints::new(points)).width(style.width).color(get_style_color(style));
+
+    if style.chart_type == SeriesChartType::Area && lowest_value.is_finite() {
+        line = line.fill(lowest_value as f32);
+    }
+
+    if style.is_dashed {
+        line = line.style(egui_plot::LineStyle::Dashed { length: 10.0 });
+    }
+
+    line
+}
+
+fn get_chart_type_key(chart_type: SeriesChartType) -> &'static str {
+    match chart_type {
+        SeriesChartType::Line => "styles.line",
+        SeriesChartType::Area => "styles.area",
+        SeriesChartType::Step => "styles.step"
+    }
+}
+
+// назва лінії, її історія та стиль для живого графіка
+type LiveSeries<'a> = (String, &'a HistorySeries, SeriesStyle);
+
+// температур ядер процесора буває десятки, тому без налаштувань у вікні сенсорів показується лише перша,
+// а от потужностей (пакет, ядра, dram) мало і вони всі потрібні
+fn is_plotted_by_default(history: &History, device: &str, sensor: &str, kind: SensorKind) -> bool {
+    if kind != SensorKind::Temperature || (device != "cpu" && device != "gpu") {
+        return true;
+    }
+
+    history.find_series(device, kind).map(|first_series| first_series.sensor == sensor).unwrap_or(true)
+}
+
+// псевдонім з вікна сенсорів замінює назву лінії в усіх видах
+fn get_series_name(settings: &Settings, device: &str, sensor: &str, kind: SensorKind, default_name: String) -> String {
+    match settings.sensor_preferences.alias(device, sensor, kind) {
+        Some(alias) => alias.to_string(),
+        None => default_name
+    }
+}
+
+// в панелі показуються сенсори процесора та відеокарти (якщо їх увімкнено) та всі сенсори інших пристроїв,
+// крім вимкнених у вікні сенсорів; номер сенсора пристрою, а з ним і назва за замовчуванням, не залежать від того, які сенсори вимкнено
+fn get_live_series<'a>(history: &'a History, settings: &Settings, kind: SensorKind, is_display_cpu: bool, is_display_gpu: bool) -> Vec<LiveSeries<'a>> {
+    let mut result = Vec::new();
+
+    let preferences = &settings.sensor_preferences;
+
+    for (device, name, is_displayed) in [("gpu", "GPU", is_display_gpu), ("cpu", "CPU", is_display_cpu)] {
+        if !is_displayed {
+            continue;
+        }
+
+        let device_series = history.series.iter().filter(|series| series.device == device && series.kind == kind);
+
+        for (index, series) in device_series.enumerate() {
+            let is_plotted_default = index == 0 || kind != SensorKind::Temperature;
+
+            if !preferences.is_plotted(&series.device, &series.sensor, series.kind, is_plotted_default) {
+                continue;
+            }
+
+            let default_name = if index == 0 {
+                name.to_string()
+            } else {
+                format!("{} / {}", name, series.sensor)
+            };
+
+            result.push((get_series_name(settings, &series.device, &series.sensor, series.kind, default_name), series, settings.series_styles.get(&series.device, &series.sensor, series.kind)));
+        }
+    }
+
+    for series in history.series.iter().filter(|series| series.kind == kind && series.device != "cpu" && series.device != "gpu" && preferences.is_plotted(&series.device, &series.sensor, series.kind, true)) {
+        result.push((get_series_name(settings, &series.device, &series.sensor, series.kind, format!("{} / {}", series.device, series.sensor)), series, settings.series_styles.get(&series.device, &series.sensor, series.kind)));
+    }
+
+    result
+}
+
+struct PanelLine {
+    name: String,
+    kind: SensorKind,
+    style: SeriesStyle,
+    points: Vec<[f64; 2]>
+}
+
+// панелі довгострокової історії та запису: спільна вісь x і курсор, а в кожної панелі своя одиниця
+fn show_static_panels(ui: &mut egui::Ui, id: &str, chart_panels: &[SensorKind], lines: &[PanelLine], temperature_unit: MeasurementUnit, x_scale: f64, x_unit: &'static str) {
+    let kinds: Vec<SensorKind> = chart_panels
+        .iter()
+        .filter(|kind| lines.iter().any(|line| line.kind == **kind))
+        .copied()
+        .collect();
+
+    if kinds.is_empty() {
+        return;
+    }
+
+    let panel_height = (ui.available_height() / kinds.len() as f32 - ui.spacing().item_spacing.y).max(80.0);
+
+    for kind in kinds {
+        let unit = kind.display_unit(temperature_unit);
+
+        egui_plot::Plot::new(format!("{}_{}", id, kind.name()))
+            .height(panel_height)
+            .link_axis(id.to_string(), true, false)
+            .link_cursor(id.to_string(), true, false)
+            .allow_zoom(false)
+            .allow_drag(false)
+            .allow_scroll(false)
+            .allow_boxed_zoom(false)
+            .show_axes(egui::Vec2b{x: true, y: true})
+            .show_grid(egui::Vec2b{x: false, y: true})
+            .legend(Legend::default()
+                .background_alpha(1.0)
+                .position(egui_plot::Corner::RightBottom)
+            )
+            .x_axis_formatter(move |value, _, _| format!("{:.*} {}", 1, value / x_scale, x_unit))
+            .y_axis_formatter(move |value, _, _| unit.format_value(value, 0))
+            .label_formatter(move |name, value| {
+                if !name.is_empty() {
+                    format!("{}: {} ({:.*} {})", name, unit.format_value(value.y, 1), 1, value.x / x_scale, x_unit)
+                } else {
+                    "".to_owned()
+                }
+            })
+            .show(ui, |plot_ui| {
+                for line in lines.iter().filter(|line| line.kind == kind) {
+                    plot_ui.line(get_styled_line(line.points.clone(), &line.style).name(&line.name));
+                }
+            });
+    }
+}
+
+fn get_trip_point_key(kind: TripPointKind) -> &'static str {
+    match kind {
+        TripPointKind::Passive => "trip_points.passive",
+        TripPointKind::Hot => "trip_points.hot",
+        TripPointKind::Critical => "trip_points.critical",
+        TripPointKind::Maximum => "trip_points.maximum",
+        TripPointKind::Slowdown => "trip_points.slowdown",
+        TripPointKind::Shutdown => "trip_points.shutdown"
+    }
+}
+
+fn get_kind_key(kind: SensorKind) -> &'static str {
+    match kind {
+        SensorKind::Temperature => "kind.temperature",
+        SensorKind::Load => "kind.load",
+        SensorKind::Power => "kind.power",
+        SensorKind::Memory => "kind.memory",
+        SensorKind::FanSpeed => "kind.fan_speed",
+        SensorKind::FanDuty => "kind.fan_duty",
+        SensorKind::Frequency => "kind.frequency",
+        SensorKind::Throttling => "kind.throttling",
+        SensorKind::DiskThroughput => "kind.disk_throughput",
+        SensorKind::DiskOperations => "kind.disk_operations",
+        SensorKind::NetworkThroughput => "kind.network_throughput",
+        SensorKind::NetworkPackets => "kind.network_packets",
+        SensorKind::BatteryCharge => "kind.battery_charge",
+        SensorKind::Voltage => "kind.voltage",
+        SensorKind::AcOnline => "kind.ac_online",
+        SensorKind::Pressure => "kind.pressure",
+        SensorKind::LoadAverage => "kind.load_average",
+        SensorKind::SchedulerEvents => "kind.scheduler_events",
+        SensorKind::ProcessCount => "kind.process_count",
+        SensorKind::ThrottleCount => "kind.throttle_count"
+    }
+}
+
+fn show_sparkline(ui: &mut egui::Ui, values: &[f32], color: egui::Color32) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 28.0), egui::Sense::hover());
+
+    if values.len() < 2 {
+        return;
+    }
+
+    let min = values.iter().cloned().fold(f32::MAX, f32::min);
+    let max = values.iter().cloned().fold(f32::MIN, f32::max);
+
+    // щоб майже рівна лінія не розтягувалася на всю висоту, діапазон беру щонайменше в один градус
+    let range = (max - min).max(1.0);
+
+    let points: Vec<egui::Pos2> = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| egui::pos2(
+            rect.left() + rect.width() * index as f32 / (values.len() - 1) as f32,
+            rect.bottom() - rect.height() * (value - min) / range
+        ))
+        .collect();
+
+    ui.painter().add(egui::Shape::line(points, egui::Stroke::new(1.5, color)));
+}
+
+// поріг зберігається в градусах Цельсія, а слайдер показує його в обраній одиниці
+// true, якщо користувач змінив поріг
+fn add_threshold_slider(ui: &mut egui::Ui, celsius_threshold: &mut f32, temperature_unit: MeasurementUnit) -> bool {
+    let kind = SensorKind::Temperature;
+
+    let mut threshold = kind.to_display_value(*celsius_threshold, temperature_unit);
+    let range = kind.to_display_value(50.0, temperature_unit)..=kind.to_display_value(110.0, temperature_unit);
+
+    let is_changed = ui.add(egui::Slider::new(&mut threshold, range)).changed();
+
+    if is_changed {
+        *celsius_threshold = kind.from_display_value(threshold, temperature_unit);
+    }
+
+    is_changed
+}
+
+pub fn run_single_threaded_implementation(arguments: &CliArguments) -> Result<(), eframe::Error> {
+    initialize_com();
+
+    let mut plot = PlotExample {
+        delay_between_temperature_requests: arguments.delay_between_temperature_requests,
+        temperature_unit: arguments.temperature_unit,
+        metrics: start_metrics_server_if_requested(&arguments.metrics_address),
+        ..Default::default()
+    };
+
+    let cgroup_monitor = open_cgroup_monitor_if_requested(&arguments.cgroup);
+
+    plot.process_monitor.set_cgroup_directory(cgroup_monitor.as_ref().map(|cgroup_monitor| cgroup_monitor.path().to_path_buf()));
+    plot.sensor_collector.set_cgroup_monitor(cgroup_monitor);
+
+    let storage_directory = match &arguments.storage_directory {
+        Some(directory) => PathBuf::from(directory),
+        None => get_default_storage_directory()
+    };
+
+    plot.tiered_storage = match TieredStorage::open(&storage_directory) {
+        Ok(tiered_storage) => Some(tiered_storage),
+        Err(error) => {
+            log::warn!("довгострокова історія вимкнена, не вдалося відкрити {}: {}", storage_directory.display(), error);
+
+            None
+        }
+    };
+
+    if let Some(path) = &arguments.export_path {
+        plot.export_path = path.clone();
+        plot.export_format = ExportFormat::from_path(&PathBuf::from(path));
+        plot.is_exported_on_exit = true;
+    }
+
+    if let Some(path) = &arguments.recording_path {
+        plot.recording_path = path.clone();
+
+        if arguments.is_recording_opened {
+            plot.open_recording(path);
+        } else {
+            plot.start_session_recording();
+        }
+    }
+
+    // від прозорості головного вікна залежить формат буфера OpenGL, спільний для всіх вікон програми,
+    // тож без неї не було б прозорим і окреме міні-вікно; саме головне вікно зафарбовує фон у clear_color
+    let mut options = eframe::NativeOptions {
+        centered: true,
+        viewport: egui::ViewportBuilder::default().with_inner_size(MAIN_WINDOW_SIZE).with_transparent(true),
+        ..Default::default()
+    };
+
+    if arguments.is_overlay_mode {
+        plot.is_overlay_mode = true;
+
+        options.viewport = options.viewport
+            .with_inner_size(OVERLAY_WINDOW_SIZE)
+            .with_decorations(false)
+            .with_always_on_top();
+    }
 
     eframe::run_native(
         "Resource monitor",
         options,
-        Box::new(|_cc| Box::<PlotExample>::default()),
+        Box::new(|_cc| Box::new(plot)),
     )
 }
\ No newline at end of file
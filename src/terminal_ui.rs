@@ -0,0 +1,506 @@
+use std::fs::{self, File};
+use std::io::{self, Stdout};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::prelude::*;
+use ratatui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, ListState, Paragraph};
+
+use crate::cgroup::open_cgroup_monitor_if_requested;
+use crate::cli_arguments::CliArguments;
+use crate::history::{History, HistorySeries};
+use crate::history_export::{export_history, ExportFormat, ExportMetadata};
+use crate::localization::{detect_language, tr, tr_with, Language};
+use crate::metrics_exporter::{start_metrics_server_if_requested, update_metrics, SharedMetrics};
+use crate::sensors::{initialize_com, SensorCollector, SensorKind};
+use crate::session_recording::{get_current_unix_timestamp, SessionRecorder};
+use crate::tiered_storage::{get_default_storage_directory, TieredStorage};
+use crate::units::{MeasurementUnit, TEMPERATURE_UNITS};
+
+// пункти панелі налаштувань до списку сенсорів, у тому ж порядку, що й у бічній панелі egui
+const OPTION_GPU_TEMPERATURE: usize = 0;
+const OPTION_CPU_TEMPERATURE: usize = 1;
+const OPTION_REQUEST_DELAY: usize = 2;
+const OPTION_STORED_DATA: usize = 3;
+const OPTION_TEMPERATURE_UNIT: usize = 4;
+const OPTIONS_COUNT: usize = 5;
+
+const OTHER_SERIES_COLORS: [Color; 5] = [Color::Cyan, Color::Yellow, Color::Magenta, Color::Blue, Color::LightRed];
+
+// назва, колір і точки (секунди відносно reference_timestamp, значення) однієї лінії графіка
+type ChartSeries = (String, Color, Vec<(f64, f64)>);
+
+struct TerminalSeries {
+    device: String,
+    sensor: String,
+    kind: SensorKind,
+    is_shown: bool
+}
+
+struct TerminalUi {
+    language: Language,
+    delay_between_temperature_requests: u64,
+    inner_timer: Option<Instant>,
+    is_display_gpu_temperature: bool,
+    is_display_cpu_temperature: bool,
+    history: History,
+    cpu_name: Option<String>,
+    gpu_name: Option<String>,
+    amount_of_stored_data: u16,
+    sensor_collector: SensorCollector,
+    temperature_unit: MeasurementUnit,
+    // сенсори всіх інших пристроїв, крім процесора та відеокарти, кожен вмикається окремо
+    other_series: Vec<TerminalSeries>,
+    selected_option: usize,
+    paused_at: Option<f64>,
+    visible_seconds: f64,
+    metrics: Option<SharedMetrics>,
+    tiered_storage: Option<TieredStorage>,
+    session_recorder: Option<SessionRecorder>,
+    // --export: історія записується сюди після виходу
+    export_path: Option<String>
+}
+
+impl TerminalUi {
+    fn new(arguments: &CliArguments) -> io::Result<Self> {
+        // файл запису створюю до перемикання терміналу, щоб помилку було видно звичайним повідомленням
+        let session_recorder = match &arguments.recording_path {
+            Some(path) => Some(SessionRecorder::create(&PathBuf::from(path))?),
+            None => None
+        };
+
+        let mut sensor_collector = SensorCollector::new();
+
+        sensor_collector.set_cgroup_monitor(open_cgroup_monitor_if_requested(&arguments.cgroup));
+
+        let cpu_name = sensor_collector.get_cpu_name();
+
+        let gpu_name = sensor_collector.get_gpu_name();
+
+        let storage_directory = match &arguments.storage_directory {
+            Some(directory) => PathBuf::from(directory),
+            None => get_default_storage_directory()
+        };
+
+        let tiered_storage = match TieredStorage::open(&storage_directory) {
+            Ok(tiered_storage) => Some(tiered_storage),
+            Err(error) => {
+                log::warn!("довгострокова історія вимкнена, не вдалося відкрити {}: {}", storage_directory.display(), error);
+
+                None
+            }
+        };
+
+        Ok(Self {
+            language: detect_language(),
+            delay_between_temperature_requests: arguments.delay_between_temperature_requests,
+            inner_timer: None,
+            is_display_gpu_temperature: true,
+            is_display_cpu_temperature: true,
+            history: History::default(),
+            cpu_name,
+            gpu_name,
+            amount_of_stored_data: 1200,
+            sensor_collector,
+            temperature_unit: arguments.temperature_unit,
+            other_series: Vec::new(),
+            selected_option: 0,
+            paused_at: None,
+            visible_seconds: 60.0,
+            metrics: start_metrics_server_if_requested(&arguments.metrics_address),
+            tiered_storage,
+            session_recorder,
+            export_path: arguments.export_path.clone()
+        })
+    }
+
+    fn sample_sensors(&mut self) {
+        let scrape_started = Instant::now();
+
+        let readings = self.sensor_collector.read_all_sensors();
+
+        if let Some(metrics) = &self.metrics {
+            update_metrics(metrics, &readings, scrape_started.elapsed(), self.sensor_collector.read_errors_total);
+        }
+
+        let timestamp = get_current_unix_timestamp();
+
+        if let Some(recorder) = &mut self.session_recorder {
+            match recorder.record_sample(timestamp, &readings) {
+                Ok(_) => {},
+                Err(error) => {
+                    log::error!("запис сесії зупинено: {}", error);
+
+                    self.session_recorder = None;
+                }
+            }
+        }
+
+        // як і у вікні, на паузі історія не обрізається, щоб застиглий графік не втрачав значень
+        let amount_of_stored_data = if self.paused_at.is_some() { usize::MAX } else { self.amount_of_stored_data as usize };
+
+        self.history.push_sample(timestamp, &readings, amount_of_stored_data);
+
+        if let Some(tiered_storage) = &mut self.tiered_storage {
+            match tiered_storage.push_sample(timestamp, &readings) {
+                Ok(_) => {},
+                Err(error) => log::warn!("не вдалося записати довгострокову історію: {}", error)
+            }
+        }
+
+        // графік у терміналі один і з температурною віссю, тому інші види сенсорів у нього не потрапляють
+        for series in &self.history.series {
+            if series.device == "cpu" || series.device == "gpu" || series.kind != SensorKind::Temperature {
+                continue;
+            }
+
+            let is_known = self.other_series
+                .iter()
+                .any(|other_series| other_series.device == series.device && other_series.sensor == series.sensor && other_series.kind == series.kind);
+
+            if !is_known {
+                self.other_series.push(TerminalSeries {
+                    device: series.device.clone(),
+                    sensor: series.sensor.clone(),
+                    kind: series.kind,
+                    is_shown: false
+                });
+            }
+        }
+    }
+
+    fn get_displayed_series(&self) -> Vec<(String, Color, &HistorySeries)> {
+        let mut displayed_series = Vec::new();
+
+        if self.is_display_gpu_temperature {
+            if let Some(series) = self.history.find_series("gpu", SensorKind::Temperature) {
+                displayed_series.push(("GPU".to_string(), Color::Green, series));
+            }
+        }
+
+        if self.is_display_cpu_temperature {
+            if let Some(series) = self.history.find_series("cpu", SensorKind::Temperature) {
+                displayed_series.push(("CPU".to_string(), Color::Red, series));
+            }
+        }
+
+        for (index, other_series) in self.other_series.iter().enumerate() {
+            if !other_series.is_shown {
+                continue;
+            }
+
+            let series = self.history.series
+                .iter()
+                .find(|series| series.device == other_series.device && series.sensor == other_series.sensor && series.kind == other_series.kind);
+
+            if let Some(series) = series {
+                displayed_series.push((format!("{} / {}", series.device, series.sensor), OTHER_SERIES_COLORS[index % OTHER_SERIES_COLORS.len()], series));
+            }
+        }
+
+        displayed_series
+    }
+
+    // у пам'яті лежить лише amount_of_stored_data значень, тож віддаляти графік далі за них немає сенсу
+    fn get_max_visible_seconds(&self) -> f64 {
+        (self.amount_of_stored_data as f64 * self.delay_between_temperature_requests as f64 / 1000.0).max(5.0)
+    }
+
+    fn get_latest_timestamp(&self) -> f64 {
+        match self.history.timestamps.last() {
+            Some(timestamp) => *timestamp,
+            None => get_current_unix_timestamp()
+        }
+    }
+
+    // повертає false, якщо користувач хоче вийти
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        let options_count = OPTIONS_COUNT + self.other_series.len();
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_option = (self.selected_option + options_count - 1) % options_count;
+            },
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_option = (self.selected_option + 1) % options_count;
+            },
+            KeyCode::Left | KeyCode::Char('h') => self.change_selected_option(-1),
+            KeyCode::Right | KeyCode::Char('l') => self.change_selected_option(1),
+            KeyCode::Char(' ') | KeyCode::Enter => self.change_selected_option(0),
+            KeyCode::Char('p') => {
+                self.paused_at = match self.paused_at {
+                    Some(_) => None,
+                    None => Some(self.get_latest_timestamp())
+                };
+            },
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.visible_seconds = (self.visible_seconds / 2.0).max(5.0);
+            },
+            KeyCode::Char('-') => {
+                self.visible_seconds = (self.visible_seconds * 2.0).min(self.get_max_visible_seconds());
+            },
+            // зсув по осі часу можливий лише на паузі, наживо графік завжди закінчується останнім значенням
+            KeyCode::Char('[') => {
+                if let Some(paused_at) = &mut self.paused_at {
+                    *paused_at -= self.visible_seconds / 4.0;
+                }
+            },
+            KeyCode::Char(']') => {
+                let latest_timestamp = self.get_latest_timestamp();
+
+                if let Some(paused_at) = &mut self.paused_at {
+                    *paused_at = f64::min(*paused_at + self.visible_seconds / 4.0, latest_timestamp);
+                }
+            },
+            KeyCode::Char('0') => {
+                self.paused_at = None;
+                self.visible_seconds = f64::min(60.0, self.get_max_visible_seconds());
+            },
+            _ => {}
+        }
+
+        true
+    }
+
+    // direction: -1 / 1 для повзунків і вибору одиниці, 0 - перемикання прапорця
+    fn change_selected_option(&mut self, direction: i64) {
+        match self.selected_option {
+            OPTION_GPU_TEMPERATURE => self.is_display_gpu_temperature = !self.is_display_gpu_temperature,
+            OPTION_CPU_TEMPERATURE => self.is_display_cpu_temperature = !self.is_display_cpu_temperature,
+            OPTION_REQUEST_DELAY => {
+                self.delay_between_temperature_requests = (self.delay_between_temperature_requests as i64 + direction * 100).clamp(0, 3000) as u64;
+                self.visible_seconds = self.visible_seconds.min(self.get_max_visible_seconds());
+            },
+            OPTION_STORED_DATA => {
+                self.amount_of_stored_data = (self.amount_of_stored_data as i64 + direction * 50).clamp(10, 1200) as u16;
+                self.visible_seconds = self.visible_seconds.min(self.get_max_visible_seconds());
+            },
+            OPTION_TEMPERATURE_UNIT => {
+                let index = TEMPERATURE_UNITS.iter().position(|unit| *unit == self.temperature_unit).unwrap_or(0) as i64;
+                let step = if direction == 0 { 1 } else { direction };
+
+                self.temperature_unit = TEMPERATURE_UNITS[(index + step).rem_euclid(TEMPERATURE_UNITS.len() as i64) as usize];
+            },
+            index => {
+                if let Some(series) = self.other_series.get_mut(index - OPTIONS_COUNT) {
+                    series.is_shown = !series.is_shown;
+                }
+            }
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let language = self.language;
+
+        let rows = Layout::new(Direction::Vertical, [Constraint::Length(2), Constraint::Min(5), Constraint::Length(1)]).split(frame.size());
+        let columns = Layout::new(Direction::Horizontal, [Constraint::Length(44), Constraint::Min(20)]).split(rows[1]);
+
+        let header = vec![
+            Line::from(tr_with(language, "plot.cpu_name", &[("name", self.cpu_name.as_deref().unwrap_or(tr(language, "sensor.information_missing")))])),
+            Line::from(tr_with(language, "plot.gpu_name", &[("name", self.gpu_name.as_deref().unwrap_or(tr(language, "sensor.information_missing")))]))
+        ];
+
+        frame.render_widget(Paragraph::new(header), rows[0]);
+
+        self.draw_options(frame, columns[0]);
+        self.draw_chart(frame, columns[1]);
+
+        let status = if self.paused_at.is_some() {
+            tr(language, "tui.paused_keys")
+        } else {
+            tr(language, "tui.keys")
+        };
+
+        frame.render_widget(Paragraph::new(status).style(Style::default().fg(Color::DarkGray)), rows[2]);
+    }
+
+    fn draw_options(&self, frame: &mut Frame, area: Rect) {
+        let language = self.language;
+
+        let checkbox = |is_checked: bool| if is_checked { "[x]" } else { "[ ]" };
+
+        let mut items = vec![
+            ListItem::new(format!("{} {}", checkbox(self.is_display_gpu_temperature), tr(language, "display.gpu_temperature"))),
+            ListItem::new(format!("{} {}", checkbox(self.is_display_cpu_temperature), tr(language, "display.cpu_temperature"))),
+            ListItem::new(format!("{}: ◀ {} ▶", tr(language, "optimization.request_delay"), self.delay_between_temperature_requests)),
+            ListItem::new(format!("{}: ◀ {} ▶", tr(language, "optimization.stored_data"), self.amount_of_stored_data)),
+            ListItem::new(format!("{}: ◀ {} ▶", tr(language, "display.temperature_unit"), self.temperature_unit.symbol()))
+        ];
+
+        for series in &self.other_series {
+            items.push(ListItem::new(format!("{} {} / {}", checkbox(series.is_shown), series.device, series.sensor)));
+        }
+
+        let mut state = ListState::default().with_selected(Some(self.selected_option));
+
+        let list = List::new(items)
+            .block(Block::default().title(tr(language, "display.heading")).borders(Borders::ALL))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn draw_chart(&self, frame: &mut Frame, area: Rect) {
+        let language = self.language;
+
+        let reference_timestamp = self.paused_at.unwrap_or(self.get_latest_timestamp());
+        let unit_symbol = SensorKind::Temperature.display_unit(self.temperature_unit).symbol();
+
+        // вісь x - секунди відносно reference_timestamp, як і на графіку egui
+        let displayed_series: Vec<ChartSeries> = self.get_displayed_series()
+            .into_iter()
+            .map(|(name, color, series)| {
+                let points = self.history
+                    .series_points(series, reference_timestamp)
+                    .into_iter()
+                    .filter(|point| point[0] >= -self.visible_seconds && point[0] <= 0.0)
+                    .map(|point| (point[0], series.kind.to_display_value(point[1] as f32, self.temperature_unit) as f64))
+                    .collect();
+
+                (name, color, points)
+            })
+            .collect();
+
+        let mut min = f64::MAX;
+        let mut max = f64::MIN;
+
+        for (_, _, points) in &displayed_series {
+            for (_, value) in points {
+                min = f64::min(min, *value);
+                max = f64::max(max, *value);
+            }
+        }
+
+        if min > max {
+            min = 0.0;
+            max = 100.0;
+        }
+
+        // невеликий запас, щоб лінія не лежала на рамці
+        let padding = ((max - min) * 0.1).max(1.0);
+        let (min, max) = ((min - padding).floor(), (max + padding).ceil());
+
+        let datasets: Vec<Dataset> = displayed_series
+            .iter()
+            .map(|(name, color, points)| Dataset::default()
+                .name(name.clone())
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(*color))
+                .data(points))
+            .collect();
+
+        let title = if self.paused_at.is_some() {
+            format!("{} ({})", tr(language, "plot.heading"), tr(language, "tui.paused"))
+        } else {
+            tr(language, "plot.heading").to_string()
+        };
+
+        let chart = Chart::new(datasets)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .x_axis(Axis::default()
+                .bounds([-self.visible_seconds, 0.0])
+                .labels(vec![
+                    Span::raw(format!("{:.*} s", 0, -self.visible_seconds)),
+                    Span::raw(format!("{:.*} s", 0, -self.visible_seconds / 2.0)),
+                    Span::raw("0 s")
+                ]))
+            .y_axis(Axis::default()
+                .bounds([min, max])
+                .labels(vec![
+                    Span::raw(format!("{:.*}{}", 0, min, unit_symbol)),
+                    Span::raw(format!("{:.*}{}", 0, (min + max) / 2.0, unit_symbol)),
+                    Span::raw(format!("{:.*}{}", 0, max, unit_symbol))
+                ]));
+
+        frame.render_widget(chart, area);
+    }
+}
+
+fn run_event_loop(terminal: &mut Terminal<CrosstermBackend<Stdout>>, terminal_ui: &mut TerminalUi) -> io::Result<()> {
+    loop {
+        if terminal_ui.inner_timer.is_none() {
+            terminal_ui.inner_timer = Some(Instant::now());
+        } else if terminal_ui.inner_timer.unwrap().elapsed() >= Duration::from_millis(terminal_ui.delay_between_temperature_requests) {
+            terminal_ui.sample_sensors();
+
+            terminal_ui.inner_timer = None;
+        }
+
+        terminal.draw(|frame| terminal_ui.draw(frame))?;
+
+        // очікування клавіші одночасно обмежує частоту перемальовування
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !terminal_ui.handle_key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+pub fn get_terminal_ui_log_path() -> PathBuf {
+    std::env::temp_dir().join("resource_monitor_tui.log")
+}
+
+// поки термінальний інтерфейс займає екран, записи лога в stderr перемішувалися б з ним, тому вони йдуть у файл
+pub fn init_terminal_ui_logger() {
+    let target = match File::create(get_terminal_ui_log_path()) {
+        Ok(file) => env_logger::Target::Pipe(Box::new(file)),
+        Err(_) => env_logger::Target::Pipe(Box::new(io::sink()))
+    };
+
+    env_logger::Builder::from_default_env().target(target).init();
+}
+
+pub fn run_terminal_ui(arguments: &CliArguments) -> io::Result<()> {
+    initialize_com();
+
+    let mut terminal_ui = TerminalUi::new(arguments)?;
+
+    // після паніки термінал теж треба повернути до звичайного стану, інакше її повідомлення не буде видно
+    let default_panic_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, crossterm::cursor::Show);
+
+        default_panic_hook(panic_info);
+    }));
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+    let result = run_event_loop(&mut terminal, &mut terminal_ui);
+
+    // термінал повертаю до звичайного стану навіть після помилки, інакше оболонка залишиться в raw режимі
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    let log_path = get_terminal_ui_log_path();
+
+    if fs::metadata(&log_path).is_ok_and(|metadata| metadata.len() > 0) {
+        eprintln!("{}", tr_with(terminal_ui.language, "tui.log_written", &[("path", &log_path.display().to_string())]));
+    }
+
+    result?;
+
+    if let Some(path) = &terminal_ui.export_path {
+        let path = PathBuf::from(path);
+        let metadata = ExportMetadata::new(terminal_ui.delay_between_temperature_requests, terminal_ui.temperature_unit);
+
+        export_history(&path, ExportFormat::from_path(&path), &terminal_ui.history.to_recorded_series(), &metadata)?;
+
+        println!("{}", tr_with(terminal_ui.language, "export.saved", &[("path", &path.display().to_string())]));
+    }
+
+    Ok(())
+}
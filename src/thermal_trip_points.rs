@@ -0,0 +1,176 @@
+use std::fs;
+use std::path::Path;
+
+use crate::hwmon::{get_hwmon_chip_name, get_hwmon_device_name, get_hwmon_drive_name, get_hwmon_indexes, get_hwmon_sensor_name, read_trimmed};
+
+pub const THERMAL_ZONES_DIRECTORY: &str = "/sys/class/thermal";
+
+// типи теплових зон, що описують сам процесор, їхні точки належать пристрою "cpu"
+const CPU_THERMAL_ZONE_TYPES: [&str; 3] = ["x86_pkg_temp", "cpu-thermal", "cpu_thermal"];
+
+// вимкнені точки ядро показує як 0 або від'ємне значення, а деякі прошивки пишуть явно вигадані числа
+const TRIP_POINT_CELSIUS_RANGE: (f32, f32) = (20.0, 150.0);
+
+// якщо відома лише критична точка (на ній машина вимикається), сповіщати треба трохи раніше
+const CRITICAL_TRIP_POINT_MARGIN: f32 = 5.0;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TripPointKind {
+    // теплова зона починає знижувати частоти
+    Passive,
+    Hot,
+    // на цій температурі система аварійно вимикається
+    Critical,
+    // tempN_max чипа hwmon
+    Maximum,
+    // пороги nvml: відеокарта знижує частоти або вимикається
+    Slowdown,
+    Shutdown
+}
+
+impl TripPointKind {
+    // активні точки керують вентиляторами, тому на графіку вони лише заважали б
+    pub fn from_thermal_zone_type(name: &str) -> Option<TripPointKind> {
+        match name {
+            "passive" => Some(TripPointKind::Passive),
+            "hot" => Some(TripPointKind::Hot),
+            "critical" => Some(TripPointKind::Critical),
+            _ => None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TripPointKind::Passive => "passive",
+            TripPointKind::Hot => "hot",
+            TripPointKind::Critical => "critical",
+            TripPointKind::Maximum => "maximum",
+            TripPointKind::Slowdown => "slowdown",
+            TripPointKind::Shutdown => "shutdown"
+        }
+    }
+
+    pub fn is_critical(&self) -> bool {
+        matches!(self, TripPointKind::Critical | TripPointKind::Shutdown)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TripPoint {
+    pub device: String,
+    // None - точка стосується всіх сенсорів пристрою, як у теплової зони
+    pub sensor: Option<String>,
+    pub kind: TripPointKind,
+    pub celsius: f32
+}
+
+impl TripPoint {
+    pub fn applies_to(&self, device: &str, sensor: &str) -> bool {
+        self.device == device && self.sensor.as_deref().map(|trip_sensor| trip_sensor == sensor).unwrap_or(true)
+    }
+}
+
+fn read_millidegrees(path: &Path) -> Option<f32> {
+    let celsius = read_trimmed(path)?.parse::<f32>().ok()? / 1000.0;
+
+    if (TRIP_POINT_CELSIUS_RANGE.0..=TRIP_POINT_CELSIUS_RANGE.1).contains(&celsius) {
+        Some(celsius)
+    } else {
+        None
+    }
+}
+
+// thermal_zoneN/trip_point_M_temp та trip_point_M_type
+pub fn read_thermal_zone_trip_points(thermal_directory: &Path) -> Vec<TripPoint> {
+    let mut zones: Vec<String> = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(thermal_directory) {
+        for entry in entries.flatten() {
+            let zone = entry.file_name().to_string_lossy().to_string();
+
+            if zone.starts_with("thermal_zone") {
+                zones.push(zone);
+            }
+        }
+    }
+
+    zones.sort();
+
+    let mut result_arr = Vec::new();
+
+    for zone in &zones {
+        let directory = thermal_directory.join(zone);
+
+        let zone_type = read_trimmed(&directory.join("type")).unwrap_or(zone.clone());
+
+        // решта зон (acpitz, iwlwifi_1, ...) мають чип hwmon з такою самою назвою, тож пристрій збігається
+        let device = if CPU_THERMAL_ZONE_TYPES.contains(&zone_type.as_str()) {
+            "cpu".to_string()
+        } else {
+            zone_type
+        };
+
+        for index in get_hwmon_indexes(&directory, "trip_point_", "_temp").unwrap_or_default() {
+            let kind = match read_trimmed(&directory.join(format!("trip_point_{}_type", index))).as_deref().and_then(TripPointKind::from_thermal_zone_type) {
+                Some(kind) => kind,
+                None => continue
+            };
+
+            if let Some(celsius) = read_millidegrees(&directory.join(format!("trip_point_{}_temp", index))) {
+                result_arr.push(TripPoint {
+                    device: device.clone(),
+                    sensor: None,
+                    kind,
+                    celsius
+                });
+            }
+        }
+    }
+
+    result_arr
+}
+
+// tempN_max та tempN_crit одного чипа hwmon, пристрій і сенсор названі так само, як температура
+pub fn read_hwmon_trip_points(directory: &Path, cpu_package: Option<usize>) -> Vec<TripPoint> {
+    let mut result_arr = Vec::new();
+
+    let chip_name = get_hwmon_chip_name(directory);
+
+    let drive_name = get_hwmon_drive_name(directory, &chip_name);
+
+    let device = get_hwmon_device_name(&chip_name, drive_name.as_deref());
+
+    for index in get_hwmon_indexes(directory, "temp", "_input").unwrap_or_default() {
+        let label = read_trimmed(&directory.join(format!("temp{}_label", index)))
+            .unwrap_or(format!("temp{}", index));
+
+        let sensor = get_hwmon_sensor_name(&label, cpu_package);
+
+        for (suffix, kind) in [("max", TripPointKind::Maximum), ("crit", TripPointKind::Critical)] {
+            if let Some(celsius) = read_millidegrees(&directory.join(format!("temp{}_{}", index, suffix))) {
+                result_arr.push(TripPoint {
+                    device: device.clone(),
+                    sensor: Some(sensor.clone()),
+                    kind,
+                    celsius
+                });
+            }
+        }
+    }
+
+    result_arr
+}
+
+// найнижча точка, з якої пристрій уже щось робить із нагрівом; без таких точок - критична з запасом
+pub fn get_default_alert_threshold(trip_points: &[TripPoint], device: &str) -> Option<f32> {
+    let lowest = |is_critical: bool| trip_points
+        .iter()
+        .filter(|trip_point| trip_point.device == device && trip_point.kind.is_critical() == is_critical)
+        .map(|trip_point| trip_point.celsius)
+        .reduce(f32::min);
+
+    match lowest(false) {
+        Some(celsius) => Some(celsius),
+        None => lowest(true).map(|celsius| celsius - CRITICAL_TRIP_POINT_MARGIN)
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use fs2::FileExt;
+
+use crate::sensors::{SensorKind, SensorReading};
+use crate::session_recording::get_current_unix_timestamp;
+
+pub struct StorageTier {
+    pub file_name: &'static str,
+    pub bucket_seconds: u64,
+    pub retention_seconds: u64
+}
+
+// сирі значення зберігаються лише в пам'яті (History), а на диск потрапляють вже агреговані кошики
+pub const STORAGE_TIERS: [StorageTier; 3] = [
+    StorageTier { file_name: "tier_10s.tsv", bucket_seconds: 10, retention_seconds: 2 * 24 * 60 * 60 },
+    StorageTier { file_name: "tier_1m.tsv", bucket_seconds: 60, retention_seconds: 14 * 24 * 60 * 60 },
+    StorageTier { file_name: "tier_10m.tsv", bucket_seconds: 600, retention_seconds: 365 * 24 * 60 * 60 }
+];
+
+// більше точок на графіку все одно не розрізнити, тому для періоду обирається найдрібніший рівень, що в це вкладається
+const MAX_POINTS_PER_QUERY: u64 = 2000;
+
+// вікно, термінальний інтерфейс і режим без вікна можуть працювати одночасно з тією самою текою,
+// тому писати в неї може лише процес, що тримає блокування цього файлу, а решта її лише читають
+const LOCK_FILE_NAME: &str = "storage.lock";
+
+// застарілі рядки вирізаються, коли їх набирається на 1/PRUNE_FRACTION терміну зберігання рівня
+const PRUNE_FRACTION: u64 = 24;
+
+type SeriesKey = (String, String, SensorKind);
+
+#[derive(Clone, Copy)]
+struct OpenBucket {
+    start: u64,
+    min: f32,
+    max: f32,
+    sum: f64,
+    count: u32
+}
+
+#[derive(Clone, Copy)]
+pub struct AggregatedPoint {
+    pub timestamp: f64,
+    pub min: f32,
+    pub max: f32,
+    pub average: f32
+}
+
+pub struct AggregatedSeries {
+    pub device: String,
+    pub sensor: String,
+    pub kind: SensorKind,
+    pub points: Vec<AggregatedPoint>
+}
+
+// індекс файлу рівня: (початок кошика, зсув рядка) для кожного рядка, що відкриває новіший за всі попередні кошик,
+// тож усі рядки до цього зсуву старші за цей початок, і запит читає файл лише з потрібного місця
+struct TierFile {
+    path: PathBuf,
+    // None, якщо теку відкрито лише для читання
+    file: Option<File>,
+    length: u64,
+    index: Vec<(u64, u64)>
+}
+
+impl TierFile {
+    fn open(path: &Path, is_read_only: bool) -> io::Result<Self> {
+        let mut data = match fs::read(path) {
+            Ok(data) => data,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error)
+        };
+
+        let file = if is_read_only {
+            None
+        } else {
+            Some(OpenOptions::new().create(true).append(true).open(path)?)
+        };
+
+        // обірваний при падінні програми останній рядок відрізаю, інакше до нього приклеївся б наступний
+        if !data.is_empty() && !data.ends_with(b"\n") {
+            let length = data.iter().rposition(|byte| *byte == b'\n').map_or(0, |position| position + 1);
+
+            if let Some(file) = &file {
+                file.set_len(length as u64)?;
+            }
+
+            data.truncate(length);
+        }
+
+        let mut tier_file = Self {
+            path: path.to_path_buf(),
+            file,
+            length: 0,
+            index: Vec::new()
+        };
+
+        for line in String::from_utf8_lossy(&data).split_inclusive('\n') {
+            if let Some((_, bucket)) = parse_bucket_line(line.trim_end_matches('\n')) {
+                tier_file.add_to_index(bucket.start, tier_file.length);
+            }
+
+            tier_file.length += line.len() as u64;
+        }
+
+        Ok(tier_file)
+    }
+
+    fn add_to_index(&mut self, bucket_start: u64, offset: u64) {
+        match self.index.last() {
+            Some((last_start, _)) if *last_start >= bucket_start => {},
+            _ => self.index.push((bucket_start, offset))
+        }
+    }
+
+    // зсув, до якого всі рядки файлу старші за from_timestamp
+    fn find_offset(&self, from_timestamp: u64) -> (usize, u64) {
+        let position = self.index.partition_point(|(bucket_start, _)| *bucket_start < from_timestamp);
+
+        match self.index.get(position) {
+            Some((_, offset)) => (position, *offset),
+            None => (position, self.length)
+        }
+    }
+
+    fn append(&mut self, lines: &[(u64, String)]) -> io::Result<()> {
+        let mut data = String::new();
+
+        for (bucket_start, line) in lines {
+            self.add_to_index(*bucket_start, self.length + data.len() as u64);
+
+            data.push_str(line);
+        }
+
+        match &mut self.file {
+            Some(file) => file.write_all(data.as_bytes())?,
+            None => return Err(io::Error::new(io::ErrorKind::PermissionDenied, "storage is opened read-only"))
+        }
+
+        self.length += data.len() as u64;
+
+        Ok(())
+    }
+
+    fn read_from(&self, from_timestamp: u64) -> io::Result<String> {
+        // процес, що пише, міг уже вирізати старі рядки, і тоді зсуви з індексу вказують не туди, тому читаю з початку
+        let offset = match self.file {
+            Some(_) => self.find_offset(from_timestamp).1,
+            None => 0
+        };
+
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(offset))?;
+
+        let mut data = String::new();
+        file.read_to_string(&mut data)?;
+
+        Ok(data)
+    }
+
+    // відкидає рядки, старші за термін зберігання рівня, якщо найстаріший кошик вийшов за нього більше ніж на slack_seconds
+    fn prune(&mut self, tier: &StorageTier, slack_seconds: u64) -> io::Result<()> {
+        let oldest_timestamp = (get_current_unix_timestamp() as u64).saturating_sub(tier.retention_seconds);
+
+        match self.index.first() {
+            Some((bucket_start, _)) if *bucket_start + slack_seconds < oldest_timestamp => {},
+            _ => return Ok(())
+        }
+
+        let (position, offset) = self.find_offset(oldest_timestamp);
+
+        let mut data = Vec::new();
+
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_to_end(&mut data)?;
+
+        // пишу в тимчасовий файл і лише потім підміняю, щоб при падінні не втратити весь рівень
+        let temporary_path = self.path.with_extension("tsv.tmp");
+
+        let mut temporary_file = File::create(&temporary_path)?;
+        temporary_file.write_all(&data)?;
+        temporary_file.sync_all()?;
+
+        fs::rename(&temporary_path, &self.path)?;
+
+        self.file = Some(OpenOptions::new().append(true).open(&self.path)?);
+        self.length = data.len() as u64;
+        self.index = self.index[position..].iter().map(|(bucket_start, entry_offset)| (*bucket_start, entry_offset - offset)).collect();
+
+        Ok(())
+    }
+}
+
+pub struct TieredStorage {
+    tier_files: Vec<TierFile>,
+    open_buckets: Vec<HashMap<SeriesKey, OpenBucket>>,
+    // блокування знімається, коли файл закривається; None - тека відкрита лише для читання
+    lock_file: Option<File>
+}
+
+pub fn get_default_storage_directory() -> PathBuf {
+    if let Ok(directory) = std::env::var("XDG_DATA_HOME") {
+        return PathBuf::from(directory).join("resource_monitor");
+    }
+
+    if let Ok(directory) = std::env::var("LOCALAPPDATA") {
+        return PathBuf::from(directory).join("resource_monitor");
+    }
+
+    match std::env::var("HOME") {
+        Ok(home) => PathBuf::from(home).join(".local").join("share").join("resource_monitor"),
+        Err(_) => PathBuf::from("resource_monitor_history")
+    }
+}
+
+impl TieredStorage {
+    pub fn open(directory: &Path) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+
+        let lock_file = OpenOptions::new().create(true).truncate(false).write(true).open(directory.join(LOCK_FILE_NAME))?;
+
+        let lock_file = match lock_file.try_lock_exclusive() {
+            Ok(_) => Some(lock_file),
+            Err(error) if error.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {
+                log::info!("довгострокову історію в {} вже записує інший процес, вона відкрита лише для читання", directory.display());
+
+                None
+            },
+            Err(error) => return Err(error)
+        };
+
+        let mut tier_files = Vec::new();
+
+        for tier in STORAGE_TIERS.iter() {
+            let mut tier_file = TierFile::open(&directory.join(tier.file_name), lock_file.is_none())?;
+
+            if lock_file.is_some() {
+                tier_file.prune(tier, 0)?;
+            }
+
+            tier_files.push(tier_file);
+        }
+
+        Ok(Self {
+            tier_files,
+            open_buckets: STORAGE_TIERS.iter().map(|_| HashMap::new()).collect(),
+            lock_file
+        })
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.lock_file.is_none()
+    }
+
+    // у режимі лише для читання значення пропускаються, їх уже записує процес, що тримає блокування
+    pub fn push_sample(&mut self, timestamp: f64, readings: &[SensorReading]) -> io::Result<()> {
+        if self.is_read_only() {
+            return Ok(());
+        }
+
+        for (tier_index, tier) in STORAGE_TIERS.iter().enumerate() {
+            let bucket_start = timestamp as u64 / tier.bucket_seconds * tier.bucket_seconds;
+
+            let mut closed_lines = Vec::new();
+
+            for reading in readings {
+                if reading.value.is_nan() {
+                    continue;
+                }
+
+                let key = (reading.device.clone(), reading.sensor.clone(), reading.kind);
+
+                let bucket = self.open_buckets[tier_index].entry(key.clone()).or_insert(OpenBucket {
+                    start: bucket_start,
+                    min: f32::MAX,
+                    max: f32::MIN,
+                    sum: 0.0,
+                    count: 0
+                });
+
+                // значення вже належить до наступного кошика, тому попередній можна дописати у файл
+                if bucket.start != bucket_start {
+                    if bucket.count > 0 {
+                        closed_lines.push((bucket.start, format_bucket_line(&key, bucket)));
+                    }
+
+                    *bucket = OpenBucket { start: bucket_start, min: f32::MAX, max: f32::MIN, sum: 0.0, count: 0 };
+                }
+
+                bucket.min = f32::min(bucket.min, reading.value);
+                bucket.max = f32::max(bucket.max, reading.value);
+                bucket.sum += reading.value as f64;
+                bucket.count += 1;
+            }
+
+            if !closed_lines.is_empty() {
+                self.tier_files[tier_index].append(&closed_lines)?;
+                self.tier_files[tier_index].prune(tier, tier.retention_seconds / PRUNE_FRACTION)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // дописує ще не закриті кошики, щоб при виході не втратити останні хвилини; після перезапуску
+    // кошик з тим самим початком запишеться ще раз, і query об'єднає обидва рядки
+    pub fn flush_open_buckets(&mut self) -> io::Result<()> {
+        for (tier_index, open_buckets) in self.open_buckets.iter_mut().enumerate() {
+            let mut lines: Vec<(u64, String)> = open_buckets
+                .iter()
+                .filter(|(_, bucket)| bucket.count > 0)
+                .map(|(key, bucket)| (bucket.start, format_bucket_line(key, bucket)))
+                .collect();
+
+            lines.sort_by_key(|(bucket_start, _)| *bucket_start);
+
+            open_buckets.clear();
+
+            if !lines.is_empty() {
+                self.tier_files[tier_index].append(&lines)?;
+
+                if let Some(file) = &self.tier_files[tier_index].file {
+                    file.sync_data()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn choose_tier(period_seconds: u64) -> usize {
+        STORAGE_TIERS
+            .iter()
+            .position(|tier| period_seconds / tier.bucket_seconds <= MAX_POINTS_PER_QUERY)
+            .unwrap_or(STORAGE_TIERS.len() - 1)
+    }
+
+    pub fn query(&self, period_seconds: u64) -> io::Result<Vec<AggregatedSeries>> {
+        let tier_index = Self::choose_tier(period_seconds);
+
+        let from_timestamp = (get_current_unix_timestamp() as u64).saturating_sub(period_seconds);
+
+        let mut buckets: Vec<(SeriesKey, Vec<OpenBucket>)> = Vec::new();
+
+        let mut push_bucket = |key: &SeriesKey, bucket: OpenBucket| {
+            let series_buckets = match buckets.iter().position(|(series_key, _)| series_key == key) {
+                Some(index) => &mut buckets[index].1,
+                None => {
+                    buckets.push((key.clone(), Vec::new()));
+
+                    &mut buckets.last_mut().unwrap().1
+                }
+            };
+
+            // кошик, дописаний при виході, і продовження того самого кошика після перезапуску
+            match series_buckets.iter_mut().rev().find(|series_bucket| series_bucket.start == bucket.start) {
+                Some(series_bucket) => {
+                    series_bucket.min = f32::min(series_bucket.min, bucket.min);
+                    series_bucket.max = f32::max(series_bucket.max, bucket.max);
+                    series_bucket.sum += bucket.sum;
+                    series_bucket.count += bucket.count;
+                },
+                None => series_buckets.push(bucket)
+            }
+        };
+
+        let data = self.tier_files[tier_index].read_from(from_timestamp)?;
+
+        for line in data.lines() {
+            if let Some((key, bucket)) = parse_bucket_line(line) {
+                if bucket.start >= from_timestamp {
+                    push_bucket(&key, bucket);
+                }
+            }
+        }
+
+        // ще не закриті кошики теж показую, інакше останні хвилини на графіку були б порожні
+        for (key, bucket) in self.open_buckets[tier_index].iter() {
+            if bucket.count > 0 && bucket.start >= from_timestamp {
+                push_bucket(key, *bucket);
+            }
+        }
+
+        Ok(buckets
+            .into_iter()
+            .map(|(key, series_buckets)| AggregatedSeries {
+                device: key.0,
+                sensor: key.1,
+                kind: key.2,
+                points: series_buckets.iter().map(bucket_to_point).collect()
+            })
+            .collect())
+    }
+}
+
+impl Drop for TieredStorage {
+    fn drop(&mut self) {
+        if let Err(error) = self.flush_open_buckets() {
+            log::warn!("не вдалося записати довгострокову історію: {}", error);
+        }
+    }
+}
+
+fn bucket_to_point(bucket: &OpenBucket) -> AggregatedPoint {
+    AggregatedPoint {
+        timestamp: bucket.start as f64,
+        min: bucket.min,
+        max: bucket.max,
+        average: (bucket.sum / bucket.count as f64) as f32
+    }
+}
+
+// рядок: початок кошика, вид сенсора, device, sensor, min, max, сума, кількість (через табуляцію)
+fn format_bucket_line(key: &SeriesKey, bucket: &OpenBucket) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+        bucket.start,
+        key.2.to_id(),
+        key.0.replace('\t', " "),
+        key.1.replace('\t', " "),
+        bucket.min,
+        bucket.max,
+        bucket.sum,
+        bucket.count
+    )
+}
+
+// обірваний при падінні програми останній рядок просто не розбирається і пропускається
+fn parse_bucket_line(line: &str) -> Option<(SeriesKey, OpenBucket)> {
+    let fields: Vec<&str> = line.split('\t').collect();
+
+    if fields.len() != 8 {
+        return None;
+    }
+
+    let kind = SensorKind::from_id(fields[1].parse().ok()?)?;
+
+    Some((
+        (fields[2].to_string(), fields[3].to_string(), kind),
+        OpenBucket {
+            start: fields[0].parse().ok()?,
+            min: fields[4].parse().ok()?,
+            max: fields[5].parse().ok()?,
+            sum: fields[6].parse().ok()?,
+            count: fields[7].parse().ok()?
+        }
+    ))
+}
@@ -0,0 +1,148 @@
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MeasurementUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Percent,
+    Watt,
+    Milliwatt,
+    Byte,
+    Rpm,
+    Megahertz,
+    BytePerSecond,
+    OperationsPerSecond,
+    PacketsPerSecond,
+    Volt,
+    // безрозмірне число, наприклад середнє навантаження чи кількість процесів
+    Number,
+    PerSecond,
+    // 1 - стан увімкнено (наприклад тротлінг), 0 - вимкнено
+    Flag
+}
+
+// одиниці, між якими користувач може обирати для температур
+pub const TEMPERATURE_UNITS: [MeasurementUnit; 3] = [MeasurementUnit::Celsius, MeasurementUnit::Fahrenheit, MeasurementUnit::Kelvin];
+
+const BYTE_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+impl MeasurementUnit {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            MeasurementUnit::Celsius => "°C",
+            MeasurementUnit::Fahrenheit => "°F",
+            MeasurementUnit::Kelvin => "K",
+            MeasurementUnit::Percent => "%",
+            MeasurementUnit::Watt => "W",
+            MeasurementUnit::Milliwatt => "mW",
+            MeasurementUnit::Byte => "B",
+            MeasurementUnit::Rpm => "RPM",
+            MeasurementUnit::Megahertz => "MHz",
+            MeasurementUnit::BytePerSecond => "B/s",
+            MeasurementUnit::OperationsPerSecond => "IOPS",
+            MeasurementUnit::PacketsPerSecond => "pkt/s",
+            MeasurementUnit::Volt => "V",
+            MeasurementUnit::Number => "",
+            MeasurementUnit::PerSecond => "/s",
+            MeasurementUnit::Flag => ""
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MeasurementUnit::Celsius => "celsius",
+            MeasurementUnit::Fahrenheit => "fahrenheit",
+            MeasurementUnit::Kelvin => "kelvin",
+            MeasurementUnit::Percent => "percent",
+            MeasurementUnit::Watt => "watt",
+            MeasurementUnit::Milliwatt => "milliwatt",
+            MeasurementUnit::Byte => "byte",
+            MeasurementUnit::Rpm => "rpm",
+            MeasurementUnit::Megahertz => "megahertz",
+            MeasurementUnit::BytePerSecond => "byte_per_second",
+            MeasurementUnit::OperationsPerSecond => "operations_per_second",
+            MeasurementUnit::PacketsPerSecond => "packets_per_second",
+            MeasurementUnit::Volt => "volt",
+            MeasurementUnit::Number => "number",
+            MeasurementUnit::PerSecond => "per_second",
+            MeasurementUnit::Flag => "flag"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<MeasurementUnit> {
+        match name.to_lowercase().as_str() {
+            "celsius" | "c" => Some(MeasurementUnit::Celsius),
+            "fahrenheit" | "f" => Some(MeasurementUnit::Fahrenheit),
+            "kelvin" | "k" => Some(MeasurementUnit::Kelvin),
+            "percent" => Some(MeasurementUnit::Percent),
+            "watt" => Some(MeasurementUnit::Watt),
+            "milliwatt" => Some(MeasurementUnit::Milliwatt),
+            "byte" => Some(MeasurementUnit::Byte),
+            "rpm" => Some(MeasurementUnit::Rpm),
+            "megahertz" => Some(MeasurementUnit::Megahertz),
+            "byte_per_second" => Some(MeasurementUnit::BytePerSecond),
+            "operations_per_second" => Some(MeasurementUnit::OperationsPerSecond),
+            "packets_per_second" => Some(MeasurementUnit::PacketsPerSecond),
+            "volt" => Some(MeasurementUnit::Volt),
+            "number" => Some(MeasurementUnit::Number),
+            "per_second" => Some(MeasurementUnit::PerSecond),
+            "flag" => Some(MeasurementUnit::Flag),
+            _ => None
+        }
+    }
+
+    pub fn is_temperature(&self) -> bool {
+        TEMPERATURE_UNITS.contains(self)
+    }
+
+    // температури перераховуються через градуси Цельсія, а між непов'язаними одиницями значення не змінюється
+    pub fn convert(&self, value: f32, to: MeasurementUnit) -> f32 {
+        if *self == to {
+            return value;
+        }
+
+        match (self, to) {
+            (MeasurementUnit::Milliwatt, MeasurementUnit::Watt) => return value / 1000.0,
+            (MeasurementUnit::Watt, MeasurementUnit::Milliwatt) => return value * 1000.0,
+            _ => {}
+        }
+
+        if !(self.is_temperature() && to.is_temperature()) {
+            return value;
+        }
+
+        let celsius = match self {
+            MeasurementUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            MeasurementUnit::Kelvin => value - 273.15,
+            _ => value
+        };
+
+        match to {
+            MeasurementUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            MeasurementUnit::Kelvin => celsius + 273.15,
+            _ => celsius
+        }
+    }
+
+    // підпис значення разом з одиницею, байти показуються в найбільшій одиниці, де число не менше одиниці
+    pub fn format_value(&self, value: f64, precision: usize) -> String {
+        match self {
+            MeasurementUnit::Celsius | MeasurementUnit::Fahrenheit | MeasurementUnit::Percent | MeasurementUnit::Flag | MeasurementUnit::Number | MeasurementUnit::PerSecond => format!("{:.*}{}", precision, value, self.symbol()),
+            MeasurementUnit::Byte => format_bytes(value, precision),
+            MeasurementUnit::BytePerSecond => format!("{}/s", format_bytes(value, precision)),
+            MeasurementUnit::Rpm => format!("{:.*} {}", 0, value, self.symbol()),
+            _ => format!("{:.*} {}", precision, value, self.symbol())
+        }
+    }
+}
+
+fn format_bytes(value: f64, precision: usize) -> String {
+    let mut value = value;
+    let mut index = 0;
+
+    while value.abs() >= 1024.0 && index < BYTE_UNITS.len() - 1 {
+        value /= 1024.0;
+        index += 1;
+    }
+
+    format!("{:.*} {}", precision, value, BYTE_UNITS[index])
+}
@@ -0,0 +1,36 @@
+use resource_monitor::cgroup::{parse_cpu_max, parse_io_stat, parse_own_cgroup, CgroupCounters};
+
+#[test]
+fn cpu_max_is_converted_to_cpus() {
+    assert_eq!(parse_cpu_max("200000 100000\n"), Some(2.0));
+    assert_eq!(parse_cpu_max("50000 100000\n"), Some(0.5));
+    // без квоти
+    assert_eq!(parse_cpu_max("max 100000\n"), Some(f64::INFINITY));
+    assert_eq!(parse_cpu_max("max 0\n"), None);
+    assert_eq!(parse_cpu_max(""), None);
+}
+
+#[test]
+fn io_stat_is_summed_over_devices() {
+    let mut counters = CgroupCounters::default();
+
+    parse_io_stat("8:0 rbytes=1000 wbytes=2000 rios=10 wios=20 dbytes=0 dios=0\n259:0 rbytes=500 wbytes=0 rios=5 wios=0 dbytes=4096 dios=1\n", &mut counters);
+
+    assert_eq!(counters, CgroupCounters {
+        cpu_usage_microseconds: 0,
+        read_bytes: 1500,
+        written_bytes: 2000,
+        read_operations: 15,
+        write_operations: 20
+    });
+}
+
+#[test]
+fn own_cgroup_is_the_unified_hierarchy_line() {
+    assert_eq!(parse_own_cgroup("0::/user.slice/user-1000.slice/session-2.scope\n"), Some("/user.slice/user-1000.slice/session-2.scope".to_string()));
+    // корінь ієрархії, наприклад у контейнері з власним простором імен cgroup
+    assert_eq!(parse_own_cgroup("0::/\n"), Some("/".to_string()));
+    // гібридна ієрархія: рядки cgroup v1 пропускаються
+    assert_eq!(parse_own_cgroup("12:cpu,cpuacct:/user.slice\n1:name=systemd:/user.slice\n0::/user.slice\n"), Some("/user.slice".to_string()));
+    assert_eq!(parse_own_cgroup("12:cpu,cpuacct:/user.slice\n"), None);
+}
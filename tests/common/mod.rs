@@ -0,0 +1,39 @@
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+// каталоги sysfs та procfs відтворюються у тимчасовій теці, яка видаляється разом з Fixture, навіть якщо перевірка не пройшла
+pub struct Fixture {
+    directory: PathBuf
+}
+
+impl Deref for Fixture {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.directory
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+pub fn create_fixture(name: &str, files: &[(&str, &str)]) -> Fixture {
+    let directory = std::env::temp_dir().join(format!("resource_monitor_{}_{}", name, std::process::id()));
+
+    let _ = fs::remove_dir_all(&directory);
+
+    fs::create_dir_all(&directory).unwrap();
+
+    for (path, content) in files {
+        let path = directory.join(path);
+
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    Fixture { directory }
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::cpu_frequency::{get_cpu_frequencies, get_cpu_throttle_counts, CpuThrottleCounts};
+
+#[test]
+fn throttle_counts_are_read_once_per_core_and_package() {
+    // два ядра з двома потоками в одному пакеті: лічильник пакета повторюється в усіх чотирьох теках, лічильник ядра - в обох потоках
+    let directory = create_fixture("cpu_throttle_counts", &[
+        ("cpu0/topology/physical_package_id", "0\n"),
+        ("cpu0/topology/core_id", "0\n"),
+        ("cpu0/thermal_throttle/core_throttle_count", "3\n"),
+        ("cpu0/thermal_throttle/package_throttle_count", "10\n"),
+        ("cpu1/topology/physical_package_id", "0\n"),
+        ("cpu1/topology/core_id", "1\n"),
+        ("cpu1/thermal_throttle/core_throttle_count", "5\n"),
+        ("cpu1/thermal_throttle/package_throttle_count", "10\n"),
+        ("cpu2/topology/physical_package_id", "0\n"),
+        ("cpu2/topology/core_id", "0\n"),
+        ("cpu2/thermal_throttle/core_throttle_count", "3\n"),
+        ("cpu2/thermal_throttle/package_throttle_count", "10\n"),
+        ("cpu3/topology/physical_package_id", "0\n"),
+        ("cpu3/topology/core_id", "1\n"),
+        ("cpu3/thermal_throttle/core_throttle_count", "5\n"),
+        ("cpu3/thermal_throttle/package_throttle_count", "10\n"),
+        ("cpufreq/boost", "1\n")
+    ]);
+
+    assert_eq!(get_cpu_throttle_counts(&directory), Some(CpuThrottleCounts { core_count: 8, package_count: 10 }));
+}
+
+#[test]
+fn throttle_counts_are_missing_without_the_driver() {
+    let directory = create_fixture("cpu_throttle_counts_missing", &[
+        ("cpu0/cpufreq/scaling_cur_freq", "3600000\n"),
+        ("cpu1/cpufreq/scaling_cur_freq", "800000\n")
+    ]);
+
+    assert_eq!(get_cpu_throttle_counts(&directory), None);
+    assert_eq!(get_cpu_frequencies(&directory), vec![("cpu0".to_string(), 3600.0), ("cpu1".to_string(), 800.0)]);
+}
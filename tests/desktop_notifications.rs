@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use resource_monitor::desktop_notifications::{DesktopNotifier, NotificationUrgency};
+use zbus::blocking::ConnectionBuilder;
+use zbus::zvariant::Value;
+
+// окремий dbus-daemon, який зупиняється разом з тестом
+struct BusDaemon {
+    child: Child,
+    address: String
+}
+
+impl Drop for BusDaemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// None, якщо dbus-daemon не встановлений, тоді тест пропускається
+fn start_bus_daemon() -> Option<BusDaemon> {
+    let mut child = match Command::new("dbus-daemon")
+        .args(["--session", "--print-address", "--nofork"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn() {
+        Ok(child) => child,
+        Err(_) => return None
+    };
+
+    let mut address = String::new();
+
+    BufReader::new(child.stdout.take()?).read_line(&mut address).ok()?;
+
+    Some(BusDaemon {
+        child,
+        address: address.trim().to_string()
+    })
+}
+
+struct ReceivedNotification {
+    replaces_id: u32,
+    summary: String,
+    body: String,
+    urgency: u8
+}
+
+// замінник демона сповіщень: запам'ятовує кожен виклик Notify
+struct NotificationServer {
+    sender: Mutex<Sender<ReceivedNotification>>,
+    next_id: Mutex<u32>
+}
+
+#[zbus::dbus_interface(name = "org.freedesktop.Notifications")]
+impl NotificationServer {
+    #[allow(clippy::too_many_arguments)]
+    fn notify(&self, _app_name: &str, replaces_id: u32, _app_icon: &str, summary: &str, body: &str, _actions: Vec<&str>, hints: HashMap<&str, Value<'_>>, _expire_timeout: i32) -> u32 {
+        let urgency = match hints.get("urgency") {
+            Some(Value::U8(urgency)) => *urgency,
+            _ => u8::MAX
+        };
+
+        let _ = self.sender.lock().unwrap().send(ReceivedNotification {
+            replaces_id,
+            summary: summary.to_string(),
+            body: body.to_string(),
+            urgency
+        });
+
+        let mut next_id = self.next_id.lock().unwrap();
+        *next_id += 1;
+
+        *next_id
+    }
+}
+
+#[test]
+fn notify_reaches_the_notification_daemon() {
+    let daemon = match start_bus_daemon() {
+        Some(daemon) => daemon,
+        None => {
+            eprintln!("dbus-daemon не знайдено, тест пропущено");
+
+            return;
+        }
+    };
+
+    let (sender, receiver) = channel();
+
+    let server = NotificationServer {
+        sender: Mutex::new(sender),
+        next_id: Mutex::new(0)
+    };
+
+    let _server_connection = ConnectionBuilder::address(daemon.address.as_str()).unwrap()
+        .name("org.freedesktop.Notifications").unwrap()
+        .serve_at("/org/freedesktop/Notifications", server).unwrap()
+        .build()
+        .unwrap();
+
+    let mut notifier = DesktopNotifier::with_address(&daemon.address);
+    notifier.urgency = NotificationUrgency::Normal;
+    notifier.deduplication_interval = Duration::ZERO;
+
+    notifier.notify("CPU", "CPU: critical temperature", "96.0°C (threshold 95°C)");
+
+    let first = receiver.recv_timeout(Duration::from_secs(10)).expect("Notify was not received");
+
+    assert_eq!(first.summary, "CPU: critical temperature");
+    assert_eq!(first.body, "96.0°C (threshold 95°C)");
+    assert_eq!(first.urgency, 1);
+    assert_eq!(first.replaces_id, 0);
+
+    // повторне сповіщення з тим самим ключем замінює попереднє
+    notifier.notify("CPU", "CPU: critical temperature", "97.0°C (threshold 95°C)");
+
+    let second = receiver.recv_timeout(Duration::from_secs(10)).expect("second Notify was not received");
+
+    assert_eq!(second.body, "97.0°C (threshold 95°C)");
+    assert_eq!(second.replaces_id, 1);
+}
+
+#[test]
+fn repeated_notifications_are_deduplicated() {
+    let daemon = match start_bus_daemon() {
+        Some(daemon) => daemon,
+        None => {
+            eprintln!("dbus-daemon не знайдено, тест пропущено");
+
+            return;
+        }
+    };
+
+    let (sender, receiver) = channel();
+
+    let server = NotificationServer {
+        sender: Mutex::new(sender),
+        next_id: Mutex::new(0)
+    };
+
+    let _server_connection = ConnectionBuilder::address(daemon.address.as_str()).unwrap()
+        .name("org.freedesktop.Notifications").unwrap()
+        .serve_at("/org/freedesktop/Notifications", server).unwrap()
+        .build()
+        .unwrap();
+
+    let mut notifier = DesktopNotifier::with_address(&daemon.address);
+    notifier.deduplication_interval = Duration::from_secs(3600);
+
+    notifier.notify("GPU", "GPU: critical temperature", "91.0°C (threshold 90°C)");
+    notifier.notify("GPU", "GPU: critical temperature", "92.0°C (threshold 90°C)");
+    notifier.notify("CPU", "CPU: critical temperature", "96.0°C (threshold 95°C)");
+
+    let first = receiver.recv_timeout(Duration::from_secs(10)).expect("Notify was not received");
+    let second = receiver.recv_timeout(Duration::from_secs(10)).expect("second Notify was not received");
+
+    assert_eq!(first.body, "91.0°C (threshold 90°C)");
+    assert_eq!(second.summary, "CPU: critical temperature");
+    assert!(receiver.recv_timeout(Duration::from_millis(500)).is_err());
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::disk_statistics::{get_disk_device_name, is_physical_block_device, parse_diskstats, DiskCounters, DiskRateMeter};
+use resource_monitor::hwmon::get_hwmon_drive_name;
+
+fn counters(name: &str, reads_completed: u64, sectors_read: u64, writes_completed: u64, sectors_written: u64) -> DiskCounters {
+    DiskCounters {
+        name: name.to_string(),
+        reads_completed,
+        sectors_read,
+        writes_completed,
+        sectors_written
+    }
+}
+
+#[test]
+fn diskstats_lines_are_parsed_and_short_lines_skipped() {
+    let data = "   8       0 sda 1200 30 96000 500 400 10 16000 900 0 1100 1400\n   8       1 sda1 10 0\n 259       0 nvme0n1 50 0 4000 20 60 0 8000 30 0 40 50 0 0 0 0\n";
+
+    assert_eq!(parse_diskstats(data), vec![
+        counters("sda", 1200, 96000, 400, 16000),
+        counters("nvme0n1", 50, 4000, 60, 8000)
+    ]);
+}
+
+#[test]
+fn virtual_block_devices_are_skipped() {
+    let directory = create_fixture("disk_statistics_devices", &[
+        ("sda/size", "0\n"),
+        ("dm-0/size", "0\n"),
+        ("md127/size", "0\n"),
+        ("loop0/size", "0\n"),
+        ("zram0/size", "0\n")
+    ]);
+
+    assert!(is_physical_block_device(&directory, "sda"));
+    assert!(!is_physical_block_device(&directory, "sda1"));
+    assert!(!is_physical_block_device(&directory, "dm-0"));
+    assert!(!is_physical_block_device(&directory, "md127"));
+    assert!(!is_physical_block_device(&directory, "loop0"));
+    assert!(!is_physical_block_device(&directory, "zram0"));
+}
+
+#[test]
+fn identical_drives_get_distinct_device_names() {
+    let directory = create_fixture("disk_statistics_models", &[
+        ("sda/device/model", "Samsung SSD 870\n"),
+        ("sdb/device/model", "Samsung SSD 870\n"),
+        ("nvme0n1/size", "0\n")
+    ]);
+
+    assert_eq!(get_disk_device_name(&directory, "sda"), "Samsung SSD 870 (sda)");
+    assert_eq!(get_disk_device_name(&directory, "sdb"), "Samsung SSD 870 (sdb)");
+    assert_eq!(get_disk_device_name(&directory, "nvme0n1"), "nvme0n1");
+}
+
+#[test]
+fn diskstats_and_drive_temperatures_share_the_device_name() {
+    // /sys/block/<name>/device веде до того самого пристрою, що й device у теці hwmon
+    let directory = create_fixture("disk_statistics_hwmon_names", &[
+        ("block/sda/device/model", "Samsung SSD 870\n"),
+        ("block/nvme0n1/device/model", "WD Blue SN570\n"),
+        ("drivetemp/name", "drivetemp\n"),
+        ("drivetemp/device/model", "Samsung SSD 870\n"),
+        ("drivetemp/device/block/sda/size", "0\n"),
+        ("nvme/name", "nvme\n"),
+        ("nvme/device/model", "WD Blue SN570\n"),
+        ("nvme/device/nvme0n1/size", "0\n"),
+        ("nvme/device/nvme0c0n1/size", "0\n")
+    ]);
+
+    let block_directory = directory.join("block");
+
+    assert_eq!(get_hwmon_drive_name(&directory.join("drivetemp"), "drivetemp"), Some(get_disk_device_name(&block_directory, "sda")));
+    assert_eq!(get_hwmon_drive_name(&directory.join("nvme"), "nvme"), Some(get_disk_device_name(&block_directory, "nvme0n1")));
+    assert_eq!(get_disk_device_name(&block_directory, "nvme0n1"), "WD Blue SN570 (nvme0n1)");
+}
+
+#[test]
+fn rates_skip_the_first_sample_and_counter_resets() {
+    let mut meter = DiskRateMeter::default();
+
+    assert!(meter.update(vec![counters("sda", 100, 1000, 10, 200)], 10.0).is_empty());
+
+    let rates = meter.update(vec![counters("sda", 120, 3048, 30, 200)], 12.0);
+
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].read_bytes_per_second, 524288.0);
+    assert_eq!(rates[0].write_bytes_per_second, 0.0);
+    assert_eq!(rates[0].read_operations_per_second, 10.0);
+    assert_eq!(rates[0].write_operations_per_second, 10.0);
+
+    // диск під'єднали заново, лічильники почалися з нуля
+    assert!(meter.update(vec![counters("sda", 5, 40, 0, 0)], 13.0).is_empty());
+    assert_eq!(meter.update(vec![counters("sda", 7, 40, 0, 0)], 14.0)[0].read_operations_per_second, 2.0);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use std::path::Path;
+
+use common::{create_fixture, Fixture};
+use resource_monitor::energy_counters::{get_energy_delta, read_hwmon_energy_counters, read_rapl_counters, EnergyCounter, EnergyPowerMeter};
+
+// назви на кшталт intel-rapl:0 не можна тримати в репозиторії на windows, тому вони створюються під час тесту
+fn create_rapl_fixture(name: &str) -> Fixture {
+    create_fixture(name, &[
+        ("intel-rapl/enabled", "1"),
+        ("intel-rapl:0/name", "package-0"),
+        ("intel-rapl:0/energy_uj", "262143328850"),
+        ("intel-rapl:0/max_energy_range_uj", "262143328850"),
+        ("intel-rapl:0:0/name", "core"),
+        ("intel-rapl:0:0/energy_uj", "1000000"),
+        ("intel-rapl:0:0/max_energy_range_uj", "262143328850"),
+        ("intel-rapl:0:1/name", "dram"),
+        ("intel-rapl:0:1/energy_uj", "2500000"),
+        ("intel-rapl:0:1/max_energy_range_uj", "65712999613"),
+        ("intel-rapl-mmio:0/name", "package-0"),
+        ("intel-rapl-mmio:0/energy_uj", "123")
+    ])
+}
+
+#[test]
+fn rapl_zones_are_parsed_with_package_prefix() {
+    let directory = create_rapl_fixture("rapl_zones");
+
+    let counters = read_rapl_counters(&directory);
+
+    assert_eq!(counters, vec![
+        EnergyCounter { sensor: "package-0".to_string(), microjoules: 262143328850, max_microjoules: Some(262143328850) },
+        EnergyCounter { sensor: "package-0 core".to_string(), microjoules: 1000000, max_microjoules: Some(262143328850) },
+        EnergyCounter { sensor: "package-0 dram".to_string(), microjoules: 2500000, max_microjoules: Some(65712999613) }
+    ]);
+}
+
+#[test]
+fn unreadable_rapl_zone_is_skipped() {
+    // без root energy_uj недоступний, що для тесту рівнозначно його відсутності
+    let directory = create_fixture("rapl_unreadable", &[
+        ("intel-rapl:0/name", "package-0"),
+        ("intel-rapl:0/max_energy_range_uj", "262143328850"),
+        ("intel-rapl:1/name", "package-1"),
+        ("intel-rapl:1/energy_uj", "not a number")
+    ]);
+
+    assert!(read_rapl_counters(&directory).is_empty());
+}
+
+#[test]
+fn missing_powercap_directory_gives_no_counters() {
+    assert!(read_rapl_counters(Path::new("/nonexistent/powercap")).is_empty());
+}
+
+#[test]
+fn amd_energy_counters_are_parsed() {
+    let directory = create_fixture("amd_energy", &[
+        ("name", "amd_energy"),
+        ("energy1_input", "5000000"),
+        ("energy1_label", "Ecore000"),
+        ("energy17_input", "90000000"),
+        ("energy17_label", "Esocket0"),
+        ("energy2_input", "6000000")
+    ]);
+
+    let counters = read_hwmon_energy_counters(&directory);
+
+    assert_eq!(counters, vec![
+        EnergyCounter { sensor: "amd_energy Ecore000".to_string(), microjoules: 5000000, max_microjoules: None },
+        EnergyCounter { sensor: "amd_energy energy2".to_string(), microjoules: 6000000, max_microjoules: None },
+        EnergyCounter { sensor: "amd_energy Esocket0".to_string(), microjoules: 90000000, max_microjoules: None }
+    ]);
+}
+
+#[test]
+fn energy_delta_without_wraparound() {
+    assert_eq!(get_energy_delta(1000, 4000, Some(10000)), Some(3000));
+    assert_eq!(get_energy_delta(1000, 1000, None), Some(0));
+}
+
+#[test]
+fn energy_delta_handles_wraparound() {
+    assert_eq!(get_energy_delta(9000, 500, Some(10000)), Some(1500));
+    assert_eq!(get_energy_delta(262143328000, 150, Some(262143328850)), Some(1000));
+}
+
+#[test]
+fn energy_delta_without_range_is_unknown_after_reset() {
+    assert_eq!(get_energy_delta(9000, 500, None), None);
+
+    // значення більше за межу означає, що межа прочитана неправильно, тоді краще пропустити відлік
+    assert_eq!(get_energy_delta(20000, 500, Some(10000)), None);
+}
+
+#[test]
+fn power_meter_computes_watts_from_deltas() {
+    let mut meter = EnergyPowerMeter::default();
+
+    let counter = |microjoules| EnergyCounter { sensor: "package-0".to_string(), microjoules, max_microjoules: Some(262143328850) };
+
+    assert!(meter.update(vec![counter(262000000000)], 10.0).is_empty());
+
+    // 30 Дж за 0.5 секунди
+    assert_eq!(meter.update(vec![counter(262030000000)], 10.5), vec![("package-0".to_string(), 60.0)]);
+
+    // лічильник переповнився: 113328850 мкДж до межі та 6671150 після, разом 120 Дж за 1.5 секунди
+    assert_eq!(meter.update(vec![counter(6671150)], 12.0), vec![("package-0".to_string(), 80.0)]);
+}
+
+#[test]
+fn power_meter_skips_reset_counter_without_range() {
+    let mut meter = EnergyPowerMeter::default();
+
+    let counter = |microjoules| EnergyCounter { sensor: "amd_energy Esocket0".to_string(), microjoules, max_microjoules: None };
+
+    meter.update(vec![counter(90000000)], 1.0);
+
+    assert!(meter.update(vec![counter(1000)], 2.0).is_empty());
+    assert_eq!(meter.update(vec![counter(11000)], 3.0), vec![("amd_energy Esocket0".to_string(), 0.01)]);
+}
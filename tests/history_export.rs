@@ -0,0 +1,113 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::create_fixture;
+use resource_monitor::history::History;
+use resource_monitor::history_export::{export_history, write_csv, write_json, ExportFormat, ExportMetadata};
+use resource_monitor::sensors::{SensorKind, SensorReading};
+use resource_monitor::units::MeasurementUnit;
+
+fn reading(device: &str, sensor: &str, kind: SensorKind, value: f32) -> SensorReading {
+    SensorReading {
+        device: device.to_string(),
+        sensor: sensor.to_string(),
+        kind,
+        value,
+        unit: kind.unit()
+    }
+}
+
+// два значення температури та потужність, що з'явилася лише з другим значенням
+fn create_history() -> History {
+    let mut history = History::default();
+
+    history.push_sample(1000.0, &[reading("cpu", "Package id 0", SensorKind::Temperature, 50.0)], 100);
+    history.push_sample(1001.5, &[
+        reading("cpu", "Package id 0", SensorKind::Temperature, 100.0),
+        reading("cpu", "package-0, core", SensorKind::Power, 12.5)
+    ], 100);
+
+    history
+}
+
+fn metadata(temperature_unit: MeasurementUnit) -> ExportMetadata {
+    ExportMetadata {
+        host: "test-host".to_string(),
+        app_version: "0.1.0".to_string(),
+        sampling_interval_ms: 1500,
+        exported_at: 1002.0,
+        temperature_unit
+    }
+}
+
+#[test]
+fn csv_has_metadata_header_and_one_row_per_value() {
+    let mut output = Vec::new();
+
+    write_csv(&mut output, &create_history().to_recorded_series(), &metadata(MeasurementUnit::Fahrenheit)).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[..6], [
+        "# host: test-host",
+        "# app_version: 0.1.0",
+        "# sampling_interval_ms: 1500",
+        "# exported_at: 1002.000",
+        "# temperature_unit: fahrenheit",
+        "timestamp,device,sensor,kind,unit,value"
+    ]);
+
+    // температури перераховані в обрану одиницю, невідоме значення лишається порожнім, а кома в назві - в лапках
+    assert_eq!(lines[6..], [
+        "1000.000,cpu,Package id 0,temperature,°F,122",
+        "1001.500,cpu,Package id 0,temperature,°F,212",
+        "1000.000,cpu,\"package-0, core\",power,W,",
+        "1001.500,cpu,\"package-0, core\",power,W,12.5"
+    ]);
+}
+
+#[test]
+fn json_has_metadata_and_all_samples() {
+    let mut output = Vec::new();
+
+    write_json(&mut output, &create_history().to_recorded_series(), &metadata(MeasurementUnit::Celsius)).unwrap();
+
+    let document: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(document["metadata"], serde_json::json!({
+        "host": "test-host",
+        "app_version": "0.1.0",
+        "sampling_interval_ms": 1500,
+        "exported_at": 1002.0,
+        "temperature_unit": "celsius"
+    }));
+
+    let series = document["series"].as_array().unwrap();
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0]["kind"], "temperature");
+    assert_eq!(series[0]["unit"], "°C");
+    assert_eq!(series[0]["samples"], serde_json::json!([[1000.0, 50.0], [1001.5, 100.0]]));
+    assert_eq!(series[1]["sensor"], "package-0, core");
+    assert_eq!(series[1]["samples"], serde_json::json!([[1000.0, null], [1001.5, 12.5]]));
+}
+
+#[test]
+fn format_follows_the_file_extension() {
+    assert_eq!(ExportFormat::from_path(Path::new("history.JSON")), ExportFormat::Json);
+    assert_eq!(ExportFormat::from_path(Path::new("history.csv")), ExportFormat::Csv);
+    assert_eq!(ExportFormat::from_path(Path::new("history")), ExportFormat::Csv);
+
+    let directory = create_fixture("history_export_files", &[]);
+    let path = directory.join("history.json");
+
+    export_history(&path, ExportFormat::from_path(&path), &create_history().to_recorded_series(), &metadata(MeasurementUnit::Kelvin)).unwrap();
+
+    let document: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+
+    assert_eq!(document["metadata"]["temperature_unit"], "kelvin");
+    assert_eq!(document["series"][0]["unit"], "K");
+}
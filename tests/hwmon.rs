@@ -0,0 +1,39 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::hwmon::get_hwmon_fans;
+
+#[test]
+fn fans_are_read_with_and_without_labels() {
+    let directory = create_fixture("hwmon_fans", &[
+        ("hwmon0/name", "nct6798\n"),
+        ("hwmon0/fan1_input", "1250\n"),
+        ("hwmon0/fan1_label", "CPU Fan\n"),
+        ("hwmon0/pwm1", "128\n"),
+        // вентилятор без мітки та без керування
+        ("hwmon0/fan2_input", "0\n"),
+        // лише керування, тахометра немає
+        ("hwmon0/pwm3", "255\n"),
+        ("hwmon0/pwm3_enable", "1\n"),
+        ("hwmon1/name", "coretemp\n"),
+        ("hwmon1/temp1_input", "45000\n")
+    ]);
+
+    let fans = get_hwmon_fans(&directory);
+
+    assert_eq!(fans.len(), 3);
+
+    assert_eq!(fans[0].chip_name, "nct6798");
+    assert_eq!(fans[0].label, "CPU Fan");
+    assert_eq!(fans[0].rpm, Some(1250.0));
+    assert!((fans[0].duty_percent.unwrap() - 50.196).abs() < 0.001);
+
+    // зупинений вентилятор теж показується, а назва береться з номера каналу
+    assert_eq!(fans[1].label, "fan2");
+    assert_eq!(fans[1].rpm, Some(0.0));
+    assert_eq!(fans[1].duty_percent, None);
+
+    assert_eq!(fans[2].label, "fan3");
+    assert_eq!(fans[2].rpm, None);
+    assert_eq!(fans[2].duty_percent, Some(100.0));
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use resource_monitor::localization::{tr, tr_with, Language, ENGLISH_MESSAGES, LANGUAGES};
+
+fn placeholders(message: &str) -> Vec<&str> {
+    let mut result: Vec<&str> = message
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+        .collect();
+
+    result.sort();
+
+    result
+}
+
+#[test]
+fn every_key_exists_in_every_language() {
+    let english_keys: HashSet<&str> = ENGLISH_MESSAGES.iter().map(|(key, _)| *key).collect();
+
+    for language in LANGUAGES {
+        let keys: HashSet<&str> = language.messages().iter().map(|(key, _)| *key).collect();
+
+        let missing: Vec<&&str> = english_keys.difference(&keys).collect();
+        let unknown: Vec<&&str> = keys.difference(&english_keys).collect();
+
+        assert!(missing.is_empty(), "{:?} is missing {:?}", language, missing);
+        assert!(unknown.is_empty(), "{:?} has keys that English does not have: {:?}", language, unknown);
+    }
+}
+
+#[test]
+fn keys_are_not_duplicated() {
+    for language in LANGUAGES {
+        let mut keys = HashSet::new();
+
+        for (key, _) in language.messages() {
+            assert!(keys.insert(*key), "{:?} defines {} twice", language, key);
+        }
+    }
+}
+
+#[test]
+fn translations_use_the_same_placeholders() {
+    for language in LANGUAGES {
+        for (key, message) in language.messages() {
+            assert!(!message.is_empty(), "{:?} has an empty message for {}", language, key);
+            assert_eq!(placeholders(message), placeholders(tr(Language::English, key)), "{:?} / {}", language, key);
+        }
+    }
+}
+
+#[test]
+fn placeholders_are_substituted() {
+    assert_eq!(tr_with(Language::English, "plot.cpu_name", &[("name", "Ryzen 7")]), "CPU: Ryzen 7");
+    assert_eq!(tr_with(Language::Ukrainian, "plot.cpu_name", &[("name", "Ryzen 7")]), "Процесор: Ryzen 7");
+}
+
+#[test]
+fn unknown_key_falls_back_to_the_key() {
+    assert_eq!(tr(Language::Ukrainian, "no.such.key"), "no.such.key");
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use std::collections::HashSet;
+
+use common::create_fixture;
+use resource_monitor::hwmon::{get_hwmon_device_name, get_hwmon_sensor_name, get_hwmon_temperatures};
+use resource_monitor::metrics_exporter::{render_metrics, MetricsSnapshot};
+use resource_monitor::sensors::{get_gpu_sensor_name, SensorKind, SensorReading};
+use resource_monitor::units::MeasurementUnit;
+
+fn reading(device: &str, sensor: &str, kind: SensorKind, value: f32) -> SensorReading {
+    SensorReading {
+        device: device.to_string(),
+        sensor: sensor.to_string(),
+        kind,
+        value,
+        unit: kind.unit()
+    }
+}
+
+#[test]
+fn dual_socket_cpus_and_identical_gpus_get_unique_label_sets() {
+    // два процесори з однаковими мітками ядер та чип, що не належить процесору
+    let directory = create_fixture("metrics_exporter_dual_socket", &[
+        ("hwmon1/name", "coretemp\n"),
+        ("hwmon1/temp1_input", "45000\n"),
+        ("hwmon1/temp1_label", "Package id 0\n"),
+        ("hwmon1/temp2_input", "43000\n"),
+        ("hwmon1/temp2_label", "Core 0\n"),
+        ("hwmon2/name", "acpitz\n"),
+        ("hwmon2/temp1_input", "30000\n"),
+        ("hwmon3/name", "coretemp\n"),
+        ("hwmon3/temp1_input", "47000\n"),
+        ("hwmon3/temp1_label", "Package id 1\n"),
+        ("hwmon3/temp2_input", "44000\n"),
+        ("hwmon3/temp2_label", "Core 0\n")
+    ]);
+
+    let mut readings: Vec<SensorReading> = get_hwmon_temperatures(&directory)
+        .into_iter()
+        .map(|temperature| SensorReading {
+            device: get_hwmon_device_name(&temperature.chip_name, temperature.drive_name.as_deref()),
+            sensor: get_hwmon_sensor_name(&temperature.label, temperature.cpu_package),
+            kind: SensorKind::Temperature,
+            value: temperature.celsius,
+            unit: MeasurementUnit::Celsius
+        })
+        .collect();
+
+    assert_eq!(readings.len(), 5);
+    assert!(readings.iter().any(|reading| reading.device == "cpu" && reading.sensor == "CPU 1 Core 0"));
+    assert!(readings.iter().any(|reading| reading.device == "acpitz" && reading.sensor == "temp1"));
+
+    for index in 0..2 {
+        let sensor = get_gpu_sensor_name(index, Some("NVIDIA GeForce RTX 4090"));
+
+        readings.push(reading("gpu", &sensor, SensorKind::Temperature, 60.0));
+        readings.push(reading("gpu", &sensor, SensorKind::Load, 90.0));
+    }
+
+    let snapshot = MetricsSnapshot {
+        readings,
+        ..Default::default()
+    };
+
+    let rendered = render_metrics(&snapshot);
+
+    let samples: Vec<&str> = rendered
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| line.rsplit_once(' ').unwrap().0)
+        .collect();
+
+    let unique_samples: HashSet<&str> = samples.iter().copied().collect();
+
+    assert_eq!(samples.len(), 12);
+    assert_eq!(unique_samples.len(), samples.len());
+}
+
+#[test]
+fn single_cpu_keeps_plain_labels() {
+    let directory = create_fixture("metrics_exporter_single_socket", &[
+        ("hwmon0/name", "k10temp\n"),
+        ("hwmon0/temp1_input", "51250\n"),
+        ("hwmon0/temp1_label", "Tctl\n")
+    ]);
+
+    let temperatures = get_hwmon_temperatures(&directory);
+
+    assert_eq!(temperatures.len(), 1);
+    assert_eq!(get_hwmon_sensor_name(&temperatures[0].label, temperatures[0].cpu_package), "Tctl");
+    assert_eq!(temperatures[0].celsius, 51.25);
+}
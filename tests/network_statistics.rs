@@ -0,0 +1,62 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::network_statistics::{is_physical_network_interface, parse_network_dev, NetworkCounters, NetworkRateMeter};
+
+fn counters(interface: &str, received_bytes: u64, received_packets: u64, transmitted_bytes: u64, transmitted_packets: u64) -> NetworkCounters {
+    NetworkCounters {
+        interface: interface.to_string(),
+        received_bytes,
+        received_packets,
+        transmitted_bytes,
+        transmitted_packets
+    }
+}
+
+#[test]
+fn network_dev_is_parsed_even_without_a_space_after_the_colon() {
+    let data = "Inter-|   Receive                                                |  Transmit\n face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n    lo: 5000 50 0 0 0 0 0 0 5000 50 0 0 0 0 0 0\n  eth0:123456789012 900 0 0 0 0 0 0 4000 30 0 0 0 0 0 0\n";
+
+    assert_eq!(parse_network_dev(data), vec![
+        counters("lo", 5000, 50, 5000, 50),
+        counters("eth0", 123456789012, 900, 4000, 30)
+    ]);
+}
+
+#[test]
+fn virtual_interfaces_are_skipped() {
+    // справжні інтерфейси мають посилання device, у lo, мостів і veth його немає
+    let directory = create_fixture("network_statistics_interfaces", &[
+        ("eth0/device/vendor", "0x8086\n"),
+        ("wlan0/device/vendor", "0x14c3\n"),
+        ("lo/mtu", "65536\n"),
+        ("br0/mtu", "1500\n"),
+        ("veth1a2b3c/mtu", "1500\n")
+    ]);
+
+    assert!(is_physical_network_interface(&directory, "eth0"));
+    assert!(is_physical_network_interface(&directory, "wlan0"));
+    assert!(!is_physical_network_interface(&directory, "lo"));
+    assert!(!is_physical_network_interface(&directory, "br0"));
+    assert!(!is_physical_network_interface(&directory, "veth1a2b3c"));
+    assert!(!is_physical_network_interface(&directory, "tun0"));
+}
+
+#[test]
+fn rates_skip_the_first_sample_and_counter_resets() {
+    let mut meter = NetworkRateMeter::default();
+
+    assert!(meter.update(vec![counters("eth0", 1000, 10, 500, 5)], 10.0).is_empty());
+
+    let rates = meter.update(vec![counters("eth0", 5000, 30, 900, 9)], 12.0);
+
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].received_bytes_per_second, 2000.0);
+    assert_eq!(rates[0].transmitted_bytes_per_second, 200.0);
+    assert_eq!(rates[0].received_packets_per_second, 10.0);
+    assert_eq!(rates[0].transmitted_packets_per_second, 2.0);
+
+    // драйвер перезавантажили, лічильники почалися з нуля
+    assert!(meter.update(vec![counters("eth0", 100, 1, 0, 0)], 13.0).is_empty());
+    assert_eq!(meter.update(vec![counters("eth0", 300, 3, 0, 0)], 14.0)[0].received_bytes_per_second, 200.0);
+}
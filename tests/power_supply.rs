@@ -0,0 +1,84 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::power_supply::read_power_supplies;
+
+fn assert_close(value: Option<f32>, expected: f32) {
+    let value = value.unwrap();
+
+    assert!((value - expected).abs() < 0.001, "{} != {}", value, expected);
+}
+
+#[test]
+fn battery_power_comes_from_power_now_or_current_and_voltage() {
+    let directory = create_fixture("power_supply_batteries", &[
+        // power_now в мкВт
+        ("BAT0/type", "Battery\n"),
+        ("BAT0/status", "Discharging\n"),
+        ("BAT0/capacity", "81\n"),
+        ("BAT0/power_now", "12500000\n"),
+        ("BAT0/voltage_now", "11400000\n"),
+        ("BAT0/cycle_count", "0\n"),
+        // лише струм і напруга, та ще й зі знаком, який драйвер обрав сам
+        ("BAT1/type", "Battery\n"),
+        ("BAT1/status", "Discharging\n"),
+        ("BAT1/charge_now", "2500000\n"),
+        ("BAT1/charge_full", "5000000\n"),
+        ("BAT1/current_now", "-1500000\n"),
+        ("BAT1/voltage_now", "12000000\n"),
+        ("BAT1/cycle_count", "312\n"),
+        ("AC/type", "Mains\n"),
+        ("AC/online", "0\n"),
+        // батарея мишки
+        ("hidpp_battery_0/type", "Battery\n"),
+        ("hidpp_battery_0/scope", "Device\n"),
+        ("hidpp_battery_0/capacity", "40\n")
+    ]);
+
+    let power_supplies = read_power_supplies(&directory);
+
+    assert_eq!(power_supplies.is_ac_online, Some(false));
+    assert_eq!(power_supplies.batteries.len(), 2);
+
+    let bat0 = &power_supplies.batteries[0];
+
+    assert_eq!(bat0.name, "BAT0");
+    assert_eq!(bat0.capacity_percent, Some(81.0));
+    assert_close(bat0.power_watts, -12.5);
+    assert_close(bat0.voltage, 11.4);
+    assert_eq!(bat0.cycle_count, None);
+
+    let bat1 = &power_supplies.batteries[1];
+
+    assert_eq!(bat1.capacity_percent, Some(50.0));
+    assert_close(bat1.power_watts, -18.0);
+    assert_eq!(bat1.cycle_count, Some(312));
+}
+
+#[test]
+fn charging_battery_has_positive_power() {
+    let directory = create_fixture("power_supply_charging", &[
+        ("BAT0/type", "Battery\n"),
+        ("BAT0/status", "Charging\n"),
+        ("BAT0/current_now", "2000000\n"),
+        ("BAT0/voltage_now", "12500000\n"),
+        ("ucsi-source-psy-USBC000/type", "USB\n"),
+        ("ucsi-source-psy-USBC000/online", "1\n")
+    ]);
+
+    let power_supplies = read_power_supplies(&directory);
+
+    assert_eq!(power_supplies.is_ac_online, Some(true));
+    assert_close(power_supplies.batteries[0].power_watts, 25.0);
+    assert_eq!(power_supplies.batteries[0].status, "Charging");
+}
+
+#[test]
+fn desktop_without_power_supplies() {
+    let directory = create_fixture("power_supply_desktop", &[]);
+
+    let power_supplies = read_power_supplies(&directory);
+
+    assert!(power_supplies.batteries.is_empty());
+    assert_eq!(power_supplies.is_ac_online, None);
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::pressure_stall::{get_cgroup_pressure_samples, get_slice_pressure_samples, get_system_pressure_samples, parse_pressure, PressureLine, PressureReading, PressureSample, PressureStallMeter};
+
+const MEMORY_PRESSURE: &str = "some avg10=1.53 avg60=0.87 avg300=0.20 total=58761459
+full avg10=0.50 avg60=0.25 avg300=0.05 total=22010234
+";
+
+// ядра до 5.13 не мають рядка full для процесора
+const OLD_KERNEL_CPU_PRESSURE: &str = "some avg10=12.00 avg60=8.50 avg300=3.10 total=913345671
+";
+
+fn line(scope: &str, avg10: f32, avg60: f32, avg300: f32, total_microseconds: u64) -> PressureLine {
+    PressureLine {
+        scope: scope.to_string(),
+        avg10,
+        avg60,
+        avg300,
+        total_microseconds
+    }
+}
+
+#[test]
+fn some_and_full_lines_are_parsed() {
+    assert_eq!(parse_pressure(MEMORY_PRESSURE), vec![
+        line("some", 1.53, 0.87, 0.20, 58761459),
+        line("full", 0.50, 0.25, 0.05, 22010234)
+    ]);
+}
+
+#[test]
+fn cpu_pressure_without_full_line_is_parsed() {
+    assert_eq!(parse_pressure(OLD_KERNEL_CPU_PRESSURE), vec![line("some", 12.0, 8.5, 3.1, 913345671)]);
+}
+
+#[test]
+fn malformed_lines_are_skipped() {
+    let data = "some avg10=abc avg60=0.87 avg300=0.20 total=58761459
+full avg10=0.50 avg60=0.25 total=22010234
+some
+full avg10=0.50 avg60=0.25 avg300=0.05 total=22010234
+";
+
+    assert_eq!(parse_pressure(data), vec![line("full", 0.50, 0.25, 0.05, 22010234)]);
+    assert!(parse_pressure("").is_empty());
+}
+
+#[test]
+fn system_pressure_is_read_for_every_resource() {
+    let directory = create_fixture("psi_system", &[
+        ("cpu", OLD_KERNEL_CPU_PRESSURE),
+        ("memory", MEMORY_PRESSURE),
+        ("io", "some avg10=0.00 avg60=0.00 avg300=0.00 total=0\nfull avg10=0.00 avg60=0.00 avg300=0.00 total=0\n")
+    ]);
+
+    let samples = get_system_pressure_samples(&directory);
+
+    let names: Vec<(String, String, String)> = samples
+        .iter()
+        .map(|sample| (sample.source.clone(), sample.resource.clone(), sample.line.scope.clone()))
+        .collect();
+
+    assert_eq!(names, vec![
+        ("pressure".to_string(), "cpu".to_string(), "some".to_string()),
+        ("pressure".to_string(), "memory".to_string(), "some".to_string()),
+        ("pressure".to_string(), "memory".to_string(), "full".to_string()),
+        ("pressure".to_string(), "io".to_string(), "some".to_string()),
+        ("pressure".to_string(), "io".to_string(), "full".to_string())
+    ]);
+}
+
+#[test]
+fn cgroup_pressure_files_are_read() {
+    let directory = create_fixture("psi_cgroup", &[
+        ("build.service/memory.pressure", MEMORY_PRESSURE),
+        ("build.service/cpu.stat", "usage_usec 100\n")
+    ]);
+
+    let samples = get_cgroup_pressure_samples(&directory.join("build.service"), "build.service");
+
+    assert_eq!(samples, vec![
+        PressureSample { source: "build.service".to_string(), resource: "memory".to_string(), line: line("some", 1.53, 0.87, 0.20, 58761459) },
+        PressureSample { source: "build.service".to_string(), resource: "memory".to_string(), line: line("full", 0.50, 0.25, 0.05, 22010234) }
+    ]);
+}
+
+#[test]
+fn only_slices_are_read_from_cgroup_root() {
+    let directory = create_fixture("psi_slices", &[
+        ("user.slice/cpu.pressure", OLD_KERNEL_CPU_PRESSURE),
+        ("system.slice/cpu.pressure", OLD_KERNEL_CPU_PRESSURE),
+        ("init.scope/cpu.pressure", OLD_KERNEL_CPU_PRESSURE),
+        ("cpu.pressure", OLD_KERNEL_CPU_PRESSURE)
+    ]);
+
+    let sources: Vec<String> = get_slice_pressure_samples(&directory).into_iter().map(|sample| sample.source).collect();
+
+    assert_eq!(sources, vec!["system.slice".to_string(), "user.slice".to_string()]);
+}
+
+#[test]
+fn stall_percent_is_computed_from_total_delta() {
+    let mut meter = PressureStallMeter::default();
+
+    let sample = |total_microseconds| PressureSample {
+        source: "pressure".to_string(),
+        resource: "io".to_string(),
+        line: line("full", 4.0, 2.0, 1.0, total_microseconds)
+    };
+
+    let reading = |sensor: &str, percent| PressureReading {
+        source: "pressure".to_string(),
+        sensor: sensor.to_string(),
+        percent
+    };
+
+    // перший відлік - лише середні, бо приросту ще немає
+    assert_eq!(meter.update(vec![sample(1000000)], 100.0), vec![
+        reading("io full avg10", 4.0),
+        reading("io full avg60", 2.0)
+    ]);
+
+    // 250 мс простою за 0.5 секунди
+    assert_eq!(meter.update(vec![sample(1250000)], 100.5), vec![
+        reading("io full avg10", 4.0),
+        reading("io full avg60", 2.0),
+        reading("io full stall", 50.0)
+    ]);
+
+    // cgroup створили заново і лічильник почався спочатку
+    assert_eq!(meter.update(vec![sample(1000)], 101.0).len(), 2);
+}
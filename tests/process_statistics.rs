@@ -0,0 +1,50 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::cgroup::{get_cgroup_pids, parse_cgroup_procs};
+use resource_monitor::process_statistics::ProcessScanner;
+
+fn process_stat(pid: u32, name: &str, cpu_time: u64) -> String {
+    format!("{} ({}) S 1 {} {} 0 -1 4194304 100 0 0 0 {} 0 0 0 20 0 1 0 100 1000000 200\n", pid, name, pid, pid, cpu_time)
+}
+
+#[test]
+fn cgroup_pids_include_nested_cgroups() {
+    let directory = create_fixture("process_statistics_cgroup", &[
+        ("cgroup.procs", "100\n101\n"),
+        ("worker.scope/cgroup.procs", "200\n"),
+        ("worker.scope/cpu.stat", "usage_usec 0\n")
+    ]);
+
+    assert_eq!(parse_cgroup_procs("100\n\n101\n"), vec![100, 101]);
+
+    let mut pids: Vec<u32> = get_cgroup_pids(&directory).into_iter().collect();
+    pids.sort();
+
+    assert_eq!(pids, vec![100, 101, 200]);
+}
+
+#[test]
+fn scan_keeps_only_processes_of_the_selected_cgroup() {
+    let directory = create_fixture("process_statistics_scan", &[
+        ("stat", "cpu  1000 0 0 0 0 0 0 0 0 0\ncpu0 500 0 0 0 0 0 0 0 0 0\ncpu1 500 0 0 0 0 0 0 0 0 0\n"),
+        ("100/stat", &process_stat(100, "nginx: worker (1)", 50)),
+        ("100/status", "Name:\tnginx\nVmRSS:\t    2048 kB\n"),
+        ("200/stat", &process_stat(200, "kworker/0:1", 10)),
+        ("200/status", "Name:\tkworker/0:1\n")
+    ]);
+
+    let mut scanner = ProcessScanner::default();
+
+    let all_processes = scanner.scan(&directory, None);
+
+    assert_eq!(all_processes.len(), 2);
+
+    let cgroup_pids = [100].into_iter().collect();
+    let processes = scanner.scan(&directory, Some(&cgroup_pids));
+
+    assert_eq!(processes.len(), 1);
+    assert_eq!(processes[0].pid, 100);
+    assert_eq!(processes[0].name, "nginx: worker (1)");
+    assert_eq!(processes[0].rss_bytes, 2048 * 1024);
+}
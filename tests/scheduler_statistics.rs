@@ -0,0 +1,83 @@
+use resource_monitor::scheduler_statistics::{parse_loadavg, parse_scheduler_counters, SchedulerCounters, SchedulerMeter, SCHEDULER_DEVICE};
+use resource_monitor::sensors::{SensorKind, SensorReading};
+
+const PROC_STAT: &str = "cpu  10132153 290696 3084719 46828483 16683 0 25195 0 0 0
+cpu0 1393280 32966 572056 13343292 6130 0 17875 0 0 0
+intr 1462898 43 9 0 0 0 0 0 0 1 0 0 0 110 0 0 0
+ctxt 3400000
+btime 1700000000
+processes 26442
+procs_running 3
+procs_blocked 1
+softirq 1069856 0 307152 7 136548 0 0 101 320158 0 305890
+";
+
+fn find_value(readings: &[SensorReading], sensor: &str) -> Option<f32> {
+    readings.iter().find(|reading| reading.sensor == sensor).map(|reading| reading.value)
+}
+
+fn counters(context_switches: u64, interrupts: u64, forks: u64) -> SchedulerCounters {
+    SchedulerCounters {
+        context_switches,
+        interrupts,
+        forks,
+        running: 2,
+        blocked: 0
+    }
+}
+
+#[test]
+fn loadavg_takes_the_first_three_numbers() {
+    assert_eq!(parse_loadavg("0.52 0.58 0.59 2/1234 56789\n"), Some([0.52, 0.58, 0.59]));
+    assert_eq!(parse_loadavg("0.52 0.58"), None);
+    assert_eq!(parse_loadavg(""), None);
+}
+
+#[test]
+fn proc_stat_counters_are_parsed() {
+    assert_eq!(parse_scheduler_counters(PROC_STAT), SchedulerCounters {
+        context_switches: 3400000,
+        interrupts: 1462898,
+        forks: 26442,
+        running: 3,
+        blocked: 1
+    });
+
+    // у контейнері частина рядків може бути відсутня
+    assert_eq!(parse_scheduler_counters("ctxt 12\n"), SchedulerCounters { context_switches: 12, ..Default::default() });
+}
+
+#[test]
+fn meter_reports_rates_from_the_second_sample() {
+    let mut meter = SchedulerMeter::default();
+
+    let readings = meter.update(Some([1.0, 0.5, 0.25]), Some(counters(1000, 500, 10)), 100.0);
+
+    assert!(readings.iter().all(|reading| reading.device == SCHEDULER_DEVICE));
+    assert_eq!(find_value(&readings, "load 5m"), Some(0.5));
+    assert_eq!(find_value(&readings, "running"), Some(2.0));
+    assert!(readings.iter().all(|reading| reading.kind != SensorKind::SchedulerEvents));
+
+    let readings = meter.update(None, Some(counters(5000, 1500, 13)), 102.0);
+
+    assert_eq!(find_value(&readings, "load 1m"), None);
+    assert_eq!(find_value(&readings, "context switches"), Some(2000.0));
+    assert_eq!(find_value(&readings, "interrupts"), Some(500.0));
+    assert_eq!(find_value(&readings, "forks"), Some(1.5));
+}
+
+#[test]
+fn meter_skips_counter_resets_and_repeated_timestamps() {
+    let mut meter = SchedulerMeter::default();
+
+    meter.update(None, Some(counters(1000, 500, 10)), 100.0);
+
+    // той самий час - частоту не порахувати
+    assert_eq!(find_value(&meter.update(None, Some(counters(1100, 500, 10)), 100.0), "context switches"), None);
+
+    // лічильник зменшився, наприклад після відновлення знімка віртуальної машини
+    let readings = meter.update(None, Some(counters(50, 600, 11)), 101.0);
+
+    assert_eq!(find_value(&readings, "context switches"), None);
+    assert_eq!(find_value(&readings, "interrupts"), Some(100.0));
+}
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use resource_monitor::localization::Language;
+use resource_monitor::sensor_inventory::{SensorBackend, SensorInventory, SensorPreferences};
+use resource_monitor::sensors::{SensorKind, SensorReading};
+use resource_monitor::units::MeasurementUnit;
+
+fn reading(device: &str, sensor: &str, value: f32) -> SensorReading {
+    SensorReading {
+        device: device.to_string(),
+        sensor: sensor.to_string(),
+        kind: SensorKind::Temperature,
+        value,
+        unit: MeasurementUnit::Celsius
+    }
+}
+
+#[test]
+fn sensors_missing_from_the_last_read_are_kept_and_marked() {
+    let mut inventory = SensorInventory::default();
+
+    inventory.record(SensorBackend::Temperatures, Duration::from_millis(2), &[reading("cpu", "Package id 0", 45.0), reading("cpu", "Core 0", 43.0)], 0);
+    inventory.record(SensorBackend::Fans, Duration::from_millis(1), &[], 0);
+    inventory.record(SensorBackend::Temperatures, Duration::from_millis(3), &[reading("cpu", "Package id 0", 47.0)], 0);
+
+    assert_eq!(inventory.entries.len(), 2);
+
+    assert_eq!(inventory.entries[0].value, 47.0);
+    assert_eq!(inventory.entries[0].latency, Duration::from_millis(3));
+    assert!(!inventory.entries[0].is_missing);
+    assert_eq!(inventory.entries[0].state(Language::English), "ok");
+
+    // значення лишається останнім відомим
+    assert_eq!(inventory.entries[1].value, 43.0);
+    assert!(inventory.entries[1].is_missing);
+    assert_eq!(inventory.entries[1].state(Language::English), "no value");
+}
+
+#[test]
+fn other_backends_do_not_touch_the_entries() {
+    let mut inventory = SensorInventory::default();
+
+    inventory.record(SensorBackend::Temperatures, Duration::from_millis(2), &[reading("cpu", "Package id 0", 45.0)], 0);
+    inventory.record(SensorBackend::Nvml, Duration::from_millis(9), &[], 2);
+
+    assert!(!inventory.entries[0].is_missing);
+    assert_eq!(inventory.entries[0].errors, 0);
+    assert_eq!(inventory.entries[0].latency, Duration::from_millis(2));
+}
+
+#[test]
+fn backend_errors_are_shown_in_the_state() {
+    let mut inventory = SensorInventory::default();
+
+    inventory.record(SensorBackend::Nvml, Duration::from_millis(9), &[reading("gpu", "NVIDIA GeForce RTX 3080", f32::NAN)], 1);
+
+    assert!(inventory.entries[0].is_missing);
+    assert_eq!(inventory.entries[0].state(Language::English), "read errors: 1");
+}
+
+#[test]
+fn state_is_translated() {
+    let mut inventory = SensorInventory::default();
+
+    inventory.record(SensorBackend::Nvml, Duration::from_millis(9), &[reading("gpu", "NVIDIA GeForce RTX 3080", f32::NAN)], 2);
+
+    assert_eq!(inventory.entries[0].state(Language::Ukrainian), "помилок читання: 2");
+}
+
+#[test]
+fn values_are_shown_in_the_chosen_temperature_unit() {
+    let mut inventory = SensorInventory::default();
+
+    let kelvin = SensorReading {
+        unit: MeasurementUnit::Kelvin,
+        ..reading("cpu", "Thermal zone 0", 323.15)
+    };
+
+    inventory.record(SensorBackend::Temperatures, Duration::from_millis(2), &[kelvin, reading("cpu", "Core 0", f32::NAN)], 0);
+
+    assert_eq!(inventory.entries[0].format_value(MeasurementUnit::Fahrenheit), Some(MeasurementUnit::Fahrenheit.format_value(122.0, 1)));
+    assert_eq!(inventory.entries[1].format_value(MeasurementUnit::Celsius), None);
+}
+
+#[test]
+fn preferences_fall_back_to_the_default_until_changed() {
+    let mut preferences = SensorPreferences::default();
+
+    assert!(!preferences.is_plotted("cpu", "Core 3", SensorKind::Temperature, false));
+    assert_eq!(preferences.alias("cpu", "Core 3", SensorKind::Temperature), None);
+
+    assert!(!preferences.is_hidden("cpu", "Core 3", SensorKind::Temperature));
+
+    preferences.get_or_insert("cpu", "Core 3", SensorKind::Temperature, false).is_plotted = true;
+
+    assert!(preferences.is_plotted("cpu", "Core 3", SensorKind::Temperature, false));
+    assert!(!preferences.is_hidden("cpu", "Core 3", SensorKind::Temperature));
+
+    preferences.get_or_insert("cpu", "Core 4", SensorKind::Temperature, true).is_plotted = false;
+
+    // лише явно вимкнений сенсор прихований у решті видів
+    assert!(preferences.is_hidden("cpu", "Core 4", SensorKind::Temperature));
+    // порожній псевдонім означає назву за замовчуванням
+    assert_eq!(preferences.alias("cpu", "Core 3", SensorKind::Temperature), None);
+}
+
+#[test]
+fn preferences_survive_json_round_trip() {
+    let mut preferences = SensorPreferences::default();
+
+    preferences.get_or_insert("cpu", "Core 3", SensorKind::Temperature, false).is_plotted = true;
+    preferences.get_or_insert("nct6798", "fan2", SensorKind::FanSpeed, true).alias = "Front intake".to_string();
+
+    let loaded = SensorPreferences::from_json(&preferences.to_json());
+
+    assert!(loaded.is_plotted("cpu", "Core 3", SensorKind::Temperature, false));
+    assert!(loaded.is_plotted("nct6798", "fan2", SensorKind::FanSpeed, false));
+    assert_eq!(loaded.alias("nct6798", "fan2", SensorKind::FanSpeed), Some("Front intake"));
+}
+
+#[test]
+fn unknown_sensor_kinds_are_skipped_when_loading() {
+    let value = serde_json::json!([
+        {"device": "cpu", "sensor": "Core 3", "kind": "humidity", "is_plotted": true, "alias": ""},
+        {"device": "cpu", "sensor": "Core 4", "kind": "temperature", "is_plotted": true}
+    ]);
+
+    let loaded = SensorPreferences::from_json(&value);
+
+    assert_eq!(loaded.entries.len(), 1);
+    assert!(loaded.is_plotted("cpu", "Core 4", SensorKind::Temperature, false));
+}
@@ -0,0 +1,106 @@
+use resource_monitor::sensors::{SensorKind, SensorReading};
+use resource_monitor::series_statistics::{summarize_range, StatisticsSummary, StatisticsTable};
+
+fn reading(kind: SensorKind, value: f32) -> SensorReading {
+    SensorReading {
+        device: "device".to_string(),
+        sensor: "sensor".to_string(),
+        kind,
+        value,
+        unit: kind.unit()
+    }
+}
+
+fn summarize(values: &[f32]) -> StatisticsSummary {
+    let timestamps: Vec<f64> = (0..values.len()).map(|index| index as f64).collect();
+
+    summarize_range(&timestamps, values, [0.0, f64::MAX], None).unwrap()
+}
+
+fn assert_close(value: f32, expected: f32) {
+    assert!((value - expected).abs() <= expected.abs() * 0.01, "{} is not within 1% of {}", value, expected);
+}
+
+#[test]
+fn idle_network_percentile_stays_near_the_values() {
+    // простій мережі: кілька сотень байтів за секунду, а не середина кошика в мегабайти
+    let values: Vec<f32> = (0..100).map(|index| 300.0 + index as f32).collect();
+
+    let summary = summarize(&values);
+
+    assert_close(summary.p95, 394.0);
+    assert!(summary.p95 <= summary.max);
+}
+
+#[test]
+fn percentile_is_clamped_to_the_window() {
+    let summary = summarize(&[42.0; 20]);
+
+    assert_eq!(summary.min, 42.0);
+    assert_eq!(summary.max, 42.0);
+    assert_eq!(summary.p95, 42.0);
+}
+
+#[test]
+fn negative_values_keep_their_own_bins() {
+    // батарея розряджається, тож потужність від'ємна
+    let values: Vec<f32> = (0..100).map(|index| -20.0 + index as f32 * 0.1).collect();
+
+    let summary = summarize(&values);
+
+    assert_close(summary.min, -20.0);
+    assert_close(summary.p95, -10.6);
+    assert!(summary.p95 >= summary.min && summary.p95 <= summary.max);
+}
+
+#[test]
+fn percentile_of_a_wide_range_has_relative_precision() {
+    let values: Vec<f32> = (1..=100).map(|index| index as f32 * 1048576.0).collect();
+
+    assert_close(summarize(&values).p95, 95.0 * 1048576.0);
+    assert_close(summarize(&[0.0, 0.0, 0.5, 1.0]).p95, 1.0);
+}
+
+#[test]
+fn window_forgets_old_values_and_session_keeps_them() {
+    let mut table = StatisticsTable::default();
+
+    for (timestamp, value) in [(0.0, 100.0), (1.0, 20.0), (2.0, 30.0), (3.0, 10.0)] {
+        table.push_sample(timestamp, &[reading(SensorKind::Temperature, value)], 3, |_| Some(25.0));
+    }
+
+    assert_eq!(table.series.len(), 1);
+
+    let window = table.series[0].window.summary().unwrap();
+
+    assert_eq!(window.current, 10.0);
+    assert_eq!(window.min, 10.0);
+    assert_eq!(window.max, 30.0);
+    assert_eq!(window.mean, 20.0);
+    assert_close(window.p95, 30.0);
+    // вище порогу була лише секунда до значення 30
+    assert_eq!(window.seconds_above_threshold, 1.0);
+
+    let session = table.series[0].session.summary().unwrap();
+
+    assert_eq!(session.min, 10.0);
+    assert_eq!(session.max, 100.0);
+    assert_eq!(session.mean, 40.0);
+    assert_close(session.p95, 100.0);
+    assert_eq!(session.seconds_above_threshold, 1.0);
+}
+
+#[test]
+fn range_summary_skips_values_outside_the_range() {
+    let timestamps = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let values = [5.0, f32::NAN, 7.0, 9.0, 50.0];
+
+    let summary = summarize_range(&timestamps, &values, [0.5, 3.5], Some(8.0)).unwrap();
+
+    assert_eq!(summary.min, 7.0);
+    assert_eq!(summary.max, 9.0);
+    assert_eq!(summary.mean, 8.0);
+    assert_eq!(summary.seconds_above_threshold, 1.0);
+
+    assert!(summarize_range(&timestamps, &values, [10.0, 20.0], None).is_none());
+}
@@ -0,0 +1,35 @@
+use resource_monitor::sensors::SensorKind;
+use resource_monitor::series_styles::{SeriesStyles, COLOR_BLIND_PALETTE};
+
+#[test]
+fn only_edited_styles_are_saved() {
+    let mut series_styles = SeriesStyles::default();
+
+    series_styles.assign_if_missing("cpu", "Package id 0", SensorKind::Temperature);
+    series_styles.assign_if_missing("process", "firefox (4242)", SensorKind::Load);
+
+    series_styles.entries[0].style.width = 4.0;
+    series_styles.entries[0].is_edited = true;
+
+    let loaded = SeriesStyles::from_json(&series_styles.to_json());
+
+    assert_eq!(loaded.entries.len(), 1);
+    assert_eq!(loaded.entries[0].sensor, "Package id 0");
+    assert_eq!(loaded.entries[0].style.width, 4.0);
+    assert!(loaded.entries[0].is_edited);
+}
+
+#[test]
+fn palette_styles_from_old_settings_are_not_kept() {
+    // старі налаштування зберігали стиль кожного сенсора без позначки is_edited
+    let value = serde_json::json!([
+        {"device": "cpu", "sensor": "Package id 0", "kind": "temperature", "color": COLOR_BLIND_PALETTE[0], "width": 2.0, "chart_type": "line", "is_dashed": false},
+        {"device": "gpu", "sensor": "NVIDIA GeForce RTX 3080", "kind": "temperature", "color": [255, 0, 0], "width": 2.0, "chart_type": "line", "is_dashed": false}
+    ]);
+
+    let loaded = SeriesStyles::from_json(&value);
+
+    assert!(!loaded.entries[0].is_edited);
+    assert!(loaded.entries[1].is_edited);
+    assert_eq!(SeriesStyles::from_json(&loaded.to_json()).entries.len(), 1);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+use common::create_fixture;
+use resource_monitor::localization::Language;
+use resource_monitor::sensors::{SensorKind, SensorReading};
+use resource_monitor::session_recording::{format_recording_error, load_recording, SessionRecorder};
+use resource_monitor::units::MeasurementUnit;
+
+fn reading(device: &str, sensor: &str, kind: SensorKind, value: f32, unit: MeasurementUnit) -> SensorReading {
+    SensorReading {
+        device: device.to_string(),
+        sensor: sensor.to_string(),
+        kind,
+        value,
+        unit
+    }
+}
+
+#[test]
+fn recorded_samples_are_read_back() {
+    let directory = create_fixture("session_recording_round_trip", &[]);
+    let path = directory.join("session.rmrec");
+
+    {
+        let mut recorder = SessionRecorder::create(&path).unwrap();
+
+        recorder.record_sample(100.0, &[reading("cpu", "Package id 0", SensorKind::Temperature, 45.5, MeasurementUnit::Celsius)]).unwrap();
+
+        // сенсор, що з'явився посеред сесії, отримує власний запис перед першим значенням
+        recorder.record_sample(101.0, &[
+            reading("cpu", "Package id 0", SensorKind::Temperature, 47.0, MeasurementUnit::Celsius),
+            reading("gpu", "GPU 0: NVIDIA GeForce RTX 4090", SensorKind::Power, 120000.0, MeasurementUnit::Milliwatt)
+        ]).unwrap();
+    }
+
+    let recording = load_recording(&path).unwrap();
+
+    assert_eq!(recording.start_timestamp, 100.0);
+    assert_eq!(recording.end_timestamp, 101.0);
+    assert_eq!(recording.series.len(), 2);
+
+    assert_eq!(recording.series[0].device, "cpu");
+    assert_eq!(recording.series[0].sensor, "Package id 0");
+    assert_eq!(recording.series[0].kind, SensorKind::Temperature);
+    assert_eq!(recording.series[0].points, vec![[100.0, 45.5], [101.0, 47.0]]);
+
+    assert_eq!(recording.series[1].sensor, "GPU 0: NVIDIA GeForce RTX 4090");
+    assert_eq!(recording.series[1].kind, SensorKind::Power);
+    assert_eq!(recording.series[1].points, vec![[101.0, 120000.0]]);
+
+    // файл уже існує, тож новий запис його не перезапише
+    assert!(SessionRecorder::create(&path).is_err());
+}
+
+#[test]
+fn truncated_trailing_record_is_dropped() {
+    let directory = create_fixture("session_recording_truncated", &[]);
+    let path = directory.join("session.rmrec");
+
+    {
+        let mut recorder = SessionRecorder::create(&path).unwrap();
+
+        for index in 0..3 {
+            recorder.record_sample(index as f64, &[reading("cpu", "Tctl", SensorKind::Temperature, 50.0 + index as f32, MeasurementUnit::Celsius)]).unwrap();
+        }
+    }
+
+    let complete_length = fs::metadata(&path).unwrap().len();
+
+    // програма впала посеред запису четвертого значення: тип запису, час і половина кількості
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(&[2, 0, 0, 0, 0, 0, 0, 8, 64, 1, 0]).unwrap();
+    drop(file);
+
+    let recording = load_recording(&path).unwrap();
+
+    assert_eq!(recording.series.len(), 1);
+    assert_eq!(recording.series[0].points, vec![[0.0, 50.0], [1.0, 51.0], [2.0, 52.0]]);
+    assert_eq!(recording.end_timestamp, 2.0);
+
+    // кожен запис обірваний на будь-якому байті відкидається цілком
+    let data = fs::read(&path).unwrap();
+
+    for length in (complete_length as usize - 20)..data.len() {
+        fs::write(&path, &data[..length]).unwrap();
+
+        let recording = load_recording(&path).unwrap();
+
+        assert!(recording.series[0].points.len() <= 3);
+        assert!(recording.series[0].points.iter().all(|point| point[1] >= 50.0 && point[1] <= 52.0));
+    }
+}
+
+#[test]
+fn other_files_are_not_recordings() {
+    let directory = create_fixture("session_recording_foreign", &[
+        ("first_version.rmrec", "RMREC01\n"),
+        ("notes.txt", "hello")
+    ]);
+
+    for name in ["first_version.rmrec", "notes.txt"] {
+        let error = load_recording(&directory.join(name)).err().unwrap();
+
+        assert_eq!(format_recording_error(&error, Language::English), "this is not a resource monitor recording");
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::PathBuf;
+
+use resource_monitor::sensors::{SensorKind, SENSOR_KINDS};
+use resource_monitor::settings::Settings;
+
+fn write_settings(name: &str, document: serde_json::Value) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("resource_monitor_{}_{}.json", name, std::process::id()));
+
+    fs::write(&path, document.to_string()).unwrap();
+
+    path
+}
+
+#[test]
+fn kinds_missing_from_old_settings_are_appended() {
+    let path = write_settings("settings_old_panels", serde_json::json!({
+        "chart_panels": ["load", "temperature"]
+    }));
+
+    let settings = Settings::load(&path);
+
+    assert_eq!(settings.chart_panels.len(), SENSOR_KINDS.len());
+    assert_eq!(settings.chart_panels[0], SensorKind::Load);
+    assert_eq!(settings.chart_panels[1], SensorKind::Temperature);
+
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn removed_panels_stay_removed_and_new_kinds_are_appended() {
+    // файл збережено версією, яка ще не знала про process_count, а панель потужності користувач прибрав
+    let known_kinds: Vec<&str> = SENSOR_KINDS
+        .iter()
+        .filter(|kind| **kind != SensorKind::ProcessCount)
+        .map(|kind| kind.name())
+        .collect();
+
+    let chart_panels: Vec<&str> = known_kinds.iter().filter(|kind| **kind != "power").copied().collect();
+
+    let path = write_settings("settings_new_kinds", serde_json::json!({
+        "chart_panels": chart_panels,
+        "known_sensor_kinds": known_kinds
+    }));
+
+    let settings = Settings::load(&path);
+
+    assert!(!settings.chart_panels.contains(&SensorKind::Power));
+    assert_eq!(settings.chart_panels.last(), Some(&SensorKind::ProcessCount));
+
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn alert_thresholds_are_saved_only_after_being_changed() {
+    let path = write_settings("settings_alert_thresholds", serde_json::json!({}));
+
+    let mut settings = Settings::load(&path);
+
+    assert_eq!(settings.cpu_alert_threshold, None);
+    assert_eq!(settings.gpu_alert_threshold, None);
+
+    settings.cpu_alert_threshold = Some(88.0);
+    settings.save(&path).unwrap();
+
+    let loaded = Settings::load(&path);
+
+    assert_eq!(loaded.cpu_alert_threshold, Some(88.0));
+    // поріг відеокарти далі береться з точок спрацювання
+    assert_eq!(loaded.gpu_alert_threshold, None);
+
+    let _ = fs::remove_file(&path);
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use common::create_fixture;
+use resource_monitor::thermal_trip_points::{get_default_alert_threshold, read_hwmon_trip_points, read_thermal_zone_trip_points, TripPoint, TripPointKind};
+
+fn trip_point(device: &str, sensor: Option<&str>, kind: TripPointKind, celsius: f32) -> TripPoint {
+    TripPoint {
+        device: device.to_string(),
+        sensor: sensor.map(|sensor| sensor.to_string()),
+        kind,
+        celsius
+    }
+}
+
+#[test]
+fn thermal_zone_trip_points_are_read() {
+    let directory = create_fixture("thermal_zones", &[
+        ("thermal_zone0/type", "acpitz\n"),
+        ("thermal_zone0/trip_point_0_temp", "105000\n"),
+        ("thermal_zone0/trip_point_0_type", "critical\n"),
+        // активні точки керують вентиляторами й пропускаються
+        ("thermal_zone0/trip_point_1_temp", "60000\n"),
+        ("thermal_zone0/trip_point_1_type", "active\n"),
+        ("thermal_zone1/type", "x86_pkg_temp\n"),
+        ("thermal_zone1/trip_point_0_temp", "96000\n"),
+        ("thermal_zone1/trip_point_0_type", "passive\n"),
+        // вимкнена точка
+        ("thermal_zone1/trip_point_1_temp", "0\n"),
+        ("thermal_zone1/trip_point_1_type", "hot\n"),
+        ("cooling_device0/type", "Processor\n")
+    ]);
+
+    assert_eq!(read_thermal_zone_trip_points(&directory), vec![
+        trip_point("acpitz", None, TripPointKind::Critical, 105.0),
+        trip_point("cpu", None, TripPointKind::Passive, 96.0)
+    ]);
+}
+
+#[test]
+fn hwmon_limits_are_read_per_channel() {
+    let directory = create_fixture("hwmon_trip_points", &[
+        ("name", "coretemp\n"),
+        ("temp1_input", "45000\n"),
+        ("temp1_label", "Package id 0\n"),
+        ("temp1_max", "80000\n"),
+        ("temp1_crit", "100000\n"),
+        ("temp2_input", "43000\n"),
+        ("temp2_crit", "100000\n")
+    ]);
+
+    assert_eq!(read_hwmon_trip_points(&directory, None), vec![
+        trip_point("cpu", Some("Package id 0"), TripPointKind::Maximum, 80.0),
+        trip_point("cpu", Some("Package id 0"), TripPointKind::Critical, 100.0),
+        trip_point("cpu", Some("temp2"), TripPointKind::Critical, 100.0)
+    ]);
+}
+
+#[test]
+fn drive_limits_use_the_drive_model() {
+    let directory = create_fixture("hwmon_drive_trip_points", &[
+        ("name", "nvme\n"),
+        ("device/model", "Samsung SSD 980 PRO 1TB\n"),
+        ("device/nvme0n1/size", "0\n"),
+        ("temp1_input", "38850\n"),
+        ("temp1_label", "Composite\n"),
+        ("temp1_max", "82850\n"),
+        // деякі контролери повідомляють явно неможливі значення
+        ("temp1_crit", "65261850\n")
+    ]);
+
+    assert_eq!(read_hwmon_trip_points(&directory, None), vec![
+        trip_point("Samsung SSD 980 PRO 1TB (nvme0n1)", Some("Composite"), TripPointKind::Maximum, 82.85)
+    ]);
+}
+
+#[test]
+fn trip_point_without_sensor_applies_to_the_whole_device() {
+    let zone = trip_point("cpu", None, TripPointKind::Passive, 96.0);
+    let channel = trip_point("cpu", Some("Core 0"), TripPointKind::Critical, 100.0);
+
+    assert!(zone.applies_to("cpu", "Core 3"));
+    assert!(channel.applies_to("cpu", "Core 0"));
+    assert!(!channel.applies_to("cpu", "Core 3"));
+    assert!(!zone.applies_to("gpu", "Core 0"));
+}
+
+#[test]
+fn default_alert_threshold_prefers_the_lowest_warning_trip_point() {
+    let trip_points = vec![
+        trip_point("cpu", Some("Package id 0"), TripPointKind::Critical, 100.0),
+        trip_point("cpu", None, TripPointKind::Passive, 96.0),
+        trip_point("cpu", Some("Package id 0"), TripPointKind::Maximum, 80.0),
+        trip_point("gpu", Some("NVIDIA GeForce RTX 3080"), TripPointKind::Slowdown, 93.0),
+        trip_point("gpu", Some("NVIDIA GeForce RTX 3080"), TripPointKind::Shutdown, 98.0)
+    ];
+
+    assert_eq!(get_default_alert_threshold(&trip_points, "cpu"), Some(80.0));
+    assert_eq!(get_default_alert_threshold(&trip_points, "gpu"), Some(93.0));
+    assert_eq!(get_default_alert_threshold(&trip_points, "acpitz"), None);
+}
+
+#[test]
+fn default_alert_threshold_keeps_a_margin_below_critical() {
+    let trip_points = vec![
+        trip_point("cpu", None, TripPointKind::Critical, 105.0),
+        trip_point("cpu", Some("Tctl"), TripPointKind::Critical, 100.0)
+    ];
+
+    assert_eq!(get_default_alert_threshold(&trip_points, "cpu"), Some(95.0));
+}
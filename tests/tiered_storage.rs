@@ -0,0 +1,110 @@
+mod common;
+
+use std::fs;
+
+use common::create_fixture;
+use resource_monitor::sensors::{SensorKind, SensorReading};
+use resource_monitor::session_recording::get_current_unix_timestamp;
+use resource_monitor::tiered_storage::TieredStorage;
+use resource_monitor::units::MeasurementUnit;
+
+fn reading(value: f32) -> SensorReading {
+    SensorReading {
+        device: "cpu".to_string(),
+        sensor: "Package id 0".to_string(),
+        kind: SensorKind::Temperature,
+        value,
+        unit: MeasurementUnit::Celsius
+    }
+}
+
+#[test]
+fn open_buckets_are_flushed_on_drop_and_merged_after_reopening() {
+    let directory = create_fixture("tiered_storage_flush", &[]);
+
+    // середина 10-секундного кошика, щоб обидва значення потрапили в один кошик
+    let timestamp = (get_current_unix_timestamp() as u64 / 10 * 10) as f64 + 5.0;
+
+    {
+        let mut tiered_storage = TieredStorage::open(&directory).unwrap();
+
+        tiered_storage.push_sample(timestamp, &[reading(40.0)]).unwrap();
+    }
+
+    let mut tiered_storage = TieredStorage::open(&directory).unwrap();
+
+    tiered_storage.push_sample(timestamp + 1.0, &[reading(60.0)]).unwrap();
+
+    let series = tiered_storage.query(600).unwrap();
+
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0].points.len(), 1);
+    assert_eq!(series[0].points[0].min, 40.0);
+    assert_eq!(series[0].points[0].max, 60.0);
+    assert_eq!(series[0].points[0].average, 50.0);
+}
+
+#[test]
+fn old_and_truncated_lines_are_dropped_when_opening() {
+    let now = get_current_unix_timestamp() as u64 / 10 * 10;
+
+    // рядок старший за два дні зберігання рівня, звичайний рядок і рядок, обірваний падінням програми
+    let tier_data = format!(
+        "{}\t0\tcpu\tPackage id 0\t30\t30\t30\t1\n{}\t0\tcpu\tPackage id 0\t45\t47\t92\t2\n{}\t0\tcpu\tPack",
+        now - 3 * 24 * 60 * 60,
+        now - 60,
+        now - 50
+    );
+
+    let directory = create_fixture("tiered_storage_prune", &[("tier_10s.tsv", &tier_data)]);
+
+    let tiered_storage = TieredStorage::open(&directory).unwrap();
+
+    let series = tiered_storage.query(600).unwrap();
+
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0].points.len(), 1);
+    assert_eq!(series[0].points[0].average, 46.0);
+
+    drop(tiered_storage);
+
+    let data = fs::read_to_string(directory.join("tier_10s.tsv")).unwrap();
+
+    assert_eq!(data.lines().count(), 1);
+    assert!(data.ends_with('\n'));
+}
+
+#[test]
+fn second_process_opens_the_directory_read_only() {
+    let now = get_current_unix_timestamp() as u64 / 10 * 10;
+
+    let tier_data = format!("{}\t0\tcpu\tPackage id 0\t45\t47\t92\t2\n", now - 60);
+
+    let directory = create_fixture("tiered_storage_lock", &[("tier_10s.tsv", &tier_data)]);
+
+    let writer = TieredStorage::open(&directory).unwrap();
+
+    assert!(!writer.is_read_only());
+
+    let mut reader = TieredStorage::open(&directory).unwrap();
+
+    assert!(reader.is_read_only());
+
+    // читач нічого не дописує, але бачить те, що вже записав інший процес
+    reader.push_sample(now as f64 + 5.0, &[reading(90.0)]).unwrap();
+
+    assert_eq!(reader.query(600).unwrap()[0].points[0].average, 46.0);
+
+    drop(reader);
+
+    let series = writer.query(600).unwrap();
+
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0].points.len(), 1);
+    assert_eq!(series[0].points[0].average, 46.0);
+
+    drop(writer);
+
+    // блокування знімається разом із процесом, що писав
+    assert!(!TieredStorage::open(&directory).unwrap().is_read_only());
+}
@@ -0,0 +1,70 @@
+use resource_monitor::sensors::SensorKind;
+use resource_monitor::units::{MeasurementUnit, TEMPERATURE_UNITS};
+
+fn assert_close(value: f32, expected: f32) {
+    assert!((value - expected).abs() < 0.001, "{} != {}", value, expected);
+}
+
+#[test]
+fn temperatures_convert_between_all_units() {
+    assert_close(MeasurementUnit::Celsius.convert(100.0, MeasurementUnit::Fahrenheit), 212.0);
+    assert_close(MeasurementUnit::Celsius.convert(-40.0, MeasurementUnit::Fahrenheit), -40.0);
+    assert_close(MeasurementUnit::Celsius.convert(0.0, MeasurementUnit::Kelvin), 273.15);
+    assert_close(MeasurementUnit::Fahrenheit.convert(32.0, MeasurementUnit::Celsius), 0.0);
+    assert_close(MeasurementUnit::Kelvin.convert(373.15, MeasurementUnit::Fahrenheit), 212.0);
+    assert_close(MeasurementUnit::Fahrenheit.convert(212.0, MeasurementUnit::Kelvin), 373.15);
+
+    for from in TEMPERATURE_UNITS {
+        for to in TEMPERATURE_UNITS {
+            assert_close(to.convert(from.convert(36.6, to), from), 36.6);
+        }
+    }
+}
+
+#[test]
+fn unrelated_units_keep_the_value() {
+    assert_eq!(MeasurementUnit::Milliwatt.convert(12500.0, MeasurementUnit::Watt), 12.5);
+    assert_eq!(MeasurementUnit::Watt.convert(1.5, MeasurementUnit::Milliwatt), 1500.0);
+    assert_eq!(MeasurementUnit::Percent.convert(42.0, MeasurementUnit::Celsius), 42.0);
+    assert_eq!(MeasurementUnit::Celsius.convert(42.0, MeasurementUnit::Byte), 42.0);
+}
+
+#[test]
+fn display_values_follow_the_temperature_unit_only_for_temperatures() {
+    assert_eq!(SensorKind::Temperature.display_unit(MeasurementUnit::Kelvin), MeasurementUnit::Kelvin);
+    assert_close(SensorKind::Temperature.to_display_value(25.0, MeasurementUnit::Fahrenheit), 77.0);
+    assert_close(SensorKind::Temperature.from_display_value(77.0, MeasurementUnit::Fahrenheit), 25.0);
+    assert_eq!(SensorKind::Load.to_display_value(25.0, MeasurementUnit::Fahrenheit), 25.0);
+}
+
+#[test]
+fn byte_rates_are_scaled_to_the_largest_whole_unit() {
+    assert_eq!(MeasurementUnit::BytePerSecond.format_value(512.0, 1), "512.0 B/s");
+    assert_eq!(MeasurementUnit::BytePerSecond.format_value(1023.0, 0), "1023 B/s");
+    assert_eq!(MeasurementUnit::BytePerSecond.format_value(1024.0, 1), "1.0 KiB/s");
+    assert_eq!(MeasurementUnit::BytePerSecond.format_value(5.5 * 1048576.0, 2), "5.50 MiB/s");
+    assert_eq!(MeasurementUnit::Byte.format_value(3.0 * 1073741824.0, 1), "3.0 GiB");
+    // більше за тебібайти одиниць немає
+    assert_eq!(MeasurementUnit::Byte.format_value(2048.0 * 1099511627776.0, 0), "2048 TiB");
+}
+
+#[test]
+fn values_are_formatted_with_the_requested_precision() {
+    assert_eq!(MeasurementUnit::Celsius.format_value(45.25, 1), "45.2°C");
+    assert_eq!(MeasurementUnit::Fahrenheit.format_value(113.456, 2), "113.46°F");
+    assert_eq!(MeasurementUnit::Kelvin.format_value(318.4, 0), "318 K");
+    assert_eq!(MeasurementUnit::Percent.format_value(99.95, 1), "100.0%");
+    assert_eq!(MeasurementUnit::Watt.format_value(12.345, 1), "12.3 W");
+    // оберти завжди цілі
+    assert_eq!(MeasurementUnit::Rpm.format_value(1234.6, 2), "1235 RPM");
+}
+
+#[test]
+fn unit_names_round_trip() {
+    for unit in TEMPERATURE_UNITS {
+        assert_eq!(MeasurementUnit::from_name(unit.name()), Some(unit));
+    }
+
+    assert_eq!(MeasurementUnit::from_name("F"), Some(MeasurementUnit::Fahrenheit));
+    assert_eq!(MeasurementUnit::from_name("rankine"), None);
+}